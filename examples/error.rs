@@ -5,7 +5,7 @@ use core::convert::Infallible;
 #[cfg(feature = "alloc")]
 use std::alloc::AllocError;
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 pub struct Error;
 
 impl From<Infallible> for Error {
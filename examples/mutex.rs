@@ -4,9 +4,11 @@
 
 use core::{
     cell::{Cell, UnsafeCell},
+    convert::Infallible,
     marker::PhantomPinned,
     ops::{Deref, DerefMut},
     pin::Pin,
+    ptr::addr_of_mut,
     sync::atomic::{AtomicBool, Ordering},
 };
 use std::{
@@ -71,14 +73,7 @@ pub struct CMutex<T> {
 impl<T> CMutex<T> {
     #[inline]
     pub fn new(val: impl PinInit<T>) -> impl PinInit<Self> {
-        pin_init!(CMutex {
-            wait_list <- ListHead::new(),
-            spin_lock: SpinLock::new(),
-            locked: Cell::new(false),
-            data <- unsafe {
-                pin_init_from_closure(|slot: *mut UnsafeCell<T>| val.__pinned_init(slot.cast::<T>()))
-            },
-        })
+        lock_init(val)
     }
 
     #[inline]
@@ -112,6 +107,26 @@ impl<T> CMutex<T> {
     }
 }
 
+// SAFETY: `data_ptr` only projects to `data` and `init_lock` initializes every other field.
+unsafe impl<T> LockInit<T> for CMutex<T> {
+    type Error = Infallible;
+
+    unsafe fn data_ptr(slot: *mut Self) -> *mut T {
+        // SAFETY: `slot` is valid and `UnsafeCell<T>` has the same layout as `T`.
+        unsafe { addr_of_mut!((*slot).data) }.cast()
+    }
+
+    unsafe fn init_lock(slot: *mut Self) -> Result<(), Infallible> {
+        // SAFETY: `slot` is valid and will stay pinned, so `wait_list` is pinned as well.
+        unsafe {
+            ListHead::new().__pinned_init(addr_of_mut!((*slot).wait_list))?;
+            addr_of_mut!((*slot).spin_lock).write(SpinLock::new());
+            addr_of_mut!((*slot).locked).write(Cell::new(false));
+        }
+        Ok(())
+    }
+}
+
 unsafe impl<T: Send> Send for CMutex<T> {}
 unsafe impl<T: Send> Sync for CMutex<T> {}
 
@@ -200,6 +215,6 @@ fn main() {
     for h in handles {
         h.join().expect("thread paniced");
     }
-    println!("{:?}", &*mtx.lock());
+    println!("{:?}", *mtx.lock());
     assert_eq!(*mtx.lock(), workload * thread_count * 2);
 }
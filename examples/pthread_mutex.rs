@@ -11,6 +11,7 @@ mod pthread_mtx {
         mem::MaybeUninit,
         ops::{Deref, DerefMut},
         pin::Pin,
+        ptr::addr_of_mut,
     };
     use pinned_init::*;
     use std::convert::Infallible;
@@ -56,47 +57,61 @@ mod pthread_mtx {
         }
     }
 
+    fn init_raw() -> impl PinInit<UnsafeCell<libc::pthread_mutex_t>, Error> {
+        let init = |slot: *mut UnsafeCell<libc::pthread_mutex_t>| {
+            // we can cast, because `UnsafeCell` has the same layout as T.
+            let slot: *mut libc::pthread_mutex_t = slot.cast();
+            let mut attr = MaybeUninit::uninit();
+            let attr = attr.as_mut_ptr();
+            // SAFETY: ptr is valid
+            let ret = unsafe { libc::pthread_mutexattr_init(attr) };
+            if ret != 0 {
+                return Err(Error::IO(std::io::Error::from_raw_os_error(ret)));
+            }
+            // SAFETY: attr is initialized
+            let ret = unsafe { libc::pthread_mutexattr_settype(attr, libc::PTHREAD_MUTEX_NORMAL) };
+            if ret != 0 {
+                // SAFETY: attr is initialized
+                unsafe { libc::pthread_mutexattr_destroy(attr) };
+                return Err(Error::IO(std::io::Error::from_raw_os_error(ret)));
+            }
+            // SAFETY: slot is valid
+            unsafe { slot.write(libc::PTHREAD_MUTEX_INITIALIZER) };
+            // SAFETY: attr and slot are valid ptrs and attr is initialized
+            let ret = unsafe { libc::pthread_mutex_init(slot, attr) };
+            // SAFETY: attr was initialized
+            unsafe { libc::pthread_mutexattr_destroy(attr) };
+            if ret != 0 {
+                return Err(Error::IO(std::io::Error::from_raw_os_error(ret)));
+            }
+            Ok(())
+        };
+        // SAFETY: mutex has been initialized
+        unsafe { pin_init_from_closure(init) }
+    }
+
+    // SAFETY: `data_ptr` only projects to `data` and `init_lock` initializes every other field.
+    unsafe impl<T> LockInit<T> for PThreadMutex<T> {
+        type Error = Error;
+
+        unsafe fn data_ptr(slot: *mut Self) -> *mut T {
+            // SAFETY: `slot` is valid and `UnsafeCell<T>` has the same layout as `T`.
+            unsafe { addr_of_mut!((*slot).data) }.cast()
+        }
+
+        unsafe fn init_lock(slot: *mut Self) -> Result<(), Error> {
+            // SAFETY: `slot` is valid and will stay pinned, so `raw` is never moved.
+            unsafe {
+                init_raw().__pinned_init(addr_of_mut!((*slot).raw))?;
+                addr_of_mut!((*slot).pin).write(PhantomPinned);
+            }
+            Ok(())
+        }
+    }
+
     impl<T> PThreadMutex<T> {
         pub fn new(data: T) -> impl PinInit<Self, Error> {
-            fn init_raw() -> impl PinInit<UnsafeCell<libc::pthread_mutex_t>, Error> {
-                let init = |slot: *mut UnsafeCell<libc::pthread_mutex_t>| {
-                    // we can cast, because `UnsafeCell` has the same layout as T.
-                    let slot: *mut libc::pthread_mutex_t = slot.cast();
-                    let mut attr = MaybeUninit::uninit();
-                    let attr = attr.as_mut_ptr();
-                    // SAFETY: ptr is valid
-                    let ret = unsafe { libc::pthread_mutexattr_init(attr) };
-                    if ret != 0 {
-                        return Err(Error::IO(std::io::Error::from_raw_os_error(ret)));
-                    }
-                    // SAFETY: attr is initialized
-                    let ret = unsafe {
-                        libc::pthread_mutexattr_settype(attr, libc::PTHREAD_MUTEX_NORMAL)
-                    };
-                    if ret != 0 {
-                        // SAFETY: attr is initialized
-                        unsafe { libc::pthread_mutexattr_destroy(attr) };
-                        return Err(Error::IO(std::io::Error::from_raw_os_error(ret)));
-                    }
-                    // SAFETY: slot is valid
-                    unsafe { slot.write(libc::PTHREAD_MUTEX_INITIALIZER) };
-                    // SAFETY: attr and slot are valid ptrs and attr is initialized
-                    let ret = unsafe { libc::pthread_mutex_init(slot, attr) };
-                    // SAFETY: attr was initialized
-                    unsafe { libc::pthread_mutexattr_destroy(attr) };
-                    if ret != 0 {
-                        return Err(Error::IO(std::io::Error::from_raw_os_error(ret)));
-                    }
-                    Ok(())
-                };
-                // SAFETY: mutex has been initialized
-                unsafe { pin_init_from_closure(init) }
-            }
-            try_pin_init!(Self {
-            data: UnsafeCell::new(data),
-            raw <- init_raw(),
-            pin: PhantomPinned,
-        }? Error)
+            lock_init(data)
         }
 
         pub fn lock(&self) -> PThreadMutexGuard<'_, T> {
@@ -170,7 +185,7 @@ fn main() {
         for h in handles {
             h.join().expect("thread paniced");
         }
-        println!("{:?}", &*mtx.lock());
+        println!("{:?}", *mtx.lock());
         assert_eq!(*mtx.lock(), workload * thread_count * 2);
     }
 }
@@ -0,0 +1,164 @@
+use core::{
+    convert::Infallible,
+    marker::PhantomPinned,
+    mem::MaybeUninit,
+    pin::Pin,
+    ptr::{self, addr_of_mut},
+};
+
+use pinned_init::*;
+
+/// A fixed size ring buffer that stores its elements inline.
+///
+/// `head` and `tail` point into `buffer`, so the ring buffer has to be pinned. It is initialized in
+/// place using the `&this in` syntax of [`pin_init!`] and elements can be pushed via initializers,
+/// so neither the buffer nor its elements need to be on the stack.
+///
+/// One slot is always kept free to distinguish a full buffer from an empty one, so at most
+/// `SIZE - 1` elements can be stored.
+#[pin_data(PinnedDrop)]
+pub struct RingBuffer<T, const SIZE: usize> {
+    buffer: [MaybeUninit<T>; SIZE],
+    head: *mut T,
+    tail: *mut T,
+    #[pin]
+    _pin: PhantomPinned,
+}
+
+// Invariants: `head` and `tail` point into `buffer`. The slots from `tail` (inclusive) to `head`
+// (exclusive), wrapping around at the end of `buffer`, are initialized.
+impl<T, const SIZE: usize> RingBuffer<T, SIZE> {
+    /// Creates an initializer for an empty ring buffer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `SIZE` is zero.
+    pub fn new() -> impl PinInit<Self> {
+        assert!(SIZE > 0);
+        pin_init!(&this in Self {
+            // SAFETY: The elements of the array are allowed to be uninitialized.
+            buffer <- unsafe { init_from_closure(|_| Ok::<_, Infallible>(())) },
+            // SAFETY: `this` points to the ring buffer that is being initialized.
+            head: unsafe { addr_of_mut!((*this.as_ptr()).buffer).cast::<T>() },
+            // SAFETY: `this` points to the ring buffer that is being initialized.
+            tail: unsafe { addr_of_mut!((*this.as_ptr()).buffer).cast::<T>() },
+            _pin: PhantomPinned,
+        })
+    }
+
+    /// Pushes a new element initialized by `value`.
+    ///
+    /// Returns `false` if the buffer is full, then `value` is not used.
+    pub fn push(self: Pin<&mut Self>, value: impl Init<T>) -> bool {
+        match self.try_push(value) {
+            Ok(res) => res,
+            Err(i) => match i {},
+        }
+    }
+
+    /// Pushes a new element initialized by `value`, which might fail.
+    ///
+    /// Returns `Ok(false)` if the buffer is full, then `value` is not used.
+    pub fn try_push<E>(self: Pin<&mut Self>, value: impl Init<T, E>) -> Result<bool, E> {
+        // SAFETY: We do not move out of `this`.
+        let this = unsafe { self.get_unchecked_mut() };
+        // SAFETY: `head` points into `buffer`.
+        let next_head = unsafe { this.advance(this.head) };
+        if ptr::eq(next_head, this.tail) {
+            // We cannot advance `head`, since `next_head` would point to the same slot as `tail`,
+            // which is currently live.
+            return Ok(false);
+        }
+        // SAFETY: `head` always points to the next free slot. If `value` fails, `head` is not
+        // advanced, so the slot stays free.
+        unsafe { value.__init(this.head)? };
+        this.head = next_head;
+        Ok(true)
+    }
+
+    /// Removes the oldest element.
+    pub fn pop(self: Pin<&mut Self>) -> Option<T> {
+        // SAFETY: We do not move out of `this`.
+        let this = unsafe { self.get_unchecked_mut() };
+        if ptr::eq(this.head, this.tail) {
+            return None;
+        }
+        // SAFETY: `tail` points to a valid element, since it is not the same as `head`. It is
+        // marked as free below.
+        let value = unsafe { this.tail.read() };
+        // SAFETY: `tail` points into `buffer`.
+        this.tail = unsafe { this.advance(this.tail) };
+        Some(value)
+    }
+
+    /// Removes the oldest element without moving it to the stack.
+    ///
+    /// The returned initializer moves the element into its slot.
+    pub fn pop_no_stack(self: Pin<&mut Self>) -> Option<impl Init<T> + '_> {
+        // SAFETY: We do not move out of `this`.
+        let this = unsafe { self.get_unchecked_mut() };
+        if ptr::eq(this.head, this.tail) {
+            return None;
+        }
+        let remove_init = |slot| {
+            // SAFETY: `tail` points to a valid element, since it is not the same as `head`. It is
+            // marked as free below.
+            unsafe { ptr::copy_nonoverlapping(this.tail, slot, 1) };
+            // SAFETY: `tail` points into `buffer`.
+            this.tail = unsafe { this.advance(this.tail) };
+            Ok(())
+        };
+        // SAFETY: The closure initializes `slot` and always succeeds.
+        Some(unsafe { init_from_closure(remove_init) })
+    }
+
+    /// Returns `true` if the buffer contains no elements.
+    pub fn is_empty(&self) -> bool {
+        ptr::eq(self.head, self.tail)
+    }
+
+    /// Returns the pointer to the slot after `ptr`, wrapping around at the end of `buffer`.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must point into `buffer`.
+    unsafe fn advance(&mut self, ptr: *mut T) -> *mut T {
+        // SAFETY: `ptr` points into `buffer`, so the result is at most one past the end of it.
+        let ptr = unsafe { ptr.add(1) };
+        let origin = addr_of_mut!(self.buffer).cast::<T>();
+        // SAFETY: Both pointers point into `buffer` or one past its end.
+        let offset = unsafe { ptr.offset_from(origin) };
+        if offset >= SIZE as isize {
+            origin
+        } else {
+            ptr
+        }
+    }
+}
+
+#[pinned_drop]
+impl<T, const SIZE: usize> PinnedDrop for RingBuffer<T, SIZE> {
+    fn drop(self: Pin<&mut Self>) {
+        // SAFETY: We do not move out of `this`.
+        let this = unsafe { self.get_unchecked_mut() };
+        while !ptr::eq(this.tail, this.head) {
+            // SAFETY: `tail` points to a valid element, since it is not the same as `head`.
+            unsafe { this.tail.drop_in_place() };
+            // SAFETY: `tail` points into `buffer`.
+            this.tail = unsafe { this.advance(this.tail) };
+        }
+    }
+}
+
+#[allow(dead_code)]
+fn main() -> Result<(), Infallible> {
+    stack_pin_init!(let buf = RingBuffer::<u64, 8>::new());
+    for i in 0..7 {
+        assert!(buf.as_mut().push(i * i));
+    }
+    assert!(!buf.as_mut().push(0));
+    while let Some(value) = buf.as_mut().pop() {
+        println!("{value}");
+    }
+    Ok(())
+}
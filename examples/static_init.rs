@@ -115,6 +115,6 @@ fn main() {
     for h in handles {
         h.join().expect("thread paniced");
     }
-    println!("{:?}, {:?}", &*mtx.lock(), &*COUNT.lock());
+    println!("{:?}, {:?}", *mtx.lock(), *COUNT.lock());
     assert_eq!(*mtx.lock(), workload * thread_count * 2);
 }
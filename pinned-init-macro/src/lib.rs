@@ -1,5 +1,6 @@
 mod pin_data;
 mod pinned_drop;
+mod transparent_wrapper;
 mod zeroable;
 
 use proc_macro::TokenStream;
@@ -18,6 +19,24 @@ use proc_macro::TokenStream;
 /// macro, and change your `Drop` implementation to `PinnedDrop` annotated with
 /// `#[`[`macro@pinned_drop`]`]`, since dropping pinned values requires extra care.
 ///
+/// The last field may be dynamically sized, for example `[T]` or a generic `T: ?Sized`. Such a
+/// struct cannot be initialized via [`pin_init!`] directly, but a generic struct can be initialized
+/// with a sized type and then be unsized.
+///
+/// A field can be structurally pinned depending on the configuration by using
+/// `#[cfg_attr(predicate, pin)]` instead of `#[pin]`. At most four different predicates can be
+/// used in a struct.
+///
+/// This macro can also be placed on an enum, then `#[pin]` can be put in front of fields of its
+/// variants. The enum only implements `Unpin` if all pinned fields of all variants do. There are no
+/// projection functions for enums, so they cannot be initialized via [`pin_init!`].
+///
+/// Adding `Zeroable` as an argument derives `Zeroable` for the struct. Adding `Default` implements
+/// `Default` by zeroing the struct, this requires the struct to implement `Zeroable`. Adding
+/// `constructor` generates a `new_pin_init` function with the visibility of the struct, that takes
+/// an initializer for every field in declaration order. This allows other modules to initialize
+/// the struct even if they cannot access its fields. Arguments are separated by commas.
+///
 /// # Examples
 ///
 /// ```rust,ignore
@@ -46,6 +65,40 @@ use proc_macro::TokenStream;
 /// }
 /// ```
 ///
+/// ```rust,ignore
+/// #[pin_data]
+/// enum Slot {
+///     Waiting {
+///         #[pin]
+///         waiter: Waiter,
+///     },
+///     Done(u32),
+/// }
+/// ```
+///
+/// ```rust,ignore
+/// #[pin_data(Zeroable, Default)]
+/// struct Config {
+///     flags: u32,
+///     timeout: Option<NonZeroU64>,
+/// }
+///
+/// let config = Config::default();
+/// ```
+///
+/// ```rust,ignore
+/// mod driver {
+///     #[pin_data(constructor)]
+///     pub struct DriverData {
+///         #[pin]
+///         queue: Mutex<Vec<Command>>,
+///         buf: Box<[u8; 1024 * 1024]>,
+///     }
+/// }
+///
+/// let data = driver::DriverData::new_pin_init(new_mutex!(Vec::new()), Box::init(zeroed())?);
+/// ```
+///
 /// [`pin_init!`]: ../pinned_init/macro.pin_init.html
 //  ^ cannot use direct link, since `kernel` is not a dependency of `macros`
 #[proc_macro_attribute]
@@ -57,6 +110,9 @@ pub fn pin_data(inner: TokenStream, item: TokenStream) -> TokenStream {
 ///
 /// Only works on structs that are annotated via `#[`[`macro@pin_data`]`]`.
 ///
+/// If the struct is `Unpin`, `fn drop(&mut self)` can be used instead of
+/// `fn drop(self: Pin<&mut Self>)`.
+///
 /// # Examples
 ///
 /// ```rust,ignore
@@ -82,7 +138,9 @@ pub fn pinned_drop(args: TokenStream, input: TokenStream) -> TokenStream {
 
 /// Derives the [`Zeroable`] trait for the given struct.
 ///
-/// This can only be used for structs where every field implements the [`Zeroable`] trait.
+/// This can only be used for structs where every field implements the [`Zeroable`] trait. Every
+/// type parameter gets a `Zeroable` bound, except for the ones that only appear inside of
+/// `PhantomData`.
 ///
 /// # Examples
 ///
@@ -93,8 +151,106 @@ pub fn pinned_drop(args: TokenStream, input: TokenStream) -> TokenStream {
 ///     buf_ptr: *mut u8,
 ///     len: usize,
 /// }
+///
+/// #[derive(Zeroable)]
+/// pub struct Handle<T>(u32, PhantomData<T>);
 /// ```
 #[proc_macro_derive(Zeroable)]
 pub fn derive_zeroable(input: TokenStream) -> TokenStream {
     zeroable::derive(input.into()).into()
 }
+
+/// Derives the [`ZeroableDefault`] trait for the given struct.
+///
+/// The struct also needs to implement [`Zeroable`] and [`Default`]. The derive macro cannot check
+/// that the default value is the zeroed value, consider comparing them in a test.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// #[derive(Zeroable, Default, ZeroableDefault)]
+/// pub struct Statistics {
+///     reads: u64,
+///     writes: u64,
+///     last_error: Option<NonZeroI32>,
+/// }
+/// ```
+#[proc_macro_derive(ZeroableDefault)]
+pub fn derive_zeroable_default(input: TokenStream) -> TokenStream {
+    zeroable::derive_default(input.into()).into()
+}
+
+/// Derives the [`FromBytes`] trait for the given struct.
+///
+/// This can only be used for structs where every field implements the [`FromBytes`] trait. Since
+/// [`Zeroable`] is a supertrait of [`FromBytes`], the struct also needs to implement it.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// #[derive(Zeroable, FromBytes)]
+/// #[repr(C)]
+/// pub struct PacketHeader {
+///     magic: u32,
+///     len: u16,
+///     flags: u16,
+/// }
+///
+/// stack_try_pin_init!(let header: PacketHeader = from_bytes_init(&packet[..8]));
+/// ```
+#[proc_macro_derive(FromBytes)]
+pub fn derive_from_bytes(input: TokenStream) -> TokenStream {
+    zeroable::derive_from_bytes(input.into()).into()
+}
+
+/// Derives the [`TransparentWrapper`] trait for the given struct.
+///
+/// The struct needs to be `#[repr(transparent)]` and have exactly one field, which becomes the
+/// `Inner` type. This allows using [`PinInit::wrap`] to turn an initializer for the field into an
+/// initializer for the struct. Since any initializer of the field can then be used, the struct
+/// should not have any additional invariants.
+///
+/// The field is structurally pinned, so the struct only implements `Unpin` if the field does and
+/// it may not implement `Drop`.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// #[derive(TransparentWrapper)]
+/// #[repr(transparent)]
+/// pub struct Counter(CMutex<usize>);
+///
+/// let counter: Pin<Box<Counter>> = Box::pin_init(CMutex::new(0).wrap())?;
+/// ```
+///
+/// [`TransparentWrapper`]: ../pinned_init/trait.TransparentWrapper.html
+/// [`PinInit::wrap`]: ../pinned_init/trait.PinInit.html#method.wrap
+#[proc_macro_derive(TransparentWrapper)]
+pub fn derive_transparent_wrapper(input: TokenStream) -> TokenStream {
+    transparent_wrapper::derive(input.into(), "TransparentWrapper").into()
+}
+
+/// Derives the [`TransparentWrapper`] trait and `from_inner_[pin_]init` functions for the given
+/// struct.
+///
+/// This has the same requirements as `#[derive(TransparentWrapper)]`, so they cannot be used
+/// together. Additionally `from_inner_pin_init` and `from_inner_init` functions with the visibility
+/// of the struct are generated, which turn a pin-initializer or an initializer for the field into
+/// one for the struct. This allows forwarding the initializer of the inner type without naming the
+/// trait.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// #[derive(InitForward)]
+/// #[repr(transparent)]
+/// pub struct Counter(CMutex<usize>);
+///
+/// let counter: Pin<Box<Counter>> = Box::pin_init(Counter::from_inner_pin_init(CMutex::new(0)))?;
+/// ```
+///
+/// [`TransparentWrapper`]: ../pinned_init/trait.TransparentWrapper.html
+#[proc_macro_derive(InitForward)]
+pub fn derive_init_forward(input: TokenStream) -> TokenStream {
+    transparent_wrapper::derive(input.into(), "InitForward").into()
+}
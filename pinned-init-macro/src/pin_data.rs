@@ -1,8 +1,14 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
-use proc_macro2::{Group, Punct, Spacing, TokenStream, TokenTree};
+use proc_macro2::{Delimiter, Group, Punct, Spacing, TokenStream, TokenTree};
 use quote::quote;
 
+/// The maximum number of distinct predicates in `#[cfg_attr(pred, pin)]` attributes.
+///
+/// The struct is expanded once for every combination of the predicates, so this limits the
+/// expansion to 16 copies.
+const MAX_CFG_PINS: usize = 4;
+
 pub(crate) struct Generics {
     pub(crate) decl_generics: Vec<TokenTree>,
     pub(crate) impl_generics: Vec<TokenTree>,
@@ -122,10 +128,24 @@ pub(crate) fn pin_data(
     args: proc_macro::TokenStream,
     input: proc_macro::TokenStream,
 ) -> proc_macro::TokenStream {
-    let args: TokenStream = args.into();
     // This proc-macro only does some pre-parsing and then delegates the actual parsing to
     // `pinned_init::__pin_data!`.
 
+    // `Zeroable`, `Default` and `constructor` are handled here, the remaining arguments are given
+    // to `__pin_data!`.
+    let mut args = split_args(args.into());
+    let zeroable = take_arg(&mut args, "Zeroable");
+    let default = take_arg(&mut args, "Default");
+    let constructor = take_arg(&mut args, "constructor");
+    let args = args
+        .into_iter()
+        .enumerate()
+        .flat_map(|(i, arg)| {
+            let sep = (i != 0).then(|| TokenTree::Punct(Punct::new(',', Spacing::Alone)));
+            sep.into_iter().chain(arg)
+        })
+        .collect::<TokenStream>();
+
     let (
         Generics {
             decl_generics,
@@ -144,7 +164,7 @@ pub(crate) fn pin_data(
     // The name of the struct with ty_generics.
     let struct_name = rest
         .iter()
-        .skip_while(|tt| !matches!(tt, TokenTree::Ident(i) if i.to_string() == "struct"))
+        .skip_while(|tt| !is_type_def_keyword(tt))
         .nth(1)
         .and_then(|tt| match tt {
             TokenTree::Ident(_) => {
@@ -180,8 +200,8 @@ pub(crate) fn pin_data(
     let mut rest = rest
         .into_iter()
         .flat_map(|tt| {
-            // We ignore top level `struct` tokens, since they would emit a compile error.
-            if matches!(&tt, TokenTree::Ident(i) if i.to_string() == "struct") {
+            // We ignore top level `struct` and `enum` tokens, since they would emit a compile error.
+            if is_type_def_keyword(&tt) {
                 vec![tt]
             } else {
                 replace_self_and_deny_type_defs(&struct_name, tt, &mut errs)
@@ -190,19 +210,189 @@ pub(crate) fn pin_data(
         .collect::<Vec<_>>();
     // This should be the body of the struct `{...}`.
     let last = rest.pop();
-    let mut quoted = quote!(::pinned_init::__pin_data! {
-        parse_input:
-        @args(#args),
-        @sig(#(#rest)*),
-        @impl_generics(#(#impl_generics)*),
-        @ty_generics(#(#ty_generics)*),
-        @decl_generics(#(#decl_generics)*),
-        @body(#last),
-    });
+    if zeroable {
+        rest.splice(0..0, quote!(#[derive(::pinned_init::Zeroable)]));
+    }
+    // `__pin_data!` only recognizes a literal `#[pin]`, so fields with `#[cfg_attr(pred, pin)]` are
+    // handled by expanding the struct once for every combination of the predicates. Each expansion
+    // is guarded by a `#[cfg]`, so the compiler selects the one matching the current configuration.
+    let mut preds = vec![];
+    if let Some(last) = &last {
+        collect_cfg_pins(last, &mut preds);
+    }
+    if preds.len() > MAX_CFG_PINS {
+        let msg = format!(
+            "`#[pin_data]` supports at most {MAX_CFG_PINS} different predicates in \
+                `#[cfg_attr(pred, pin)]`, found {}",
+            preds.len()
+        );
+        errs.extend(quote!(::core::compile_error!(#msg);));
+        // Expand the struct once without the conditional `#[pin]`s to avoid follow-up errors.
+        preds.clear();
+    }
+    let mut quoted = TokenStream::new();
+    for mask in 0..1usize << preds.len() {
+        let enabled = (0..preds.len())
+            .map(|i| mask & (1 << i) != 0)
+            .collect::<Vec<_>>();
+        let last = last
+            .clone()
+            .map(|last| resolve_cfg_pins(last, &preds, &enabled));
+        let cfg = if preds.is_empty() {
+            quote!()
+        } else {
+            let conds = preds.iter().zip(&enabled).map(|(pred, enabled)| {
+                if *enabled {
+                    quote!(#pred)
+                } else {
+                    quote!(not(#pred))
+                }
+            });
+            quote!(#[cfg(all(#(#conds),*))])
+        };
+        quoted.extend(quote!(#cfg ::pinned_init::__pin_data! {
+            parse_input:
+            @args(#args),
+            @sig(#(#rest)*),
+            @impl_generics(#(#impl_generics)*),
+            @ty_generics(#(#ty_generics)*),
+            @decl_generics(#(#decl_generics)*),
+            @body(#last),
+        }));
+        if default {
+            quoted.extend(quote!(#cfg ::pinned_init::__pin_data! {
+                zeroed_default:
+                @sig(#(#rest)*),
+                @impl_generics(#(#impl_generics)*),
+                @ty_generics(#(#ty_generics)*),
+            }));
+        }
+        if constructor {
+            quoted.extend(quote!(#cfg ::pinned_init::__pin_data! {
+                constructor:
+                @sig(#(#rest)*),
+                @impl_generics(#(#impl_generics)*),
+                @ty_generics(#(#ty_generics)*),
+                @body(#last),
+            }));
+        }
+    }
     quoted.extend(errs);
     quoted.into()
 }
 
+/// Splits the contents of a `cfg_attr(pred, attrs...)` attribute into the predicate and the
+/// attributes, if `pin` is one of the attributes.
+fn split_cfg_pin(attr: &Group) -> Option<(Vec<TokenTree>, Vec<Vec<TokenTree>>)> {
+    let mut toks = attr.stream().into_iter();
+    match (toks.next(), toks.next(), toks.next()) {
+        (Some(TokenTree::Ident(i)), Some(TokenTree::Group(args)), None)
+            if i == "cfg_attr" && args.delimiter() == Delimiter::Parenthesis =>
+        {
+            let mut args = split_args(args.stream());
+            if args.is_empty() {
+                return None;
+            }
+            let pred = args.remove(0);
+            take_arg(&mut args, "pin").then_some((pred, args))
+        }
+        _ => None,
+    }
+}
+
+/// Collects the distinct predicates of all `#[cfg_attr(pred, pin)]` attributes in `tt`.
+fn collect_cfg_pins(tt: &TokenTree, preds: &mut Vec<TokenStream>) {
+    let TokenTree::Group(g) = tt else {
+        return;
+    };
+    let mut toks = g.stream().into_iter().peekable();
+    while let Some(tt) = toks.next() {
+        if matches!(&tt, TokenTree::Punct(p) if p.as_char() == '#') {
+            if let Some(TokenTree::Group(attr)) = toks.peek() {
+                if attr.delimiter() == Delimiter::Bracket {
+                    if let Some((pred, _)) = split_cfg_pin(attr) {
+                        let pred = pred.into_iter().collect::<TokenStream>();
+                        if !preds.iter().any(|p| p.to_string() == pred.to_string()) {
+                            preds.push(pred);
+                        }
+                        toks.next();
+                        continue;
+                    }
+                }
+            }
+        }
+        collect_cfg_pins(&tt, preds);
+    }
+}
+
+/// Replaces every `#[cfg_attr(pred, pin)]` attribute in `tt` with `#[pin]` if the entry of
+/// `enabled` for `pred` in `preds` is true and removes it otherwise.
+///
+/// Predicates that are not in `preds` are treated as disabled. Other attributes in the same
+/// `cfg_attr` are kept.
+fn resolve_cfg_pins(tt: TokenTree, preds: &[TokenStream], enabled: &[bool]) -> TokenTree {
+    let TokenTree::Group(g) = tt else {
+        return tt;
+    };
+    let mut res = TokenStream::new();
+    let mut toks = g.stream().into_iter().peekable();
+    while let Some(tt) = toks.next() {
+        if matches!(&tt, TokenTree::Punct(p) if p.as_char() == '#') {
+            if let Some(TokenTree::Group(attr)) = toks.peek() {
+                if attr.delimiter() == Delimiter::Bracket {
+                    if let Some((pred, others)) = split_cfg_pin(attr) {
+                        let key = pred.iter().cloned().collect::<TokenStream>().to_string();
+                        if let Some(i) = preds.iter().position(|p| p.to_string() == key) {
+                            if enabled[i] {
+                                res.extend(quote!(#[pin]));
+                            }
+                        }
+                        if !others.is_empty() {
+                            let others = others.into_iter().map(|a| a.into_iter().collect());
+                            let others: Vec<TokenStream> = others.collect();
+                            res.extend(quote!(#[cfg_attr(#(#pred)*, #(#others),*)]));
+                        }
+                        toks.next();
+                        continue;
+                    }
+                }
+            }
+        }
+        res.extend([resolve_cfg_pins(tt, preds, enabled)]);
+    }
+    let mut group = Group::new(g.delimiter(), res);
+    group.set_span(g.span());
+    TokenTree::Group(group)
+}
+
+/// Returns whether `tt` is the `struct` or `enum` keyword that `#[pin_data]` can be placed on.
+fn is_type_def_keyword(tt: &TokenTree) -> bool {
+    matches!(tt, TokenTree::Ident(i) if i.to_string() == "struct" || i.to_string() == "enum")
+}
+
+/// Splits the arguments of `#[pin_data]` at the top level commas.
+fn split_args(args: TokenStream) -> Vec<Vec<TokenTree>> {
+    let mut res = vec![];
+    let mut cur = vec![];
+    for tt in args {
+        match tt {
+            TokenTree::Punct(p) if p.as_char() == ',' => res.push(std::mem::take(&mut cur)),
+            tt => cur.push(tt),
+        }
+    }
+    if !cur.is_empty() {
+        res.push(cur);
+    }
+    res
+}
+
+/// Removes the argument consisting only of the identifier `name` and returns whether it was present.
+fn take_arg(args: &mut Vec<Vec<TokenTree>>, name: &str) -> bool {
+    let len = args.len();
+    args.retain(|arg| !matches!(&arg[..], [TokenTree::Ident(i)] if *i == name));
+    args.len() != len
+}
+
 /// Replaces `Self` with `struct_name` and errors on `enum`, `trait`, `struct` `union` and `impl`
 /// keywords.
 ///
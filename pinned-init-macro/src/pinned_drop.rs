@@ -38,17 +38,75 @@ pub(crate) fn pinned_drop(
     }
     let idx = pinned_drop_idx
         .unwrap_or_else(|| panic!("Expected an `impl` block implementing `PinnedDrop`."));
+    // The generics and the `for Type where ...` part are needed to implement a helper trait for
+    // the `&mut self` receiver.
+    let impl_generics = toks[1..idx].to_vec();
+    let impl_for = toks[idx + 1..toks.len().saturating_sub(1)].to_vec();
     // Fully qualify the `PinnedDrop`, as to avoid any tampering.
     toks.splice(idx..idx, quote::quote!(::pinned_init::));
     // Take the `{}` body and call the declarative macro.
     if let Some(TokenTree::Group(last)) = toks.pop() {
-        let last = last.stream();
-        quote::quote!(::pinned_init::__pinned_drop! {
+        let mut errs = TokenStream::new();
+        let last = remove_fn_qualifiers(last.stream(), &mut errs);
+        let mut quoted = quote::quote!(::pinned_init::__pinned_drop! {
             @impl_sig(#(#toks)*),
-            @impl_body(#last),
-        })
-        .into()
+            @impl_generics(#(#impl_generics)*),
+            @impl_for(#(#impl_for)*),
+            @impl_body(#(#last)*),
+        });
+        quoted.extend(errs);
+        quoted.into()
     } else {
         TokenStream::from_iter(toks).into()
     }
 }
+
+/// Removes the `async`, `const`, `unsafe` and `extern "abi"` qualifiers in front of `fn drop`.
+///
+/// `PinnedDrop::drop` is a plain function, so an error is appended to `errs` for every qualifier.
+/// Removing them allows the declarative macro to continue parsing the function normally.
+fn remove_fn_qualifiers(body: TokenStream, errs: &mut TokenStream) -> Vec<TokenTree> {
+    let mut toks = body.into_iter().collect::<Vec<_>>();
+    let Some(fn_idx) = toks
+        .iter()
+        .position(|tt| matches!(tt, TokenTree::Ident(i) if *i == "fn"))
+    else {
+        return toks;
+    };
+    let mut after_extern = false;
+    let mut i = 0;
+    toks.retain(|tt| {
+        i += 1;
+        if i > fn_idx {
+            return true;
+        }
+        match tt {
+            TokenTree::Ident(q)
+                if *q == "async" || *q == "const" || *q == "unsafe" || *q == "extern" =>
+            {
+                errs.extend(
+                    format!("::core::compile_error!(\"`PinnedDrop::drop` cannot be `{q}`.\");")
+                        .parse::<TokenStream>()
+                        .unwrap()
+                        .into_iter()
+                        .map(|mut tok| {
+                            tok.set_span(q.span());
+                            tok
+                        }),
+                );
+                after_extern = *q == "extern";
+                false
+            }
+            // The ABI of `extern "C"`.
+            TokenTree::Literal(_) if after_extern => {
+                after_extern = false;
+                false
+            }
+            _ => {
+                after_extern = false;
+                true
+            }
+        }
+    });
+    toks
+}
@@ -0,0 +1,69 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use crate::pin_data::{parse_generics, Generics};
+use proc_macro2::{Delimiter, Ident, Span, TokenStream, TokenTree};
+use quote::quote;
+
+/// Implements both `#[derive(TransparentWrapper)]` and `#[derive(InitForward)]`, `derive` is the
+/// name of the derive macro.
+pub(crate) fn derive(input: TokenStream, derive: &str) -> TokenStream {
+    let (
+        Generics {
+            impl_generics,
+            decl_generics: _,
+            ty_generics,
+        },
+        mut rest,
+    ) = parse_generics(input);
+    if !is_repr_transparent(&rest) {
+        let msg =
+            format!("`#[derive({derive})]` can only be used on `#[repr(transparent)]` structs.");
+        return quote! {
+            ::core::compile_error!(#msg);
+        };
+    }
+    // Tuple structs end with a `;`.
+    if matches!(rest.last(), Some(TokenTree::Punct(p)) if p.as_char() == ';') {
+        rest.pop();
+    }
+    let body = if matches!(rest.last(), Some(TokenTree::Group(g)) if g.delimiter() == Delimiter::Brace)
+    {
+        // This is the body of the struct `{...}`.
+        rest.pop()
+    } else {
+        // The body of a tuple struct `(...)` comes directly after its name, the where clause
+        // follows the body.
+        rest.iter()
+            .position(|tt| matches!(tt, TokenTree::Ident(i) if *i == "struct"))
+            .map(|i| i + 2)
+            .filter(|i| *i < rest.len())
+            .map(|i| rest.remove(i))
+    };
+    let derive = Ident::new(derive, Span::call_site());
+    quote! {
+        ::pinned_init::__derive_transparent_wrapper!(
+            parse_input:
+                @derive(#derive),
+                @sig(#(#rest)*),
+                @impl_generics(#(#impl_generics)*),
+                @ty_generics(#(#ty_generics)*),
+                @body(#body),
+        );
+    }
+}
+
+/// Returns whether the attributes in `rest` contain `#[repr(transparent)]`.
+fn is_repr_transparent(rest: &[TokenTree]) -> bool {
+    rest.windows(2).any(|w| match w {
+        [TokenTree::Punct(p), TokenTree::Group(g)]
+            if p.as_char() == '#' && g.delimiter() == Delimiter::Bracket =>
+        {
+            let attr = g.stream().into_iter().collect::<Vec<_>>();
+            matches!(&attr[..], [TokenTree::Ident(repr), TokenTree::Group(args)]
+                if *repr == "repr"
+                    && args.delimiter() == Delimiter::Parenthesis
+                    && args.stream().to_string() == "transparent")
+        }
+        _ => false,
+    })
+}
@@ -1,7 +1,7 @@
 // SPDX-License-Identifier: GPL-2.0
 
 use crate::pin_data::{parse_generics, Generics};
-use proc_macro2::{TokenStream, TokenTree};
+use proc_macro2::{Delimiter, Spacing, TokenStream, TokenTree};
 use quote::quote;
 
 pub(crate) fn derive(input: TokenStream) -> TokenStream {
@@ -13,13 +13,163 @@ pub(crate) fn derive(input: TokenStream) -> TokenStream {
         },
         mut rest,
     ) = parse_generics(input);
-    // This should be the body of the struct `{...}`.
-    let last = rest.pop();
-    // Now we insert `Zeroable` as a bound for every generic parameter in `impl_generics`.
+    let last = take_body(&mut rest);
+    let phantom = phantom_only_params(&ty_generics, last.as_ref());
+    let new_impl_generics = add_bound(impl_generics, quote! { ::pinned_init::Zeroable }, &phantom);
+    quote! {
+        ::pinned_init::__derive_zeroable!(
+            parse_input:
+                @sig(#(#rest)*),
+                @impl_generics(#(#new_impl_generics)*),
+                @ty_generics(#(#ty_generics)*),
+                @body(#last),
+        );
+    }
+}
+
+pub(crate) fn derive_default(input: TokenStream) -> TokenStream {
+    let (
+        Generics {
+            impl_generics,
+            decl_generics: _,
+            ty_generics,
+        },
+        mut rest,
+    ) = parse_generics(input);
+    let last = take_body(&mut rest);
+    quote! {
+        ::pinned_init::__derive_zeroable_default!(
+            parse_input:
+                @sig(#(#rest)*),
+                @impl_generics(#(#impl_generics)*),
+                @ty_generics(#(#ty_generics)*),
+                @body(#last),
+        );
+    }
+}
+
+pub(crate) fn derive_from_bytes(input: TokenStream) -> TokenStream {
+    let (
+        Generics {
+            impl_generics,
+            decl_generics: _,
+            ty_generics,
+        },
+        mut rest,
+    ) = parse_generics(input);
+    let last = take_body(&mut rest);
+    let phantom = phantom_only_params(&ty_generics, last.as_ref());
+    let new_impl_generics = add_bound(impl_generics, quote! { ::pinned_init::FromBytes }, &phantom);
+    quote! {
+        ::pinned_init::__derive_from_bytes!(
+            parse_input:
+                @sig(#(#rest)*),
+                @impl_generics(#(#new_impl_generics)*),
+                @ty_generics(#(#ty_generics)*),
+                @body(#last),
+        );
+    }
+}
+
+/// Removes the body of the struct from `rest` and returns it.
+///
+/// The body of a tuple struct is followed by an optional where clause and a `;`, these stay in
+/// `rest`, except for the `;`.
+fn take_body(rest: &mut Vec<TokenTree>) -> Option<TokenTree> {
+    if !matches!(rest.last(), Some(TokenTree::Punct(p)) if p.as_char() == ';') {
+        // This should be the body of the struct `{...}`.
+        return rest.pop();
+    }
+    rest.pop();
+    // Skip the visibility, since `pub(crate)` is also a parenthesized group.
+    let start = rest
+        .iter()
+        .position(|tt| matches!(tt, TokenTree::Ident(i) if *i == "struct"))?;
+    let pos = rest[start..]
+        .iter()
+        .position(|tt| is_group(tt, Delimiter::Parenthesis))?;
+    Some(rest.remove(start + pos))
+}
+
+/// Returns the names of the generic type parameters that only appear inside of `PhantomData` in
+/// `body`.
+///
+/// `PhantomData<T>` implements `Zeroable` and `FromBytes` for every `T`, so these parameters do not
+/// need a bound.
+fn phantom_only_params(ty_generics: &[TokenTree], body: Option<&TokenTree>) -> Vec<String> {
+    let mut params = vec![];
+    let mut lifetime = false;
+    for tt in ty_generics {
+        match tt {
+            TokenTree::Punct(p) if p.as_char() == '\'' => lifetime = true,
+            TokenTree::Ident(i) if !lifetime => params.push(i.to_string()),
+            _ => lifetime = false,
+        }
+    }
+    let mut used = vec![];
+    if let Some(body) = body {
+        collect_used_idents(TokenStream::from(body.clone()), &mut used);
+    }
+    params.retain(|param| !used.contains(param));
+    params
+}
+
+/// Collects all identifiers in `tokens` that are not inside of `PhantomData<...>` or an attribute.
+fn collect_used_idents(tokens: TokenStream, used: &mut Vec<String>) {
+    let mut tokens = tokens.into_iter().peekable();
+    while let Some(tt) = tokens.next() {
+        match tt {
+            TokenTree::Punct(p) if p.as_char() == '#' => {
+                if tokens
+                    .peek()
+                    .is_some_and(|tt| is_group(tt, Delimiter::Bracket))
+                {
+                    tokens.next();
+                }
+            }
+            TokenTree::Ident(i) if i == "PhantomData" => {
+                if !matches!(tokens.peek(), Some(TokenTree::Punct(p)) if p.as_char() == '<') {
+                    continue;
+                }
+                // Skip the generic argument, `->` of function pointers does not close it.
+                let mut nested = 0;
+                let mut arrow = false;
+                for tt in tokens.by_ref() {
+                    if let TokenTree::Punct(p) = &tt {
+                        match p.as_char() {
+                            '<' => nested += 1,
+                            '>' if !arrow => nested -= 1,
+                            _ => {}
+                        }
+                        arrow = p.as_char() == '-' && p.spacing() == Spacing::Joint;
+                    } else {
+                        arrow = false;
+                    }
+                    if nested == 0 {
+                        break;
+                    }
+                }
+            }
+            TokenTree::Ident(i) => used.push(i.to_string()),
+            TokenTree::Group(g) => collect_used_idents(g.stream(), used),
+            TokenTree::Literal(_) | TokenTree::Punct(_) => {}
+        }
+    }
+}
+
+fn is_group(tt: &TokenTree, delimiter: Delimiter) -> bool {
+    matches!(tt, TokenTree::Group(g) if g.delimiter() == delimiter)
+}
+
+/// Inserts `bound` as a bound for every generic type parameter in `impl_generics`, except for the
+/// ones in `skip`.
+fn add_bound(impl_generics: Vec<TokenTree>, bound: TokenStream, skip: &[String]) -> Vec<TokenTree> {
     let mut new_impl_generics = Vec::with_capacity(impl_generics.len());
-    // Are we inside of a generic where we want to add `Zeroable`?
+    // Are we inside of a generic where we want to add `bound`?
     let mut in_generic = !impl_generics.is_empty();
-    // Have we already inserted `Zeroable`?
+    // Are we at the start of a generic parameter, i.e. before its name?
+    let mut at_start = true;
+    // Have we already inserted `bound`?
     let mut inserted = false;
     // Level of `<>` nestings.
     let mut nested = 0;
@@ -28,21 +178,38 @@ pub(crate) fn derive(input: TokenStream) -> TokenStream {
             // If we find a `,`, then we have finished a generic/constant/lifetime parameter.
             TokenTree::Punct(p) if nested == 0 && p.as_char() == ',' => {
                 if in_generic && !inserted {
-                    new_impl_generics.extend(quote! { : ::pinned_init::Zeroable });
+                    new_impl_generics.extend(quote! { : #bound });
                 }
                 in_generic = true;
+                at_start = true;
                 inserted = false;
                 new_impl_generics.push(tt);
             }
             // If we find `'`, then we are entering a lifetime.
             TokenTree::Punct(p) if nested == 0 && p.as_char() == '\'' => {
                 in_generic = false;
+                at_start = false;
+                new_impl_generics.push(tt);
+            }
+            // If we find `const`, then we are entering a const generic. Its value is not stored in
+            // the type, so no bound is needed.
+            TokenTree::Ident(i) if nested == 0 && *i == "const" => {
+                in_generic = false;
+                at_start = false;
+                new_impl_generics.push(tt);
+            }
+            // This is the name of a type parameter.
+            TokenTree::Ident(i) if nested == 0 && at_start => {
+                if skip.iter().any(|s| *i == s) {
+                    in_generic = false;
+                }
+                at_start = false;
                 new_impl_generics.push(tt);
             }
             TokenTree::Punct(p) if nested == 0 && p.as_char() == ':' => {
                 new_impl_generics.push(tt);
                 if in_generic {
-                    new_impl_generics.extend(quote! { ::pinned_init::Zeroable + });
+                    new_impl_generics.extend(quote! { #bound + });
                     inserted = true;
                 }
             }
@@ -60,15 +227,7 @@ pub(crate) fn derive(input: TokenStream) -> TokenStream {
     }
     assert_eq!(nested, 0);
     if in_generic && !inserted {
-        new_impl_generics.extend(quote! { : ::pinned_init::Zeroable });
-    }
-    quote! {
-        ::pinned_init::__derive_zeroable!(
-            parse_input:
-                @sig(#(#rest)*),
-                @impl_generics(#(#new_impl_generics)*),
-                @ty_generics(#(#ty_generics)*),
-                @body(#last),
-        );
+        new_impl_generics.extend(quote! { : #bound });
     }
+    new_impl_generics
 }
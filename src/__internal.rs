@@ -16,6 +16,10 @@
 //! - `../pinned-init-macro/src/pin_data.rs`
 
 use super::*;
+use core::{
+    cell::Cell,
+    mem::{self, ManuallyDrop},
+};
 
 /// See the [nomicon] for what subtyping is. See also [this table].
 ///
@@ -53,12 +57,26 @@ where
     }
 }
 
+impl<T: ?Sized, F, E> PinPlaceInit<T, E> for InitClosure<F, T, E> where
+    F: FnOnce(*mut T) -> Result<(), E>
+{
+}
+
+impl<T: ?Sized, F, E> PlaceInit<T, E> for InitClosure<F, T, E> where
+    F: FnOnce(*mut T) -> Result<(), E>
+{
+}
+
 /// This trait is only implemented via the `#[pin_data]` proc-macro. It is used to facilitate
 /// the pin projections within the initializers.
 ///
 /// # Safety
 ///
 /// Only the `init` module is allowed to use this trait.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` does not have pin data",
+    note = "did you forget `#[pin_data]` on `{Self}`?"
+)]
 pub unsafe trait HasPinData {
     type PinData: PinData;
 
@@ -66,6 +84,24 @@ pub unsafe trait HasPinData {
     unsafe fn __pin_data() -> Self::PinData;
 }
 
+/// Helper trait used by the initializer macros to get the pin data of a type.
+///
+/// In contrast to calling [`HasPinData::__pin_data`] directly, this results in a trait error
+/// with a helpful message instead of a missing function error if `#[pin_data]` is missing.
+pub trait GetPinData {
+    #[expect(clippy::missing_safety_doc)]
+    #[inline]
+    unsafe fn __get_pin_data() -> <Self as HasPinData>::PinData
+    where
+        Self: HasPinData,
+    {
+        // SAFETY: The caller upholds the requirements of `__pin_data`.
+        unsafe { Self::__pin_data() }
+    }
+}
+
+impl<T: ?Sized> GetPinData for T {}
+
 /// Marker trait for pinning data of structs.
 ///
 /// # Safety
@@ -137,6 +173,23 @@ unsafe impl<T: ?Sized> HasInitData for T {
     }
 }
 
+/// Fills `slot` with `0xAA` bytes when the `debug-poison` feature is enabled, otherwise does
+/// nothing.
+///
+/// # Safety
+///
+/// `slot` must be valid for writes and must not contain an initialized value.
+#[inline]
+pub(crate) unsafe fn poison<T>(slot: *mut T) {
+    #[cfg(feature = "debug-poison")]
+    // SAFETY: `slot` is valid for writes and its contents do not need to be dropped.
+    unsafe {
+        ptr::write_bytes(slot.cast::<u8>(), 0xAA, mem::size_of::<T>())
+    };
+    #[cfg(not(feature = "debug-poison"))]
+    let _ = slot;
+}
+
 /// Stack initializer helper type. Use [`stack_pin_init`] instead of this primitive.
 ///
 /// # Invariants
@@ -185,7 +238,11 @@ impl<T> StackInit<T> {
             // SAFETY: `this.is_init` was true and therefore `this.value` is initialized.
             unsafe { this.value.assume_init_drop() };
         }
+        // SAFETY: The memory slot is valid and uninitialized.
+        unsafe { poison(this.value.as_mut_ptr()) };
         // SAFETY: The memory slot is valid and this type ensures that it will stay pinned.
+        // If the initializer errors or panics, it has already cleaned up the slot and `is_init`
+        // stays false, so the slot is not dropped.
         unsafe { init.__pinned_init(this.value.as_mut_ptr())? };
         // INVARIANT: `this.value` is initialized above.
         this.is_init = true;
@@ -221,7 +278,9 @@ fn stack_init_reuse() {
 
 /// When a value of this type is dropped, it drops a `T`.
 ///
-/// Can be forgotten to prevent the drop.
+/// Once the pointee has been handed over to its new owner, the guard is dismissed via
+/// [`mem::forget`]. There is intentionally no separate method for this, so every user of the guard
+/// dismisses it in the same way.
 pub struct DropGuard<T: ?Sized> {
     ptr: *mut T,
 }
@@ -243,6 +302,29 @@ impl<T: ?Sized> DropGuard<T> {
     }
 }
 
+impl<T> DropGuard<T> {
+    /// `false` if `T` is a zero-sized type without drop glue, since dropping it is a no-op.
+    const NEEDED: bool = mem::size_of::<T>() != 0 || mem::needs_drop::<T>();
+
+    /// Creates a new [`DropGuard<T>`] for a field of a struct that is being initialized.
+    ///
+    /// Returns `None` if `T` is a zero-sized type without drop glue. This is a constant, so the
+    /// initializer macros do not generate any guard code for such fields.
+    ///
+    /// # Safety
+    ///
+    /// Same as [`DropGuard::new`].
+    #[inline]
+    pub unsafe fn new_field(ptr: *mut T) -> Option<Self> {
+        if Self::NEEDED {
+            // SAFETY: The caller upholds the safety requirements of `new`.
+            Some(unsafe { Self::new(ptr) })
+        } else {
+            None
+        }
+    }
+}
+
 impl<T: ?Sized> Drop for DropGuard<T> {
     #[inline]
     fn drop(&mut self) {
@@ -252,6 +334,109 @@ impl<T: ?Sized> Drop for DropGuard<T> {
     }
 }
 
+/// Writes the default value of `T` into `slot`.
+///
+/// Used by the `..Default::default()` syntax of the initializer macros.
+///
+/// # Safety
+///
+/// `slot` must be valid for writes.
+#[inline]
+pub unsafe fn write_default<T: Default>(slot: *mut T) {
+    // SAFETY: The caller guarantees that `slot` is valid for writes.
+    unsafe { slot.write(T::default()) };
+}
+
+/// Creates a shared reference to the already initialized field `field` of `*slot`.
+///
+/// Used by the `field_ref!` macro inside of the initializer macros. The lifetime of the reference
+/// is bound to the borrow of `slot`, so it cannot be stored inside of the initialized value.
+///
+/// # Safety
+///
+/// `field` must point to an initialized field of `*slot` that is not modified while the returned
+/// reference is alive.
+#[inline]
+pub unsafe fn field_ref<T: ?Sized, F: ?Sized>(_slot: &*mut T, field: *const F) -> &F {
+    // SAFETY: The caller guarantees that `field` is initialized and not modified while the reference is alive.
+    unsafe { &*field }
+}
+
+/// Holds the default value of a field while it is being initialized.
+///
+/// Used by the `..Default::default()` syntax of the initializer macros. When a value of this type
+/// is dropped, the default value is written back to the field. Use [`DefaultFieldGuard::dismiss`]
+/// to drop the default value instead once the field has been initialized.
+pub struct DefaultFieldGuard<T> {
+    ptr: *mut T,
+    old: ManuallyDrop<T>,
+}
+
+impl<T> DefaultFieldGuard<T> {
+    /// Moves the value out of `ptr`, leaving it uninitialized.
+    ///
+    /// # Safety
+    ///
+    /// - `ptr` must be valid and point to an initialized value that has not been pinned,
+    /// - if `self` is dropped, `ptr` must be valid for writes and uninitialized.
+    #[inline]
+    pub unsafe fn new(ptr: *mut T) -> Self {
+        Self {
+            ptr,
+            // SAFETY: The caller guarantees that `ptr` is valid and initialized.
+            old: ManuallyDrop::new(unsafe { ptr.read() }),
+        }
+    }
+
+    /// Drops the old value, since the field has been initialized.
+    #[inline]
+    pub fn dismiss(self) {
+        let mut this = ManuallyDrop::new(self);
+        // SAFETY: `this` is not dropped, so `old` is dropped only once.
+        unsafe { ManuallyDrop::drop(&mut this.old) };
+    }
+}
+
+impl<T> Drop for DefaultFieldGuard<T> {
+    #[inline]
+    fn drop(&mut self) {
+        // SAFETY: `self.old` is not used after this. The caller of `new` guarantees that `ptr` is
+        // valid for writes and uninitialized.
+        unsafe { self.ptr.write(ManuallyDrop::take(&mut self.old)) };
+    }
+}
+
+/// Wraps the drop guard of a field while it is being initialized with `..Zeroable::zeroed()`.
+///
+/// When `skip_rest!()` is used, `skipped` is set and the fields that have already been
+/// initialized have to stay initialized. In that case the wrapped guard is forgotten instead of
+/// dropped.
+pub struct SkipRestGuard<'a, G> {
+    guard: ManuallyDrop<G>,
+    skipped: &'a Cell<bool>,
+}
+
+impl<'a, G> SkipRestGuard<'a, G> {
+    /// Creates a new [`SkipRestGuard`] that only drops `guard` if `skipped` is not set.
+    #[inline]
+    pub fn new(guard: G, skipped: &'a Cell<bool>) -> Self {
+        Self {
+            guard: ManuallyDrop::new(guard),
+            skipped,
+        }
+    }
+}
+
+impl<G> Drop for SkipRestGuard<'_, G> {
+    #[inline]
+    fn drop(&mut self) {
+        if !self.skipped.get() {
+            // SAFETY: `self.guard` is not used after this.
+            unsafe { ManuallyDrop::drop(&mut self.guard) };
+        }
+    }
+}
+
 /// Token used by `PinnedDrop` to prevent calling the function without creating this unsafely
 /// created struct. This is needed, because the `drop` function is safe, but should not be called
 /// manually.
@@ -267,6 +452,20 @@ impl OnlyCallFromDrop {
     }
 }
 
+/// Trait used by [`assert_not_unpin!`] to detect `Unpin` types.
+///
+/// It is implemented twice for every `Unpin` type, so the generic parameter can only be inferred for
+/// types that are `!Unpin`.
+///
+/// [`assert_not_unpin!`]: crate::assert_not_unpin
+pub trait AmbiguousIfUnpin<A> {
+    fn some_item() {}
+}
+
+impl<T: ?Sized> AmbiguousIfUnpin<()> for T {}
+
+impl<T: ?Sized + Unpin> AmbiguousIfUnpin<u8> for T {}
+
 /// Initializer that always fails.
 ///
 /// Used by [`assert_pinned!`].
@@ -0,0 +1,100 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Values that are pin-initialized in place on their first access.
+
+use crate::{boxed_pin_init, PinInit, PinInitDyn};
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::boxed::Box;
+use core::{
+    cell::{Cell, UnsafeCell},
+    convert::Infallible,
+    marker::PhantomPinned,
+    mem::MaybeUninit,
+    pin::Pin,
+};
+
+/// A value that is pin-initialized in place on its first access.
+///
+/// In contrast to [`LazyCell`], which stores a closure returning the value, this stores an
+/// initializer that is run directly on the memory inside of the `DeferredInit`. The initializer is
+/// boxed, so its type does not show up in the type of the `DeferredInit`.
+///
+/// Since the value is pinned once it has been initialized, it can only be initialized through a
+/// `Pin<&Self>`. Before that, the `DeferredInit` can be moved freely.
+///
+/// # Examples
+///
+/// ```rust
+/// # #![feature(allocator_api)]
+/// # #[path = "../examples/mutex.rs"] mod mutex; use mutex::*;
+/// # use pinned_init::*;
+/// # use core::pin::pin;
+/// let deferred = pin!(DeferredInit::new(CMutex::new(42)));
+/// assert!(deferred.get().is_none());
+/// assert_eq!(*deferred.as_ref().get_or_init().lock(), 42);
+/// assert!(deferred.get().is_some());
+/// ```
+///
+/// [`LazyCell`]: core::cell::LazyCell
+pub struct DeferredInit<T> {
+    init: Cell<Option<Box<dyn PinInitDyn<T, Infallible>>>>,
+    value: UnsafeCell<MaybeUninit<T>>,
+    is_init: Cell<bool>,
+    _pin: PhantomPinned,
+}
+
+// Invariants: If `is_init` is true, then `value` is initialized and `init` is `None`.
+impl<T> DeferredInit<T> {
+    /// Creates a new `DeferredInit` that runs `init` on its first access.
+    pub fn new(init: impl PinInit<T> + 'static) -> Self {
+        Self {
+            init: Cell::new(Some(boxed_pin_init(init))),
+            value: UnsafeCell::new(MaybeUninit::uninit()),
+            is_init: Cell::new(false),
+            _pin: PhantomPinned,
+        }
+    }
+
+    /// Returns the value, initializing it first if this is the first access.
+    ///
+    /// # Panics
+    ///
+    /// Panics when called from within the initializer, or when the initializer panicked during an
+    /// earlier access.
+    pub fn get_or_init(self: Pin<&Self>) -> Pin<&T> {
+        if !self.is_init.get() {
+            let init = self
+                .init
+                .take()
+                .expect("the initializer of `DeferredInit` did not complete");
+            let slot = self.value.get().cast::<T>();
+            // SAFETY: `slot` is valid for writes and uninitialized, since `is_init` is false and
+            // the initializer has not been taken before. `self` is pinned, so `slot` will not move.
+            match unsafe { init.pinned_init_boxed(slot) } {
+                Ok(()) => {}
+                Err(x) => match x {},
+            }
+            self.is_init.set(true);
+        }
+        // SAFETY: `value` has been initialized above or on an earlier access. It is pinned, since
+        // `self` is pinned.
+        unsafe { Pin::new_unchecked(&*self.value.get().cast::<T>()) }
+    }
+
+    /// Returns the value if it has already been initialized.
+    pub fn get(&self) -> Option<&T> {
+        // SAFETY: `value` is initialized, since `is_init` is true.
+        self.is_init
+            .get()
+            .then(|| unsafe { &*self.value.get().cast::<T>() })
+    }
+}
+
+impl<T> Drop for DeferredInit<T> {
+    fn drop(&mut self) {
+        if self.is_init.get() {
+            // SAFETY: `value` is initialized, since `is_init` is true and it is not used afterwards.
+            unsafe { self.value.get_mut().assume_init_drop() };
+        }
+    }
+}
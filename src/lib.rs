@@ -30,6 +30,25 @@
 //! disabling `alloc`. In practice this will require the `std` feature, because
 //! stable compilers have neither `Box` nor `Arc` in no-std mode.
 //!
+//! ## Poisoning uninitialized memory
+//!
+//! The `debug-poison` feature is a debugging aid, it should not be enabled in production. When it
+//! is enabled, [`InPlaceInit`], [`InPlaceWrite`] and [`stack_pin_init!`] fill the slot with
+//! `0xAA` bytes before running the initializer. This makes it more likely that an initializer,
+//! which does not initialize all of the memory, is noticed.
+//!
+//! ## Testing initializers
+//!
+//! The `test-util` feature enables `init_to_value` and `pin_init_to_boxed`, which run an
+//! initializer and return the initialized value, so custom initializers can be tested without
+//! setting up their memory manually.
+//!
+//! ## Cyclic `Arc`s
+//!
+//! The `cyclic` feature enables `InPlaceInitCyclic` to pin-initialize values that hold a weak
+//! pointer to themselves. It implies `alloc` and additionally requires the `unique_rc_arc` unstable
+//! feature, which is less mature than `allocator_api`.
+//!
 //! # Overview
 //!
 //! To initialize a `struct` with an in-place constructor you will need two things:
@@ -110,6 +129,9 @@
 //! Many types that use this library supply a function/macro that returns an initializer, because
 //! the above method only works for types where you can access the fields.
 //!
+//! Alternatively, `#[pin_data(constructor)]` generates a `new_pin_init` function on the type that
+//! takes one initializer per field and has the same visibility as the type.
+//!
 //! ```rust
 //! # #![feature(allocator_api)]
 //! # #[path = "../examples/mutex.rs"] mod mutex; use mutex::*;
@@ -157,6 +179,9 @@
 //! - you may assume that `slot` will stay pinned even after the closure returns until `drop` of
 //!   `slot` gets called.
 //!
+//! When initializing a struct field by field, [`write_field!`] takes care of the cleanup of fields
+//! that have already been initialized.
+//!
 //! ```rust
 //! # #![feature(extern_types)]
 //! use pinned_init::*;
@@ -238,23 +263,26 @@
 #![forbid(missing_docs, unsafe_op_in_unsafe_fn)]
 #![cfg_attr(not(feature = "std"), no_std)]
 #![cfg_attr(feature = "alloc", feature(allocator_api))]
+#![cfg_attr(feature = "cyclic", feature(unique_rc_arc))]
 
 #[cfg(feature = "alloc")]
 extern crate alloc;
 
 #[cfg(all(feature = "alloc", not(feature = "std")))]
-use alloc::{boxed::Box, sync::Arc};
+use alloc::{boxed::Box, string::String, sync::Arc};
 #[cfg(feature = "std")]
 use std::sync::Arc;
 
 use core::{
-    cell::UnsafeCell,
+    alloc::Layout,
+    cell::{OnceCell, RefCell, UnsafeCell},
     convert::Infallible,
     marker::PhantomData,
     mem::MaybeUninit,
     num::*,
     pin::Pin,
     ptr::{self, NonNull},
+    sync::atomic::{AtomicBool, Ordering},
 };
 
 #[cfg(feature = "alloc")]
@@ -267,10 +295,22 @@ type AllocError = Infallible;
 
 #[doc(hidden)]
 pub mod __internal;
+#[cfg(any(feature = "std", feature = "alloc"))]
+mod deferred;
 #[doc(hidden)]
 pub mod macros;
+#[cfg(feature = "test-util")]
+mod test_util;
+mod tuple;
 
-pub use pinned_init_macro::{pin_data, pinned_drop, Zeroable};
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub use deferred::*;
+pub use pinned_init_macro::{
+    pin_data, pinned_drop, FromBytes, InitForward, TransparentWrapper, Zeroable, ZeroableDefault,
+};
+#[cfg(feature = "test-util")]
+pub use test_util::*;
+pub use tuple::*;
 
 /// Initialize and pin a type directly on the stack.
 ///
@@ -309,6 +349,17 @@ pub use pinned_init_macro::{pin_data, pinned_drop, Zeroable};
 /// A normal `let` binding with optional type annotation. The expression is expected to implement
 /// [`PinInit`]/[`Init`] with the error type [`Infallible`]. If you want to use a different error
 /// type, then use [`stack_try_pin_init!`].
+///
+/// Multiple bindings separated by commas can be given, for example
+/// `stack_pin_init!(let a = init_a(), let b = init_b());`. They are initialized in the given order
+/// and live until the end of the enclosing scope, so they are dropped in reverse order.
+///
+/// # Panics
+///
+/// If the initializer panics, the panic is propagated and the stack slot is not dropped, since it
+/// does not contain a value. The initializer itself is responsible for dropping what it already
+/// initialized, the initializers created by [`pin_init!`] and friends drop every field that was
+/// initialized before the panic.
 #[macro_export]
 macro_rules! stack_pin_init {
     (let $var:ident $(: $t:ty)? = $val:expr) => {
@@ -322,6 +373,9 @@ macro_rules! stack_pin_init {
             }
         };
     };
+    ($(let $var:ident $(: $t:ty)? = $val:expr),+ $(,)?) => {
+        $($crate::stack_pin_init!(let $var $(: $t)? = $val);)+
+    };
 }
 
 /// Initialize and pin a type directly on the stack.
@@ -557,11 +611,38 @@ macro_rules! stack_try_pin_init {
 /// As already mentioned in the examples above, inside of `pin_init!` a `struct` initializer with
 /// the following modifications is expected:
 /// - Fields that you want to initialize in-place have to use `<-` instead of `:`.
+/// - The generics of the struct can be given explicitly using turbofish syntax, for example
+///   `Foo::<u32, N> { .. }`. Only types and named constants are supported as generic arguments,
+///   lifetimes and literal constants have to be inferred.
+///   When they are omitted, they are inferred from the field initializers and from the type the
+///   initializer is used for. Generics that are only used in [`PhantomData`] fields often cannot be
+///   inferred, then they have to be given explicitly.
 /// - In front of the initializer you can write `&this in` to have access to a [`NonNull<Self>`]
-///   pointer named `this` inside of the initializer.
+///   pointer named `this` inside of the initializer. If the pointer is stored inside of the
+///   struct, the struct must be `!Unpin`, this can be checked using [`assert_not_unpin!`].
+/// - Instead of `&this in` you can also write `&raw this in`, then `this` is a `*mut Self` pointer
+///   instead of a [`NonNull<Self>`].
+/// - Fields are initialized in the order in which they are written. Inside of the expression of a
+///   field, `field_ref!(name)` returns a shared reference to the field `name`, if it has already
+///   been initialized. Using a field that is initialized later results in a compile error. The
+///   reference cannot be stored in the struct, to create self-referential structs use `this`.
+/// - Fields of reference type are initialized by-value using `:`. The expressions of the fields
+///   are evaluated inside of a `move` closure, so references to local variables have to be created
+///   outside of the initializer, for example `let data = &buf[..];` followed by `data` in the
+///   initializer. The lifetime parameter of the struct ensures that the referenced value outlives
+///   the initialized struct.
 /// - Using struct update syntax one can place `..Zeroable::zeroed()` at the very end of the
 ///   struct, this initializes every field with 0 and then runs all initializers specified in the
-///   body. This can only be done if [`Zeroable`] is implemented for the struct.
+///   body. This can only be done if [`Zeroable`] is implemented for the struct. The qualified forms
+///   `..::pinned_init::Zeroable::zeroed()` and `..<Self as Zeroable>::zeroed()` (with or without
+///   the crate path) are also accepted, where `Self` can also be the name of the struct. Inside of
+///   the expressions of the fields, [`skip_rest!()`] can then be used to finish the initialization
+///   early, leaving the remaining fields zeroed.
+/// - Similarly `..Default::default()` can be placed at the very end of the struct, this first
+///   writes the [`Default`] value of the struct and then replaces the fields specified in the body.
+///   The default values of the replaced fields are dropped. In contrast to zeroing, this does not
+///   initialize the fields that are not mentioned in-place, they are created on the stack by
+///   [`Default::default`] and moved into place.
 ///
 /// For instance:
 ///
@@ -586,9 +667,58 @@ macro_rules! stack_try_pin_init {
 /// # let _ = Box::pin_init(init);
 /// ```
 ///
+/// The same initializer using `&raw this in`:
+///
+/// ```rust
+/// # use pinned_init::*;
+/// # use core::{ptr::addr_of_mut, marker::PhantomPinned};
+/// # #[pin_data]
+/// # struct Buf {
+/// #     ptr: *mut u8,
+/// #     buf: [u8; 64],
+/// #     #[pin]
+/// #     pin: PhantomPinned,
+/// # }
+/// let init = pin_init!(&raw this in Buf {
+///     buf: [0; 64],
+///     // SAFETY: `this` points to the `Buf` that is being initialized.
+///     ptr: unsafe { addr_of_mut!((*this).buf).cast() },
+///     pin: PhantomPinned,
+/// });
+/// let buf = Box::pin_init(init).unwrap();
+/// assert_eq!(buf.ptr.cast_const(), buf.buf.as_ptr());
+/// ```
+///
+/// Reading an earlier field while initializing a later one:
+///
+/// ```rust
+/// # use pinned_init::*;
+/// #[pin_data]
+/// struct Range {
+///     start: usize,
+///     end: usize,
+///     len: usize,
+/// }
+///
+/// let init = pin_init!(Range {
+///     start: 4,
+///     end: *field_ref!(start) + 8,
+///     len: *field_ref!(end) - *field_ref!(start),
+/// });
+/// let range = Box::pin_init(init).unwrap();
+/// assert_eq!(range.len, 8);
+/// ```
+///
 /// [`NonNull<Self>`]: core::ptr::NonNull
 #[macro_export]
 macro_rules! pin_init {
+    (&raw $this:ident in $t:ident $(::<$($generics:ty),* $(,)?>)? {
+        $($fields:tt)*
+    }) => {
+        $crate::try_pin_init!(&raw $this in $t $(::<$($generics),*>)? {
+            $($fields)*
+        }? ::core::convert::Infallible)
+    };
     ($(&$this:ident in)? $t:ident $(::<$($generics:ty),* $(,)?>)? {
         $($fields:tt)*
     }) => {
@@ -608,8 +738,10 @@ macro_rules! pin_init {
 /// IMPORTANT: if you have `unsafe` code inside of the initializer you have to ensure that when
 /// initialization fails, the memory can be safely deallocated without any further modifications.
 ///
-/// The syntax is identical to [`pin_init!`] with the following exception: you must append `? $type`
-/// after the `struct` initializer to specify the error type you want to use.
+/// The syntax is identical to [`pin_init!`] with the following exception: you can append `? $type`
+/// after the `struct` initializer to specify the error type you want to use. When it is omitted,
+/// the error type is inferred from the context, e.g. from the return type of the surrounding
+/// function.
 ///
 /// # Examples
 ///
@@ -635,22 +767,76 @@ macro_rules! pin_init {
 /// }
 /// # let _ = Box::pin_init(BigBuf::new());
 /// ```
+///
+/// Here the error type is inferred from the return type:
+///
+/// ```rust
+/// # #![feature(allocator_api)]
+/// # #[path = "../examples/error.rs"] mod error; use error::Error;
+/// use pinned_init::*;
+/// #[pin_data]
+/// struct Buf {
+///     big: Box<[u8; 1024 * 1024]>,
+///     len: usize,
+/// }
+///
+/// impl Buf {
+///     fn new() -> impl PinInit<Self, Error> {
+///         try_pin_init!(Self {
+///             big: Box::init(zeroed())?,
+///             len: 0,
+///         })
+///     }
+/// }
+/// # let _ = Box::pin_init(Buf::new());
+/// ```
 #[macro_export]
 macro_rules! try_pin_init {
+    (&raw $this_ptr:ident in $t:ident $(::<$($generics:ty),* $(,)?>)? {
+        $($fields:tt)*
+    }? $err:ty) => {
+        $crate::__init_internal!(
+            @this(),
+            @this_ptr($this_ptr),
+            @typ($t $(::<$($generics),*>)? ),
+            @fields($($fields)*),
+            @error($err),
+            @data(PinData, use_data),
+            @has_data(GetPinData, __get_pin_data),
+            @construct_closure(pin_init_from_closure),
+            @munch_fields($($fields)*),
+        )
+    };
     ($(&$this:ident in)? $t:ident $(::<$($generics:ty),* $(,)?>)? {
         $($fields:tt)*
     }? $err:ty) => {
         $crate::__init_internal!(
             @this($($this)?),
+            @this_ptr(),
             @typ($t $(::<$($generics),*>)? ),
             @fields($($fields)*),
             @error($err),
             @data(PinData, use_data),
-            @has_data(HasPinData, __pin_data),
+            @has_data(GetPinData, __get_pin_data),
             @construct_closure(pin_init_from_closure),
             @munch_fields($($fields)*),
         )
     };
+    // Without an error type, it is inferred from the context.
+    (&raw $this_ptr:ident in $t:ident $(::<$($generics:ty),* $(,)?>)? {
+        $($fields:tt)*
+    }) => {
+        $crate::try_pin_init!(&raw $this_ptr in $t $(::<$($generics),*>)? {
+            $($fields)*
+        }? _)
+    };
+    ($(&$this:ident in)? $t:ident $(::<$($generics:ty),* $(,)?>)? {
+        $($fields:tt)*
+    }) => {
+        $crate::try_pin_init!($(&$this in)? $t $(::<$($generics),*>)? {
+            $($fields)*
+        }? _)
+    };
 }
 
 /// Construct an in-place initializer for `struct`s.
@@ -691,6 +877,13 @@ macro_rules! try_pin_init {
 /// ```
 #[macro_export]
 macro_rules! init {
+    (&raw $this:ident in $t:ident $(::<$($generics:ty),* $(,)?>)? {
+        $($fields:tt)*
+    }) => {
+        $crate::try_init!(&raw $this in $t $(::<$($generics),*>)? {
+            $($fields)*
+        }? ::core::convert::Infallible)
+    };
     ($(&$this:ident in)? $t:ident $(::<$($generics:ty),* $(,)?>)? {
         $($fields:tt)*
     }) => {
@@ -705,8 +898,8 @@ macro_rules! init {
 /// If the initialization can complete without error (or [`Infallible`]), then use
 /// [`init!`].
 ///
-/// The syntax is identical to [`try_pin_init!`]. You need to specify a custom error
-/// via `? $type` after the `struct` initializer.
+/// The syntax is identical to [`try_pin_init!`]. You can specify a custom error via `? $type`
+/// after the `struct` initializer, otherwise it is inferred from the context.
 /// The safety caveats from [`try_pin_init!`] also apply:
 /// - `unsafe` code must guarantee either full initialization or return an error and allow
 ///   deallocation of the memory.
@@ -736,11 +929,27 @@ macro_rules! init {
 /// ```
 #[macro_export]
 macro_rules! try_init {
+    (&raw $this_ptr:ident in $t:ident $(::<$($generics:ty),* $(,)?>)? {
+        $($fields:tt)*
+    }? $err:ty) => {
+        $crate::__init_internal!(
+            @this(),
+            @this_ptr($this_ptr),
+            @typ($t $(::<$($generics),*>)?),
+            @fields($($fields)*),
+            @error($err),
+            @data(InitData, /*no use_data*/),
+            @has_data(HasInitData, __init_data),
+            @construct_closure(init_from_closure),
+            @munch_fields($($fields)*),
+        )
+    };
     ($(&$this:ident in)? $t:ident $(::<$($generics:ty),* $(,)?>)? {
         $($fields:tt)*
     }? $err:ty) => {
         $crate::__init_internal!(
             @this($($this)?),
+            @this_ptr(),
             @typ($t $(::<$($generics),*>)?),
             @fields($($fields)*),
             @error($err),
@@ -750,6 +959,122 @@ macro_rules! try_init {
             @munch_fields($($fields)*),
         )
     };
+    // Without an error type, it is inferred from the context.
+    (&raw $this_ptr:ident in $t:ident $(::<$($generics:ty),* $(,)?>)? {
+        $($fields:tt)*
+    }) => {
+        $crate::try_init!(&raw $this_ptr in $t $(::<$($generics),*>)? {
+            $($fields)*
+        }? _)
+    };
+    ($(&$this:ident in)? $t:ident $(::<$($generics:ty),* $(,)?>)? {
+        $($fields:tt)*
+    }) => {
+        $crate::try_init!($(&$this in)? $t $(::<$($generics),*>)? {
+            $($fields)*
+        }? _)
+    };
+}
+
+/// Finishes the initialization early, leaving all fields that have not been initialized yet zeroed.
+///
+/// This can only be used inside of the expressions of the fields in [`pin_init!`],
+/// [`try_pin_init!`], [`init!`] and [`try_init!`] when the struct update syntax
+/// `..Zeroable::zeroed()` is used. The field whose expression uses `skip_rest!()` also stays
+/// zeroed. Just like `return Err(err)`, it cannot be used inside of closures.
+///
+/// # Examples
+///
+/// ```rust
+/// # use pinned_init::*;
+/// #[derive(Debug)]
+/// struct Error;
+///
+/// #[derive(Zeroable)]
+/// struct Header {
+///     len: usize,
+///     checksum: u32,
+///     flags: u32,
+/// }
+///
+/// fn parse(buf: &[u8]) -> impl Init<Header, Error> + '_ {
+///     try_init!(Header {
+///         len: match buf.len() {
+///             // An empty buffer is valid and has no checksum and flags.
+///             0 => skip_rest!(),
+///             len => len,
+///         },
+///         checksum: buf.iter().map(|&b| b as u32).sum(),
+///         flags: *buf.first().ok_or(Error)? as u32,
+///         ..Zeroable::zeroed()
+///     }? Error)
+/// }
+///
+/// stack_try_pin_init!(let header: Header = parse(&[]));
+/// let header = header.unwrap();
+/// assert_eq!((header.len, header.checksum, header.flags), (0, 0, 0));
+/// stack_try_pin_init!(let header: Header = parse(&[1, 2]));
+/// let header = header.unwrap();
+/// assert_eq!((header.len, header.checksum, header.flags), (2, 3, 1));
+/// ```
+#[macro_export]
+macro_rules! skip_rest {
+    () => {
+        ::core::compile_error!(
+            "`skip_rest!()` can only be used inside of `[try_][pin_]init!` with \
+                `..Zeroable::zeroed()`"
+        )
+    };
+}
+
+/// Construct a pin-initializer in two phases, for values whose fields reference each other.
+///
+/// In the first phase all fields are initialized with zeroes, so pointers are null. In the second
+/// phase the closure `$patch` is called with a `Pin<&mut $t>` to the zeroed value. At that point
+/// the address of the value is stable and every field exists, so the closure can set up
+/// references between the fields. It returns `Ok(())` on success; if it returns an error, the
+/// value is dropped and the error is forwarded.
+///
+/// This generalizes the `&this in` syntax of [`pin_init!`], which only gives access to the address
+/// of the whole value while the fields are initialized one after the other. The type has to
+/// implement [`Zeroable`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use pinned_init::*;
+/// # use core::{convert::Infallible, marker::PhantomPinned, pin::Pin, ptr};
+/// #[derive(Zeroable)]
+/// struct Node {
+///     value: u32,
+///     peer: *const Node,
+/// }
+///
+/// #[pin_data]
+/// #[derive(Zeroable)]
+/// struct Pair {
+///     left: Node,
+///     right: Node,
+///     #[pin]
+///     _pin: PhantomPinned,
+/// }
+///
+/// stack_pin_init!(let pair: Pair = two_phase_pin_init!(Pair, |pair: Pin<&mut Pair>| {
+///     // SAFETY: No field is moved out of `pair`.
+///     let pair = unsafe { pair.get_unchecked_mut() };
+///     pair.left = Node { value: 1, peer: &pair.right };
+///     pair.right = Node { value: 2, peer: &pair.left };
+///     Ok::<_, Infallible>(())
+/// }));
+/// assert!(ptr::eq(pair.left.peer, &pair.right));
+/// // SAFETY: `peer` points to `pair.left`, which is pinned.
+/// assert_eq!(unsafe { (*pair.right.peer).value }, 1);
+/// ```
+#[macro_export]
+macro_rules! two_phase_pin_init {
+    ($t:ty, $patch:expr $(,)?) => {
+        $crate::PinInit::pin_chain($crate::pin_infallible::<$t, _>($crate::zeroed()), $patch)
+    };
 }
 
 /// Asserts that a field on a struct using `#[pin_data]` is marked with `#[pin]` ie. that it is
@@ -821,121 +1146,440 @@ macro_rules! assert_pinned {
     };
 }
 
-/// A pin-initializer for the type `T`.
+/// Asserts at compile time that a type is `!Unpin`.
 ///
-/// To use this initializer, you will need a suitable memory location that can hold a `T`. This can
-/// be [`Box<T>`], [`Arc<T>`] or even the stack (see [`stack_pin_init!`]). Use the
-/// [`InPlaceInit::try_pin_init`] function of a smart pointer like [`Arc<T>`] on this.
+/// Initializers that store the address of the value being initialized (for example via `&this in`
+/// in [`pin_init!`]) are only sound if the value cannot be moved afterwards. This is only
+/// guaranteed when the type does not implement [`Unpin`], which is usually achieved by adding a
+/// `#[pin]` field of type [`PhantomPinned`].
 ///
-/// Also see the [module description](self).
+/// This macro only works for concrete types, for generic types it always fails to compile.
 ///
-/// # Safety
+/// # Example
 ///
-/// When implementing this trait you will need to take great care. Also there are probably very few
-/// cases where a manual implementation is necessary. Use [`pin_init_from_closure`] where possible.
+/// This will succeed:
+/// ```
+/// use pinned_init::*;
+/// use core::marker::PhantomPinned;
+/// #[pin_data]
+/// struct SelfRef {
+///     ptr: *const u8,
+///     buf: [u8; 64],
+///     #[pin]
+///     _pin: PhantomPinned,
+/// }
 ///
-/// The [`PinInit::__pinned_init`] function:
-/// - returns `Ok(())` if it initialized every field of `slot`,
-/// - returns `Err(err)` if it encountered an error and then cleaned `slot`, this means:
-///     - `slot` can be deallocated without UB occurring,
-///     - `slot` does not need to be dropped,
-///     - `slot` is not partially initialized.
-/// - while constructing the `T` at `slot` it upholds the pinning invariants of `T`.
+/// assert_not_unpin!(SelfRef);
+/// ```
 ///
-/// [`Arc<T>`]: alloc::sync::Arc
-#[must_use = "An initializer must be used in order to create its value."]
-pub unsafe trait PinInit<T: ?Sized, E = Infallible>: Sized {
-    /// Initializes `slot`.
-    ///
-    /// # Safety
-    ///
-    /// - `slot` is a valid pointer to uninitialized memory.
-    /// - the caller does not touch `slot` when `Err` is returned, they are only permitted to
-    ///   deallocate.
-    /// - `slot` will not move until it is dropped, i.e. it will be pinned.
-    unsafe fn __pinned_init(self, slot: *mut T) -> Result<(), E>;
-
-    /// First initializes the value using `self` then calls the function `f` with the initialized
-    /// value.
-    ///
-    /// If `f` returns an error the value is dropped and the initializer will forward the error.
-    ///
-    /// # Examples
-    ///
-    /// ```rust
-    /// # #![feature(allocator_api)]
-    /// # #[path = "../examples/mutex.rs"] mod mutex; use mutex::*;
-    /// # use pinned_init::*;
-    /// let mtx_init = CMutex::new(42);
-    /// // Make the initializer print the value.
-    /// let mtx_init = mtx_init.pin_chain(|mtx| {
-    ///     println!("{:?}", mtx.get_data_mut());
-    ///     Ok(())
-    /// });
-    /// ```
-    fn pin_chain<F>(self, f: F) -> ChainPinInit<Self, F, T, E>
-    where
-        F: FnOnce(Pin<&mut T>) -> Result<(), E>,
-    {
-        ChainPinInit(self, f, PhantomData)
-    }
-}
-
-/// An initializer returned by [`PinInit::pin_chain`].
-pub struct ChainPinInit<I, F, T: ?Sized, E>(I, F, __internal::Invariant<(E, *const T)>);
-
-// SAFETY: The `__pinned_init` function is implemented such that it
-// - returns `Ok(())` on successful initialization,
-// - returns `Err(err)` on error and in this case `slot` will be dropped.
-// - considers `slot` pinned.
-unsafe impl<T: ?Sized, E, I, F> PinInit<T, E> for ChainPinInit<I, F, T, E>
-where
-    I: PinInit<T, E>,
-    F: FnOnce(Pin<&mut T>) -> Result<(), E>,
-{
-    unsafe fn __pinned_init(self, slot: *mut T) -> Result<(), E> {
-        // SAFETY: All requirements fulfilled since this function is `__pinned_init`.
-        unsafe { self.0.__pinned_init(slot)? };
-        // SAFETY: The above call initialized `slot` and we still have unique access.
-        let val = unsafe { &mut *slot };
-        // SAFETY: `slot` is considered pinned.
-        let val = unsafe { Pin::new_unchecked(val) };
-        // SAFETY: `slot` was initialized above.
-        (self.1)(val).inspect_err(|_| unsafe { core::ptr::drop_in_place(slot) })
-    }
+/// This will fail:
+// TODO: replace with `compile_fail` when supported.
+/// ```ignore
+/// # use pinned_init::*;
+/// #[pin_data]
+/// struct SelfRef {
+///     ptr: *const u8,
+///     buf: [u8; 64],
+/// }
+///
+/// assert_not_unpin!(SelfRef);
+/// ```
+///
+/// [`PhantomPinned`]: core::marker::PhantomPinned
+#[macro_export]
+macro_rules! assert_not_unpin {
+    ($ty:ty) => {
+        const _: () = {
+            // If `$ty: Unpin`, then both impls of `AmbiguousIfUnpin` apply and the type of `_` cannot
+            // be inferred, leading to a compile error.
+            let _ = <$ty as $crate::__internal::AmbiguousIfUnpin<_>>::some_item;
+        };
+    };
 }
 
-/// An initializer for `T`.
+/// Declares a pinned wrapper around an opaque FFI type with init and destroy functions.
 ///
-/// To use this initializer, you will need a suitable memory location that can hold a `T`. This can
-/// be [`Box<T>`], [`Arc<T>`] or even the stack (see [`stack_pin_init!`]). Use the
-/// [`InPlaceInit::try_init`] function of a smart pointer like [`Arc<T>`] on this. Because
-/// [`PinInit<T, E>`] is a super trait, you can use every function that takes it as well.
+/// This generates the boilerplate from the [manual creation of an initializer] example: a
+/// `#[pin_data(PinnedDrop)]` struct containing the FFI type in an [`UnsafeCell`], a `new`
+/// constructor that calls the init function and a `PinnedDrop` implementation that calls the
+/// destroy function. `as_raw` returns a pointer to the FFI type that can be passed to the other
+/// functions of the C API.
 ///
-/// Also see the [module description](self).
+/// Optionally an enable function can be given, which is called with the pointer and the arguments
+/// of `new` after the init function. It returns `0` on success and an error code otherwise. On
+/// error, the destroy function is called and `new` fails with the error code. Without an enable
+/// function, `new` takes no arguments and cannot fail.
 ///
 /// # Safety
 ///
-/// When implementing this trait you will need to take great care. Also there are probably very few
-/// cases where a manual implementation is necessary. Use [`init_from_closure`] where possible.
+/// The functions are called in `unsafe` blocks, so they have to be given inside of an `unsafe {}`
+/// block. By doing so, the caller guarantees that:
+/// - the init function initializes the FFI type in place,
+/// - the destroy function cleans up an initialized value and may be called exactly once,
+/// - the enable function leaves the value initialized, also when it fails,
+/// - the FFI type may not be moved after it has been initialized, so it is structurally pinned.
 ///
-/// The [`Init::__init`] function:
-/// - returns `Ok(())` if it initialized every field of `slot`,
-/// - returns `Err(err)` if it encountered an error and then cleaned `slot`, this means:
-///     - `slot` can be deallocated without UB occurring,
-///     - `slot` does not need to be dropped,
-///     - `slot` is not partially initialized.
-/// - while constructing the `T` at `slot` it upholds the pinning invariants of `T`.
+/// # Examples
 ///
-/// The `__pinned_init` function from the supertrait [`PinInit`] needs to execute the exact same
-/// code as `__init`.
+/// ```rust
+/// # use pinned_init::*;
+/// # use core::pin::pin;
+/// mod bindings {
+///     pub struct foo {
+///         pub enabled: bool,
+///     }
 ///
-/// Contrary to its supertype [`PinInit<T, E>`] the caller is allowed to
-/// move the pointee after initialization.
+///     pub unsafe fn init_foo(ptr: *mut foo) {
+///         unsafe { ptr.write(foo { enabled: false }) };
+///     }
+///
+///     pub unsafe fn destroy_foo(_ptr: *mut foo) {}
+///
+///     pub unsafe fn enable_foo(ptr: *mut foo, flags: u32) -> i32 {
+///         if flags > 1 {
+///             return -22;
+///         }
+///         unsafe { (*ptr).enabled = true };
+///         0
+///     }
+/// }
+///
+/// ffi_pin_init! {
+///     /// A `foo` that is always initialized and enabled.
+///     pub struct RawFoo(bindings::foo);
+///     // SAFETY: `init_foo` initializes `foo` in place, `destroy_foo` cleans it up and
+///     // `enable_foo` does not deinitialize it.
+///     unsafe {
+///         init = bindings::init_foo;
+///         destroy = bindings::destroy_foo;
+///         enable(flags: u32) = bindings::enable_foo;
+///     }
+/// }
+///
+/// stack_try_pin_init!(let foo = RawFoo::new(1));
+/// let foo = foo.unwrap();
+/// assert!(unsafe { (*foo.as_raw()).enabled });
+/// stack_try_pin_init!(let foo = RawFoo::new(2));
+/// assert_eq!(foo.err(), Some(-22));
+/// ```
+///
+/// [manual creation of an initializer]: crate#manual-creation-of-an-initializer
+/// [`UnsafeCell`]: core::cell::UnsafeCell
+#[macro_export]
+macro_rules! ffi_pin_init {
+    (
+        $(#[$attr:meta])*
+        $vis:vis struct $name:ident($inner:ty);
+        unsafe {
+            init = $init:path;
+            destroy = $destroy:path;
+            enable($($arg:ident: $arg_ty:ty),* $(,)?) = $enable:path;
+        }
+    ) => {
+        $crate::ffi_pin_init!(@type:
+            $(#[$attr])*
+            $vis struct $name($inner);
+            destroy = $destroy;
+        );
+
+        impl $name {
+            /// Initializes and enables the value.
+            ///
+            /// Fails with the error code of the enable function.
+            #[allow(dead_code)]
+            $vis fn new($($arg: $arg_ty),*) -> impl $crate::PinInit<Self, i32> {
+                let init = move |slot: *mut Self| {
+                    // SAFETY: `slot` is valid for writes, so is the pointer to the FFI type.
+                    let ptr = ::core::cell::UnsafeCell::raw_get(unsafe {
+                        ::core::ptr::addr_of_mut!((*slot).inner)
+                    });
+                    // SAFETY: The invocation of `ffi_pin_init!` guarantees that these functions
+                    // initialize the FFI type and leave it initialized even if enabling fails.
+                    let err = unsafe {
+                        $init(ptr);
+                        $enable(ptr, $($arg),*)
+                    };
+                    if err != 0 {
+                        // SAFETY: The value is initialized and is not used afterwards.
+                        unsafe { $destroy(ptr) };
+                        return Err(err);
+                    }
+                    // All fields have been initialized, since `_pin` is a ZST.
+                    Ok(())
+                };
+                // SAFETY: The closure initializes all fields when it returns `Ok` and cleans up the
+                // FFI type before returning `Err`. The FFI type is structurally pinned.
+                unsafe { $crate::pin_init_from_closure(init) }
+            }
+        }
+    };
+    (
+        $(#[$attr:meta])*
+        $vis:vis struct $name:ident($inner:ty);
+        unsafe {
+            init = $init:path;
+            destroy = $destroy:path;
+        }
+    ) => {
+        $crate::ffi_pin_init!(@type:
+            $(#[$attr])*
+            $vis struct $name($inner);
+            destroy = $destroy;
+        );
+
+        impl $name {
+            /// Initializes the value.
+            #[allow(dead_code)]
+            $vis fn new() -> impl $crate::PinInit<Self> {
+                let init = move |slot: *mut Self| {
+                    // SAFETY: `slot` is valid for writes, so is the pointer to the FFI type.
+                    let ptr = ::core::cell::UnsafeCell::raw_get(unsafe {
+                        ::core::ptr::addr_of_mut!((*slot).inner)
+                    });
+                    // SAFETY: The invocation of `ffi_pin_init!` guarantees that this function
+                    // initializes the FFI type.
+                    unsafe { $init(ptr) };
+                    // All fields have been initialized, since `_pin` is a ZST.
+                    Ok(())
+                };
+                // SAFETY: The closure initializes all fields and the FFI type is structurally
+                // pinned.
+                unsafe { $crate::pin_init_from_closure(init) }
+            }
+        }
+    };
+    (@type:
+        $(#[$attr:meta])*
+        $vis:vis struct $name:ident($inner:ty);
+        destroy = $destroy:path;
+    ) => {
+        $(#[$attr])*
+        #[$crate::pin_data(PinnedDrop)]
+        $vis struct $name {
+            #[pin]
+            _pin: ::core::marker::PhantomPinned,
+            #[pin]
+            inner: ::core::cell::UnsafeCell<$inner>,
+        }
+
+        impl $name {
+            /// Returns a pointer to the FFI type.
+            #[allow(dead_code)]
+            $vis fn as_raw(&self) -> *mut $inner {
+                self.inner.get()
+            }
+        }
+
+        #[$crate::pinned_drop]
+        impl PinnedDrop for $name {
+            fn drop(self: ::core::pin::Pin<&mut Self>) {
+                // SAFETY: The FFI type is always initialized and the invocation of
+                // `ffi_pin_init!` guarantees that the destroy function cleans it up.
+                unsafe { $destroy(self.inner.get()) };
+            }
+        }
+    };
+}
+
+/// Initializes a single field of a struct inside of a closure given to [`pin_init_from_closure`].
+///
+/// This is a helper for the [manual creation of an initializer]. It writes the field through
+/// [`addr_of_mut!`] without creating a reference to the uninitialized struct and returns a guard
+/// that drops the field again. Keep the guards until every field has been initialized:
+/// - when a later field fails, returning the error drops the guards in reverse order, so the fields
+///   initialized before are cleaned up,
+/// - when every field has been initialized, dismiss all guards with [`mem::forget`].
+///
+/// There are two forms, mirroring the syntax of [`pin_init!`]:
+/// - `write_field!(slot, field: value)` moves `value` into the field and evaluates to the guard,
+/// - `write_field!(slot, field <- init)` runs the [`PinInit`] `init` on the field and evaluates to
+///   a `Result` containing the guard, which is usually followed by `?`.
+///
+/// The macro has to be used inside of an `unsafe` block. By doing so the caller guarantees that
+/// `slot` is a `*mut` to the struct that is currently being initialized by the closure. `slot` is
+/// considered pinned, so only use the `<-` form with [`init_from_closure`] if `init` also
+/// implements [`Init`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use pinned_init::*;
+/// # use core::{cell::UnsafeCell, marker::PhantomPinned, mem, pin::Pin, ptr::addr_of_mut};
+/// # mod bindings {
+/// #     pub struct foo;
+/// #     pub unsafe fn init_foo(_ptr: *mut foo) {}
+/// #     pub unsafe fn enable_foo(_ptr: *mut foo, flags: u32) -> i32 {
+/// #         if flags > 1 { -22 } else { 0 }
+/// #     }
+/// #     pub unsafe fn destroy_foo(_ptr: *mut foo) {}
+/// # }
+/// #[pin_data(PinnedDrop)]
+/// pub struct RawFoo {
+///     name: String,
+///     #[pin]
+///     foo: UnsafeCell<bindings::foo>,
+///     #[pin]
+///     _p: PhantomPinned,
+/// }
+///
+/// fn raw_foo(flags: u32) -> impl PinInit<UnsafeCell<bindings::foo>, i32> {
+///     // SAFETY: `foo` is destroyed before an error is returned.
+///     unsafe {
+///         pin_init_from_closure(move |slot: *mut UnsafeCell<bindings::foo>| {
+///             let foo = UnsafeCell::raw_get(slot);
+///             bindings::init_foo(foo);
+///             let err = bindings::enable_foo(foo, flags);
+///             if err != 0 {
+///                 bindings::destroy_foo(foo);
+///                 return Err(err);
+///             }
+///             Ok(())
+///         })
+///     }
+/// }
+///
+/// impl RawFoo {
+///     pub fn new(name: &str, flags: u32) -> impl PinInit<Self, i32> + '_ {
+///         // SAFETY: Every field is initialized when `Ok` is returned. On error, the guards drop
+///         // the fields that have been initialized before.
+///         unsafe {
+///             pin_init_from_closure(move |slot: *mut Self| {
+///                 let name = write_field!(slot, name: name.to_owned());
+///                 let p = write_field!(slot, _p: PhantomPinned);
+///                 // If this fails, `name` is dropped.
+///                 let foo = write_field!(slot, foo <- raw_foo(flags))?;
+///                 mem::forget((name, p, foo));
+///                 Ok(())
+///             })
+///         }
+///     }
+/// }
+///
+/// #[pinned_drop]
+/// impl PinnedDrop for RawFoo {
+///     fn drop(self: Pin<&mut Self>) {
+///         // SAFETY: Since `foo` is initialized, destroying is safe.
+///         unsafe { bindings::destroy_foo(self.foo.get()) };
+///     }
+/// }
+///
+/// stack_try_pin_init!(let foo = RawFoo::new("foo", 1));
+/// assert_eq!(foo.unwrap().name, "foo");
+/// stack_try_pin_init!(let foo = RawFoo::new("foo", 2));
+/// assert_eq!(foo.err(), Some(-22));
+/// ```
+///
+/// [manual creation of an initializer]: crate#manual-creation-of-an-initializer
+/// [`addr_of_mut!`]: core::ptr::addr_of_mut
+/// [`mem::forget`]: core::mem::forget
+#[macro_export]
+macro_rules! write_field {
+    ($slot:expr, $field:ident <- $init:expr $(,)?) => {{
+        let ptr = ::core::ptr::addr_of_mut!((*$slot).$field);
+        match $crate::PinInit::__pinned_init($init, ptr) {
+            ::core::result::Result::Ok(()) => {
+                ::core::result::Result::Ok($crate::__internal::DropGuard::new(ptr))
+            }
+            ::core::result::Result::Err(err) => ::core::result::Result::Err(err),
+        }
+    }};
+    ($slot:expr, $field:ident : $value:expr $(,)?) => {{
+        let ptr = ::core::ptr::addr_of_mut!((*$slot).$field);
+        ptr.write($value);
+        $crate::__internal::DropGuard::new(ptr)
+    }};
+}
+
+/// Returns a raw pointer to the contents of an [`UnsafeCell`] field.
+///
+/// `raw_field!(this, field)` evaluates to a `*mut Inner`, when `field` has the type
+/// `UnsafeCell<Inner>`. `this` can be any pointer that dereferences to the struct, such as `&Self`
+/// or the `Pin<&mut Self>` of a [`PinnedDrop`] implementation. This is the pointer that is passed
+/// to the functions of a C API and replaces the combination of [`UnsafeCell::raw_get`] and
+/// [`addr_of_mut!`]. Creating the pointer is safe, using it is not.
+///
+/// # Examples
+///
+/// ```rust
+/// # use pinned_init::*;
+/// # use core::{cell::UnsafeCell, convert::Infallible, marker::PhantomPinned, pin::Pin};
+/// # mod bindings {
+/// #     pub struct foo;
+/// #     pub unsafe fn init_foo(_ptr: *mut foo) {}
+/// #     pub unsafe fn destroy_foo(_ptr: *mut foo) {}
+/// # }
+/// #[pin_data(PinnedDrop)]
+/// pub struct RawFoo {
+///     #[pin]
+///     foo: UnsafeCell<bindings::foo>,
+///     #[pin]
+///     _p: PhantomPinned,
+/// }
+///
+/// impl RawFoo {
+///     pub fn new() -> impl PinInit<Self> {
+///         pin_init!(Self {
+///             // SAFETY: `init_foo` initializes `foo` in place.
+///             foo <- unsafe { unsafe_cell_init(init_from_closure(|slot| {
+///                 bindings::init_foo(slot);
+///                 Ok::<_, Infallible>(())
+///             })) },
+///             _p: PhantomPinned,
+///         })
+///     }
+/// }
+///
+/// #[pinned_drop]
+/// impl PinnedDrop for RawFoo {
+///     fn drop(self: Pin<&mut Self>) {
+///         // SAFETY: Since `foo` is initialized, destroying is safe.
+///         unsafe { bindings::destroy_foo(raw_field!(self, foo)) };
+///     }
+/// }
+/// ```
+///
+/// [`UnsafeCell`]: core::cell::UnsafeCell
+/// [`UnsafeCell::raw_get`]: core::cell::UnsafeCell::raw_get
+/// [`addr_of_mut!`]: core::ptr::addr_of_mut
+#[macro_export]
+macro_rules! raw_field {
+    ($this:expr, $field:ident $(,)?) => {
+        ::core::cell::UnsafeCell::raw_get(::core::ptr::addr_of!((*$this).$field))
+    };
+}
+
+/// A pin-initializer for the type `T`.
+///
+/// To use this initializer, you will need a suitable memory location that can hold a `T`. This can
+/// be [`Box<T>`], [`Arc<T>`] or even the stack (see [`stack_pin_init!`]). Use the
+/// [`InPlaceInit::try_pin_init`] function of a smart pointer like [`Arc<T>`] on this.
+///
+/// Also see the [module description](self).
+///
+/// # Safety
+///
+/// When implementing this trait you will need to take great care. Also there are probably very few
+/// cases where a manual implementation is necessary. Use [`pin_init_from_closure`] where possible.
+///
+/// The [`PinInit::__pinned_init`] function:
+/// - returns `Ok(())` if it initialized every field of `slot`,
+/// - returns `Err(err)` if it encountered an error and then cleaned `slot`, this means:
+///     - `slot` can be deallocated without UB occurring,
+///     - `slot` does not need to be dropped,
+///     - `slot` is not partially initialized.
+/// - cleans `slot` in the same way before unwinding, if it panics. Callers treat a panic like an
+///   error and never drop `slot`, so anything left in it would be leaked without being dropped,
+///   which violates the drop guarantee of [`Pin`].
+/// - while constructing the `T` at `slot` it upholds the pinning invariants of `T`.
 ///
 /// [`Arc<T>`]: alloc::sync::Arc
 #[must_use = "An initializer must be used in order to create its value."]
-pub unsafe trait Init<T: ?Sized, E = Infallible>: PinInit<T, E> {
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not an initializer for `{T}` with error `{E}`",
+    label = "expected an `impl PinInit<{T}, {E}>`",
+    note = "initializers can be created with `[try_]pin_init!`, `[try_]init!` or the functions of this crate, a value of type `{T}` can also be used directly"
+)]
+pub unsafe trait PinInit<T: ?Sized, E = Infallible>: Sized {
     /// Initializes `slot`.
     ///
     /// # Safety
@@ -943,7 +1587,8 @@ pub unsafe trait Init<T: ?Sized, E = Infallible>: PinInit<T, E> {
     /// - `slot` is a valid pointer to uninitialized memory.
     /// - the caller does not touch `slot` when `Err` is returned, they are only permitted to
     ///   deallocate.
-    unsafe fn __init(self, slot: *mut T) -> Result<(), E>;
+    /// - `slot` will not move until it is dropped, i.e. it will be pinned.
+    unsafe fn __pinned_init(self, slot: *mut T) -> Result<(), E>;
 
     /// First initializes the value using `self` then calls the function `f` with the initialized
     /// value.
@@ -953,368 +1598,3099 @@ pub unsafe trait Init<T: ?Sized, E = Infallible>: PinInit<T, E> {
     /// # Examples
     ///
     /// ```rust
-    /// # #![expect(clippy::disallowed_names)]
+    /// # #![feature(allocator_api)]
+    /// # #[path = "../examples/mutex.rs"] mod mutex; use mutex::*;
     /// # use pinned_init::*;
-    /// struct Foo {
-    ///     buf: [u8; 1_000_000],
-    /// }
+    /// let mtx_init = CMutex::new(42);
+    /// // Make the initializer print the value.
+    /// let mtx_init = mtx_init.pin_chain(|mtx| {
+    ///     println!("{:?}", mtx.get_data_mut());
+    ///     Ok(())
+    /// });
+    /// ```
+    fn pin_chain<F>(self, f: F) -> ChainPinInit<Self, F, T, E>
+    where
+        F: FnOnce(Pin<&mut T>) -> Result<(), E>,
+    {
+        ChainPinInit(self, f, PhantomData)
+    }
+
+    /// First initializes the value using `self` then calls `register` with the address of the
+    /// initialized value.
     ///
-    /// impl Foo {
-    ///     fn setup(&mut self) {
-    ///         println!("Setting up foo");
-    ///     }
-    /// }
+    /// This is similar to [`PinInit::pin_chain`], but passes a [`NonNull<T>`] instead of a
+    /// `Pin<&mut T>`, which is more convenient for intrusive data structures that store the final
+    /// address of the value. The value stays pinned, so the address remains valid until the value
+    /// is dropped.
     ///
-    /// let foo = init!(Foo {
-    ///     buf <- zeroed()
-    /// }).chain(|foo| {
-    ///     foo.setup();
+    /// If `register` returns an error the value is dropped and the initializer will forward the
+    /// error. In that case `register` must not have stored the pointer anywhere.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #![feature(allocator_api)]
+    /// # #[path = "../examples/mutex.rs"] mod mutex; use mutex::*;
+    /// # use pinned_init::*;
+    /// # use core::{cell::Cell, ptr::NonNull};
+    /// let last = Cell::new(None);
+    /// let mtx_init = CMutex::new(42).pin_init_register(|mtx| {
+    ///     last.set(Some(mtx));
     ///     Ok(())
     /// });
+    /// stack_pin_init!(let mtx = mtx_init);
+    /// assert_eq!(last.get(), Some(NonNull::from(&*mtx)));
     /// ```
-    fn chain<F>(self, f: F) -> ChainInit<Self, F, T, E>
+    ///
+    /// [`NonNull<T>`]: core::ptr::NonNull
+    fn pin_init_register<F>(self, register: F) -> RegisterPinInit<Self, F, T, E>
     where
-        F: FnOnce(&mut T) -> Result<(), E>,
+        F: FnOnce(NonNull<T>) -> Result<(), E>,
     {
-        ChainInit(self, f, PhantomData)
+        RegisterPinInit(self, register, PhantomData)
     }
-}
-
-/// An initializer returned by [`Init::chain`].
-pub struct ChainInit<I, F, T: ?Sized, E>(I, F, __internal::Invariant<(E, *const T)>);
 
-// SAFETY: The `__init` function is implemented such that it
-// - returns `Ok(())` on successful initialization,
-// - returns `Err(err)` on error and in this case `slot` will be dropped.
-unsafe impl<T: ?Sized, E, I, F> Init<T, E> for ChainInit<I, F, T, E>
-where
-    I: Init<T, E>,
-    F: FnOnce(&mut T) -> Result<(), E>,
-{
-    unsafe fn __init(self, slot: *mut T) -> Result<(), E> {
-        // SAFETY: All requirements fulfilled since this function is `__init`.
-        unsafe { self.0.__pinned_init(slot)? };
-        // SAFETY: The above call initialized `slot` and we still have unique access.
-        (self.1)(unsafe { &mut *slot }).inspect_err(|_|
-            // SAFETY: `slot` was initialized above.
-            unsafe { core::ptr::drop_in_place(slot) })
+    /// First initializes the value using `self` then calls `check` with a shared reference to the
+    /// initialized value.
+    ///
+    /// This is a read-only version of [`PinInit::pin_chain`] that can be used to validate
+    /// invariants of the initialized value. If `check` returns an error the value is dropped and
+    /// the initializer will forward the error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use pinned_init::*;
+    /// #[derive(Debug)]
+    /// struct ContainsOdd;
+    ///
+    /// let init = pin_init_array_from_fn::<_, 4, u8, ContainsOdd>(|i| i as u8 * 2).validate(|array| {
+    ///     if array.iter().any(|x| x % 2 == 1) {
+    ///         Err(ContainsOdd)
+    ///     } else {
+    ///         Ok(())
+    ///     }
+    /// });
+    /// stack_try_pin_init!(let array: [u8; 4] = init);
+    /// assert_eq!(*array.unwrap(), [0, 2, 4, 6]);
+    /// ```
+    fn validate<F>(self, check: F) -> ValidatePinInit<Self, F, T, E>
+    where
+        F: FnOnce(Pin<&T>) -> Result<(), E>,
+    {
+        ValidatePinInit(self, check, PhantomData)
     }
-}
 
-// SAFETY: `__pinned_init` behaves exactly the same as `__init`.
-unsafe impl<T: ?Sized, E, I, F> PinInit<T, E> for ChainInit<I, F, T, E>
-where
-    I: Init<T, E>,
-    F: FnOnce(&mut T) -> Result<(), E>,
-{
-    unsafe fn __pinned_init(self, slot: *mut T) -> Result<(), E> {
-        // SAFETY: `__init` has less strict requirements compared to `__pinned_init`.
-        unsafe { self.__init(slot) }
+    /// Checks `token` before running the initializer and aborts with [`Cancelled`] if it has been
+    /// cancelled.
+    ///
+    /// The returned initializer has the error type `E2`, which needs to be constructible from both
+    /// `E` and [`Cancelled`].
+    ///
+    /// Initializers are all-or-nothing, so the check can only happen before `self` starts running.
+    /// Once `self` has started, it runs to completion. To cancel a composite initializer in between
+    /// the initialization of its fields, use this function on the initializer of each field. Since
+    /// the error type of the field initializers cannot be inferred in that case, you have to specify
+    /// it explicitly: `with_cancel::<Error>(&token)`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #![feature(allocator_api)]
+    /// # use pinned_init::*;
+    /// # use core::{alloc::AllocError, convert::Infallible};
+    /// #[derive(Debug)]
+    /// enum Error {
+    ///     Cancelled,
+    ///     Alloc,
+    /// }
+    ///
+    /// impl From<Infallible> for Error {
+    ///     fn from(e: Infallible) -> Self {
+    ///         match e {}
+    ///     }
+    /// }
+    ///
+    /// impl From<Cancelled> for Error {
+    ///     fn from(_: Cancelled) -> Self {
+    ///         Self::Cancelled
+    ///     }
+    /// }
+    ///
+    /// impl From<AllocError> for Error {
+    ///     fn from(_: AllocError) -> Self {
+    ///         Self::Alloc
+    ///     }
+    /// }
+    ///
+    /// let token = CancelToken::new();
+    /// token.cancel();
+    /// let res: Result<Box<[u8; 1024 * 1024]>, Error> =
+    ///     Box::try_init(zeroed().with_cancel(&token));
+    /// assert!(matches!(res, Err(Error::Cancelled)));
+    /// ```
+    fn with_cancel<E2>(self, token: &CancelToken) -> WithCancel<'_, Self, T, E, E2>
+    where
+        E2: From<E> + From<Cancelled>,
+    {
+        WithCancel(self, token, PhantomData)
     }
-}
 
-/// Creates a new [`PinInit<T, E>`] from the given closure.
-///
-/// # Safety
-///
-/// The closure:
-/// - returns `Ok(())` if it initialized every field of `slot`,
-/// - returns `Err(err)` if it encountered an error and then cleaned `slot`, this means:
-///     - `slot` can be deallocated without UB occurring,
-///     - `slot` does not need to be dropped,
-///     - `slot` is not partially initialized.
-/// - may assume that the `slot` does not move if `T: !Unpin`,
-/// - while constructing the `T` at `slot` it upholds the pinning invariants of `T`.
-#[inline]
-pub const unsafe fn pin_init_from_closure<T: ?Sized, E>(
-    f: impl FnOnce(*mut T) -> Result<(), E>,
-) -> impl PinInit<T, E> {
-    __internal::InitClosure(f, PhantomData)
-}
+    /// Catches panics that occur while running the initializer and returns them as
+    /// [`PanicOrError::Panic`].
+    ///
+    /// This is useful at FFI boundaries where unwinding is not allowed. When a panic is caught,
+    /// `slot` is left in the same state as when the initializer returns an error, since every
+    /// initializer is required to clean up `slot` when it panics.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use pinned_init::*;
+    /// # use core::convert::Infallible;
+    /// let init = pin_init_array_from_fn::<_, 8, u8, Infallible>(|i| {
+    ///     assert!(i < 4);
+    ///     i as u8
+    /// });
+    /// stack_try_pin_init!(let array: [u8; 8] = init.catch_unwind());
+    /// assert!(matches!(array, Err(PanicOrError::Panic(_))));
+    /// ```
+    #[cfg(feature = "std")]
+    fn catch_unwind(self) -> CatchUnwind<Self, T, E> {
+        CatchUnwind(self, PhantomData)
+    }
 
-/// Creates a new [`Init<T, E>`] from the given closure.
-///
-/// # Safety
+    /// Turns this initializer for `T` into an initializer for the transparent wrapper `W` of `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #![feature(allocator_api)]
+    /// # #[path = "../examples/mutex.rs"] mod mutex; use mutex::*;
+    /// # use pinned_init::*;
+    /// #[derive(TransparentWrapper)]
+    /// #[repr(transparent)]
+    /// struct Counter(CMutex<usize>);
+    ///
+    /// stack_pin_init!(let counter: Counter = CMutex::new(0).wrap());
+    /// assert_eq!(*counter.0.lock(), 0);
+    /// ```
+    fn wrap<W>(self) -> Wrap<Self, W, E>
+    where
+        W: TransparentWrapper<Inner = T>,
+    {
+        Wrap(self, PhantomData)
+    }
+
+    /// Runs this initializer and falls back to zeroing the slot when it fails.
+    ///
+    /// This is useful for best-effort initialization, where a failure should not be propagated.
+    /// The error returned by `self` is discarded. Since every initializer cleans up `slot` before
+    /// it returns an error, the fields that `self` already initialized have been dropped at that
+    /// point. Only afterwards the slot is overwritten with zeroes, so nothing is leaked.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use pinned_init::*;
+    /// #[derive(Debug)]
+    /// struct Error;
+    ///
+    /// let init = pin_init_array_from_fn::<_, 4, u8, Error>(|i| i as u8 + 1)
+    ///     .pin_chain(|_| Err(Error));
+    /// stack_pin_init!(let array: [u8; 4] = init.or_zeroed());
+    /// assert_eq!(*array, [0; 4]);
+    /// ```
+    fn or_zeroed(self) -> OrZeroed<Self, T, E>
+    where
+        T: Zeroable + Sized,
+    {
+        OrZeroed(self, PhantomData)
+    }
+
+    /// Boxes this initializer, such that its type does not depend on the type of `self`.
+    ///
+    /// Functions that are generic over an initializer are instantiated once for every initializer
+    /// type. For big structs with deeply nested initializers, this can increase the size of the
+    /// binary considerably. Erasing the initializer before passing it to such a function makes all
+    /// call sites use the same instance.
+    ///
+    /// This costs an allocation for the initializer and a dynamic call when it runs, so only use it
+    /// when the code size matters more. If the allocation fails, the returned initializer fails
+    /// with the [`AllocError`] converted into `E`, without touching the slot.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #![feature(allocator_api)]
+    /// # #[path = "../examples/mutex.rs"] mod mutex; use mutex::*;
+    /// # use pinned_init::*;
+    /// # use core::pin::Pin;
+    /// // This function is only instantiated once.
+    /// fn alloc_counter(init: ErasedPinInit<'_, CMutex<usize>>) -> Pin<Box<CMutex<usize>>> {
+    ///     Box::try_pin_init(init).unwrap()
+    /// }
+    ///
+    /// let a = alloc_counter(pin_infallible(CMutex::new(1)).erase());
+    /// let b = alloc_counter(pin_infallible(CMutex::new(2)).pin_chain(|_| Ok(())).erase());
+    /// assert_eq!(*a.lock() + *b.lock(), 3);
+    /// ```
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    fn erase<'a>(self) -> ErasedPinInit<'a, T, E>
+    where
+        Self: 'a,
+        T: Sized,
+        E: From<AllocError>,
+    {
+        ErasedPinInit(try_box_dyn(self))
+    }
+
+    /// Turns this pin-initializer into an [`Init`], since `T` is [`Unpin`].
+    ///
+    /// [`pin_init!`] always creates a [`PinInit`], even when the struct does not contain any
+    /// genuinely pinned fields. When the struct is [`Unpin`] anyway, moving it after initialization
+    /// cannot break any pinning invariants, so it can be initialized in places that are not pinned,
+    /// such as a `Box<T>`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #![feature(allocator_api)]
+    /// # use pinned_init::*;
+    /// #[pin_data]
+    /// struct Point {
+    ///     x: i32,
+    ///     y: i32,
+    /// }
+    ///
+    /// let point: Box<Point> = Box::init(pin_init!(Point { x: 1, y: 2 }).into_init()).unwrap();
+    /// let point = *point;
+    /// assert_eq!(point.x + point.y, 3);
+    /// ```
+    fn into_init(self) -> IntoInit<Self, T, E>
+    where
+        T: Unpin,
+    {
+        IntoInit(self, PhantomData)
+    }
+}
+
+/// An initializer returned by [`PinInit::wrap`].
+pub struct Wrap<I, W, E>(I, __internal::Invariant<(E, *const W)>);
+
+// SAFETY: `W` has the same layout as `W::Inner` and every valid `W::Inner` is a valid `W`, so
+// initializing `slot` as a `W::Inner` initializes it as a `W`. `W::Inner` is structurally pinned,
+// so it may be pinned when `W` is.
+unsafe impl<W, E, I> PinInit<W, E> for Wrap<I, W, E>
+where
+    W: TransparentWrapper,
+    I: PinInit<W::Inner, E>,
+{
+    unsafe fn __pinned_init(self, slot: *mut W) -> Result<(), E> {
+        // SAFETY: All requirements fulfilled since this function is `__pinned_init`.
+        unsafe { self.0.__pinned_init(slot.cast::<W::Inner>()) }
+    }
+}
+
+// SAFETY: `W` has the same layout as `W::Inner` and every valid `W::Inner` is a valid `W`, so
+// initializing `slot` as a `W::Inner` initializes it as a `W`.
+unsafe impl<W, E, I> Init<W, E> for Wrap<I, W, E>
+where
+    W: TransparentWrapper,
+    I: Init<W::Inner, E>,
+{
+    unsafe fn __init(self, slot: *mut W) -> Result<(), E> {
+        // SAFETY: All requirements fulfilled since this function is `__init`.
+        unsafe { self.0.__init(slot.cast::<W::Inner>()) }
+    }
+}
+
+impl<W, E, I> PinPlaceInit<W, E> for Wrap<I, W, E>
+where
+    W: TransparentWrapper,
+    I: PinPlaceInit<W::Inner, E>,
+{
+}
+
+impl<W, E, I> PlaceInit<W, E> for Wrap<I, W, E>
+where
+    W: TransparentWrapper,
+    I: PlaceInit<W::Inner, E>,
+{
+}
+
+/// Marker trait for transparent wrappers around `Self::Inner`.
+///
+/// This allows turning initializers for `Self::Inner` into initializers for `Self` via
+/// [`PinInit::wrap`]. Use [`derive@TransparentWrapper`] or [`derive@InitForward`] to implement this
+/// trait.
+///
+/// # Safety
+///
+/// - `Self` has the same layout as `Self::Inner`, e.g. it is a `#[repr(transparent)]` struct with
+///   `Self::Inner` as its only field,
+/// - every valid `Self::Inner` is a valid `Self`,
+/// - `Self::Inner` is structurally pinned, i.e. `Self` only implements `Unpin` if `Self::Inner`
+///   does and it never moves the inner value out of a pinned `Self`, not even in its `Drop`
+///   implementation.
+pub unsafe trait TransparentWrapper {
+    /// The wrapped type.
+    type Inner;
+}
+
+/// An initializer returned by [`PinInit::with_cancel`].
+pub struct WithCancel<'a, I, T: ?Sized, E, E2>(
+    I,
+    &'a CancelToken,
+    __internal::Invariant<(E, E2, *const T)>,
+);
+
+// SAFETY: The `__pinned_init` function either returns `Err` without touching `slot` or delegates
+// to `self.0`, which upholds all requirements.
+unsafe impl<T: ?Sized, E, E2, I> PinInit<T, E2> for WithCancel<'_, I, T, E, E2>
+where
+    I: PinInit<T, E>,
+    E2: From<E> + From<Cancelled>,
+{
+    unsafe fn __pinned_init(self, slot: *mut T) -> Result<(), E2> {
+        if self.1.is_cancelled() {
+            return Err(Cancelled.into());
+        }
+        // SAFETY: All requirements fulfilled since this function is `__pinned_init`.
+        unsafe { self.0.__pinned_init(slot) }.map_err(E2::from)
+    }
+}
+
+// SAFETY: The `__init` function either returns `Err` without touching `slot` or delegates to
+// `self.0`, which upholds all requirements.
+unsafe impl<T: ?Sized, E, E2, I> Init<T, E2> for WithCancel<'_, I, T, E, E2>
+where
+    I: Init<T, E>,
+    E2: From<E> + From<Cancelled>,
+{
+    unsafe fn __init(self, slot: *mut T) -> Result<(), E2> {
+        if self.1.is_cancelled() {
+            return Err(Cancelled.into());
+        }
+        // SAFETY: All requirements fulfilled since this function is `__init`.
+        unsafe { self.0.__init(slot) }.map_err(E2::from)
+    }
+}
+
+/// A flag that can be used to cancel initializers before they start.
+///
+/// See [`PinInit::with_cancel`].
+#[derive(Debug, Default)]
+pub struct CancelToken(AtomicBool);
+
+impl CancelToken {
+    /// Creates a new token that is not cancelled.
+    #[inline]
+    pub const fn new() -> Self {
+        Self(AtomicBool::new(false))
+    }
+
+    /// Cancels every initializer that checks this token and has not started yet.
+    #[inline]
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Release);
+    }
+
+    /// Returns `true` if [`CancelToken::cancel`] has been called.
+    #[inline]
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Acquire)
+    }
+}
+
+/// The error returned by initializers that have been cancelled via a [`CancelToken`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cancelled;
+
+/// An initializer returned by [`PinInit::or_zeroed`].
+pub struct OrZeroed<I, T, E>(I, __internal::Invariant<(E, *const T)>);
+
+// SAFETY: The `__pinned_init` function is implemented such that it
+// - returns `Ok(())` when `self.0` initialized `slot`,
+// - otherwise `self.0` has cleaned up `slot`, so it is uninitialized. Then it is initialized by
+//   writing zeroes, which is a valid bit pattern for `T`, and `Ok(())` is returned.
+// - when `self.0` panics, it has cleaned up `slot` before unwinding.
+unsafe impl<T: Zeroable, E, I> PinInit<T> for OrZeroed<I, T, E>
+where
+    I: PinInit<T, E>,
+{
+    unsafe fn __pinned_init(self, slot: *mut T) -> Result<(), Infallible> {
+        // SAFETY: All requirements fulfilled since this function is `__pinned_init`.
+        if unsafe { self.0.__pinned_init(slot) }.is_err() {
+            // SAFETY: `slot` is valid for writes and uninitialized, since `self.0` failed.
+            unsafe { slot.write_bytes(0, 1) };
+        }
+        Ok(())
+    }
+}
+
+// SAFETY: The `__init` function is implemented such that it
+// - returns `Ok(())` when `self.0` initialized `slot`,
+// - otherwise `self.0` has cleaned up `slot`, so it is uninitialized. Then it is initialized by
+//   writing zeroes, which is a valid bit pattern for `T`, and `Ok(())` is returned.
+// - when `self.0` panics, it has cleaned up `slot` before unwinding.
+unsafe impl<T: Zeroable, E, I> Init<T> for OrZeroed<I, T, E>
+where
+    I: Init<T, E>,
+{
+    unsafe fn __init(self, slot: *mut T) -> Result<(), Infallible> {
+        // SAFETY: All requirements fulfilled since this function is `__init`.
+        if unsafe { self.0.__init(slot) }.is_err() {
+            // SAFETY: `slot` is valid for writes and uninitialized, since `self.0` failed.
+            unsafe { slot.write_bytes(0, 1) };
+        }
+        Ok(())
+    }
+}
+
+/// An initializer returned by [`PinInit::into_init`].
+pub struct IntoInit<I, T: ?Sized, E>(I, __internal::Invariant<(E, *const T)>);
+
+// SAFETY: The `__pinned_init` function delegates to `self.0`.
+unsafe impl<T: ?Sized, E, I> PinInit<T, E> for IntoInit<I, T, E>
+where
+    I: PinInit<T, E>,
+{
+    unsafe fn __pinned_init(self, slot: *mut T) -> Result<(), E> {
+        // SAFETY: All requirements fulfilled since this function is `__pinned_init`.
+        unsafe { self.0.__pinned_init(slot) }
+    }
+}
+
+// SAFETY: The `__init` function executes the same code as `__pinned_init`. Since `T: Unpin`,
+// `self.0` cannot rely on `slot` staying pinned, neither during nor after initialization.
+unsafe impl<T: ?Sized + Unpin, E, I> Init<T, E> for IntoInit<I, T, E>
+where
+    I: PinInit<T, E>,
+{
+    unsafe fn __init(self, slot: *mut T) -> Result<(), E> {
+        // SAFETY: `slot` is valid and uninitialized. It is not pinned, but since `T: Unpin`, this
+        // makes no difference to `self.0`.
+        unsafe { self.0.__pinned_init(slot) }
+    }
+}
+
+/// An initializer returned by [`PinInit::catch_unwind`].
+#[cfg(feature = "std")]
+pub struct CatchUnwind<I, T: ?Sized, E>(I, __internal::Invariant<(E, *const T)>);
+
+// SAFETY: The `__pinned_init` function delegates to `self.0`. When it panics, `self.0` has already
+// cleaned up `slot`, so `slot` is in the same state as if `self.0` had returned an error.
+#[cfg(feature = "std")]
+unsafe impl<T: ?Sized, E, I> PinInit<T, PanicOrError<E>> for CatchUnwind<I, T, E>
+where
+    I: PinInit<T, E>,
+{
+    unsafe fn __pinned_init(self, slot: *mut T) -> Result<(), PanicOrError<E>> {
+        let init = self.0;
+        // SAFETY: All requirements fulfilled since this function is `__pinned_init`.
+        let res = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| unsafe {
+            init.__pinned_init(slot)
+        }));
+        match res {
+            Ok(res) => res.map_err(PanicOrError::Error),
+            Err(payload) => Err(PanicOrError::Panic(payload)),
+        }
+    }
+}
+
+// SAFETY: The `__init` function delegates to `self.0`. When it panics, `self.0` has already
+// cleaned up `slot`, so `slot` is in the same state as if `self.0` had returned an error.
+#[cfg(feature = "std")]
+unsafe impl<T: ?Sized, E, I> Init<T, PanicOrError<E>> for CatchUnwind<I, T, E>
+where
+    I: Init<T, E>,
+{
+    unsafe fn __init(self, slot: *mut T) -> Result<(), PanicOrError<E>> {
+        let init = self.0;
+        // SAFETY: All requirements fulfilled since this function is `__init`.
+        let res = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| unsafe {
+            init.__init(slot)
+        }));
+        match res {
+            Ok(res) => res.map_err(PanicOrError::Error),
+            Err(payload) => Err(PanicOrError::Panic(payload)),
+        }
+    }
+}
+
+/// The error returned by initializers created by [`PinInit::catch_unwind`].
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub enum PanicOrError<E> {
+    /// The initializer panicked, contains the panic payload.
+    Panic(Box<dyn core::any::Any + Send + 'static>),
+    /// The initializer returned an error.
+    Error(E),
+}
+
+#[cfg(feature = "std")]
+impl<E> From<AllocError> for PanicOrError<E>
+where
+    E: From<AllocError>,
+{
+    fn from(err: AllocError) -> Self {
+        Self::Error(err.into())
+    }
+}
+
+/// An initializer returned by [`PinInit::pin_chain`].
+pub struct ChainPinInit<I, F, T: ?Sized, E>(I, F, __internal::Invariant<(E, *const T)>);
+
+// SAFETY: The `__pinned_init` function is implemented such that it
+// - returns `Ok(())` on successful initialization,
+// - returns `Err(err)` on error and in this case `slot` will be dropped.
+// - considers `slot` pinned.
+unsafe impl<T: ?Sized, E, I, F> PinInit<T, E> for ChainPinInit<I, F, T, E>
+where
+    I: PinInit<T, E>,
+    F: FnOnce(Pin<&mut T>) -> Result<(), E>,
+{
+    unsafe fn __pinned_init(self, slot: *mut T) -> Result<(), E> {
+        // SAFETY: All requirements fulfilled since this function is `__pinned_init`.
+        unsafe { self.0.__pinned_init(slot)? };
+        // SAFETY: `slot` was initialized above. If `self.1` fails or panics, the value is dropped
+        // by the guard and `slot` is considered uninitialized memory again.
+        let guard = unsafe { __internal::DropGuard::new(slot) };
+        // SAFETY: The above call initialized `slot` and we still have unique access.
+        let val = unsafe { &mut *slot };
+        // SAFETY: `slot` is considered pinned.
+        let val = unsafe { Pin::new_unchecked(val) };
+        (self.1)(val)?;
+        core::mem::forget(guard);
+        Ok(())
+    }
+}
+
+impl<T: ?Sized, E, I, F> PinPlaceInit<T, E> for ChainPinInit<I, F, T, E>
+where
+    I: PinPlaceInit<T, E>,
+    F: FnOnce(Pin<&mut T>) -> Result<(), E>,
+{
+}
+
+/// An initializer returned by [`PinInit::pin_init_register`].
+pub struct RegisterPinInit<I, F, T: ?Sized, E>(I, F, __internal::Invariant<(E, *const T)>);
+
+// SAFETY: The `__pinned_init` function is implemented such that it
+// - returns `Ok(())` on successful initialization,
+// - returns `Err(err)` on error and in this case `slot` will be dropped.
+// - considers `slot` pinned.
+unsafe impl<T: ?Sized, E, I, F> PinInit<T, E> for RegisterPinInit<I, F, T, E>
+where
+    I: PinInit<T, E>,
+    F: FnOnce(NonNull<T>) -> Result<(), E>,
+{
+    unsafe fn __pinned_init(self, slot: *mut T) -> Result<(), E> {
+        // SAFETY: All requirements fulfilled since this function is `__pinned_init`.
+        unsafe { self.0.__pinned_init(slot)? };
+        // SAFETY: `slot` was initialized above. If `self.1` fails or panics, the value is dropped
+        // by the guard and `slot` is considered uninitialized memory again.
+        let guard = unsafe { __internal::DropGuard::new(slot) };
+        // SAFETY: `slot` is a valid pointer and thus not null.
+        let ptr = unsafe { NonNull::new_unchecked(slot) };
+        (self.1)(ptr)?;
+        core::mem::forget(guard);
+        Ok(())
+    }
+}
+
+impl<T: ?Sized, E, I, F> PinPlaceInit<T, E> for RegisterPinInit<I, F, T, E>
+where
+    I: PinPlaceInit<T, E>,
+    F: FnOnce(NonNull<T>) -> Result<(), E>,
+{
+}
+
+/// An initializer returned by [`PinInit::validate`].
+pub struct ValidatePinInit<I, F, T: ?Sized, E>(I, F, __internal::Invariant<(E, *const T)>);
+
+// SAFETY: The `__pinned_init` function is implemented such that it
+// - returns `Ok(())` on successful initialization,
+// - returns `Err(err)` on error and in this case `slot` will be dropped.
+// - considers `slot` pinned.
+unsafe impl<T: ?Sized, E, I, F> PinInit<T, E> for ValidatePinInit<I, F, T, E>
+where
+    I: PinInit<T, E>,
+    F: FnOnce(Pin<&T>) -> Result<(), E>,
+{
+    unsafe fn __pinned_init(self, slot: *mut T) -> Result<(), E> {
+        // SAFETY: All requirements fulfilled since this function is `__pinned_init`.
+        unsafe { self.0.__pinned_init(slot)? };
+        // SAFETY: `slot` was initialized above. If `self.1` fails or panics, the value is dropped
+        // by the guard and `slot` is considered uninitialized memory again.
+        let guard = unsafe { __internal::DropGuard::new(slot) };
+        // SAFETY: The above call initialized `slot`.
+        let val = unsafe { &*slot };
+        // SAFETY: `slot` is considered pinned.
+        let val = unsafe { Pin::new_unchecked(val) };
+        (self.1)(val)?;
+        core::mem::forget(guard);
+        Ok(())
+    }
+}
+
+impl<T: ?Sized, E, I, F> PinPlaceInit<T, E> for ValidatePinInit<I, F, T, E>
+where
+    I: PinPlaceInit<T, E>,
+    F: FnOnce(Pin<&T>) -> Result<(), E>,
+{
+}
+
+/// An initializer for `T`.
+///
+/// To use this initializer, you will need a suitable memory location that can hold a `T`. This can
+/// be [`Box<T>`], [`Arc<T>`] or even the stack (see [`stack_pin_init!`]). Use the
+/// [`InPlaceInit::try_init`] function of a smart pointer like [`Arc<T>`] on this. Because
+/// [`PinInit<T, E>`] is a super trait, you can use every function that takes it as well.
+///
+/// Also see the [module description](self).
+///
+/// # Safety
+///
+/// When implementing this trait you will need to take great care. Also there are probably very few
+/// cases where a manual implementation is necessary. Use [`init_from_closure`] where possible.
+///
+/// The [`Init::__init`] function:
+/// - returns `Ok(())` if it initialized every field of `slot`,
+/// - returns `Err(err)` if it encountered an error and then cleaned `slot`, this means:
+///     - `slot` can be deallocated without UB occurring,
+///     - `slot` does not need to be dropped,
+///     - `slot` is not partially initialized.
+/// - cleans `slot` in the same way before unwinding, if it panics.
+/// - while constructing the `T` at `slot` it upholds the pinning invariants of `T`.
+///
+/// The `__pinned_init` function from the supertrait [`PinInit`] needs to execute the exact same
+/// code as `__init`.
+///
+/// Contrary to its supertype [`PinInit<T, E>`] the caller is allowed to
+/// move the pointee after initialization.
+///
+/// [`Arc<T>`]: alloc::sync::Arc
+#[must_use = "An initializer must be used in order to create its value."]
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not an initializer for `{T}` with error `{E}` that allows moving",
+    label = "expected an `impl Init<{T}, {E}>`",
+    note = "pinned initializers cannot be used here, since `{T}` might be moved after initialization; consider using `[try_]pin_init!` or marking the field with `#[pin]`"
+)]
+pub unsafe trait Init<T: ?Sized, E = Infallible>: PinInit<T, E> {
+    /// Initializes `slot`.
+    ///
+    /// # Safety
+    ///
+    /// - `slot` is a valid pointer to uninitialized memory.
+    /// - the caller does not touch `slot` when `Err` is returned, they are only permitted to
+    ///   deallocate.
+    unsafe fn __init(self, slot: *mut T) -> Result<(), E>;
+
+    /// First initializes the value using `self` then calls the function `f` with the initialized
+    /// value.
+    ///
+    /// If `f` returns an error the value is dropped and the initializer will forward the error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #![expect(clippy::disallowed_names)]
+    /// # use pinned_init::*;
+    /// struct Foo {
+    ///     buf: [u8; 1_000_000],
+    /// }
+    ///
+    /// impl Foo {
+    ///     fn setup(&mut self) {
+    ///         println!("Setting up foo");
+    ///     }
+    /// }
+    ///
+    /// let foo = init!(Foo {
+    ///     buf <- zeroed()
+    /// }).chain(|foo| {
+    ///     foo.setup();
+    ///     Ok(())
+    /// });
+    /// ```
+    fn chain<F>(self, f: F) -> ChainInit<Self, F, T, E>
+    where
+        F: FnOnce(&mut T) -> Result<(), E>,
+    {
+        ChainInit(self, f, PhantomData)
+    }
+
+    /// Zeroes the slot before initializing it with `self`.
+    ///
+    /// This ensures that padding bytes that are not written by `self` are zero, which is for
+    /// example needed for structs that are compared or hashed bytewise by C code. Padding between
+    /// fields initialized by [`init!`] or [`try_init!`] is not written, but padding inside of
+    /// values that are moved into the slot might be uninitialized again afterwards.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use pinned_init::*;
+    /// # use core::mem::MaybeUninit;
+    /// #[repr(C)]
+    /// struct Request {
+    ///     kind: u8,
+    ///     // Three padding bytes.
+    ///     len: u32,
+    /// }
+    ///
+    /// let mut req = MaybeUninit::<[u8; 8]>::uninit();
+    /// let init = init!(Request { kind: 1, len: 2 }).zero_padding();
+    /// // SAFETY: `req` is valid for writes and big enough for a `Request`.
+    /// let Ok(()) = unsafe { init.__init(req.as_mut_ptr().cast::<Request>()) };
+    /// // SAFETY: All bytes have been initialized, the padding bytes are zero.
+    /// let bytes = unsafe { req.assume_init() };
+    /// assert_eq!(bytes, [1, 0, 0, 0, 2u32.to_ne_bytes()[0], 2u32.to_ne_bytes()[1], 0, 0]);
+    /// ```
+    fn zero_padding(self) -> ZeroPadding<Self, T, E>
+    where
+        T: Sized,
+    {
+        ZeroPadding(self, PhantomData)
+    }
+
+    /// Verifies that `self` writes every byte of the slot.
+    ///
+    /// When the `debug-poison` feature and debug assertions are enabled, the slot is filled with
+    /// `0xAA` bytes before running `self`. Afterwards every byte is checked and if a byte still
+    /// contains `0xAA`, the initializer panics. The value is not dropped in that case, since it might
+    /// be invalid, so it is leaked. Otherwise this only runs `self`.
+    ///
+    /// This helps to find manual initializers that forget to initialize parts of the value. Since
+    /// the check cannot distinguish the poison from written `0xAA` bytes, it only works for values
+    /// that do not contain such bytes.
+    ///
+    /// # Safety
+    ///
+    /// `T` must not contain padding bytes, [`MaybeUninit`] fields or union fields. Writing a value
+    /// can leave such bytes uninitialized, so they cannot be checked.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use pinned_init::*;
+    /// #[derive(Debug, PartialEq)]
+    /// struct Header {
+    ///     magic: u32,
+    ///     len: u32,
+    /// }
+    ///
+    /// // SAFETY: `Header` has no padding bytes.
+    /// let init = unsafe { init!(Header { magic: 0x7f45_4c46, len: 64 }).with_len_check() };
+    /// let header: Box<Header> = Box::init(init).unwrap();
+    /// assert_eq!(header.len, 64);
+    /// ```
+    unsafe fn with_len_check(self) -> LenCheck<Self, T, E>
+    where
+        T: Sized,
+    {
+        LenCheck(self, PhantomData)
+    }
+
+    /// Boxes this initializer, such that its type does not depend on the type of `self`.
+    ///
+    /// This is the [`Init`] version of [`PinInit::erase`], the returned initializer can still be
+    /// used for values that are not pinned.
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    fn erase_init<'a>(self) -> ErasedInit<'a, T, E>
+    where
+        Self: 'a,
+        T: Sized + 'a,
+        E: From<AllocError> + 'a,
+    {
+        ErasedInit(try_box_dyn(InitAsPinInit(self, PhantomData)))
+    }
+}
+
+/// An initializer returned by [`Init::chain`].
+pub struct ChainInit<I, F, T: ?Sized, E>(I, F, __internal::Invariant<(E, *const T)>);
+
+// SAFETY: The `__init` function is implemented such that it
+// - returns `Ok(())` on successful initialization,
+// - returns `Err(err)` on error and in this case `slot` will be dropped.
+unsafe impl<T: ?Sized, E, I, F> Init<T, E> for ChainInit<I, F, T, E>
+where
+    I: Init<T, E>,
+    F: FnOnce(&mut T) -> Result<(), E>,
+{
+    unsafe fn __init(self, slot: *mut T) -> Result<(), E> {
+        // SAFETY: All requirements fulfilled since this function is `__init`.
+        unsafe { self.0.__pinned_init(slot)? };
+        // SAFETY: `slot` was initialized above. If `self.1` fails or panics, the value is dropped
+        // by the guard and `slot` is considered uninitialized memory again.
+        let guard = unsafe { __internal::DropGuard::new(slot) };
+        // SAFETY: The above call initialized `slot` and we still have unique access.
+        (self.1)(unsafe { &mut *slot })?;
+        core::mem::forget(guard);
+        Ok(())
+    }
+}
+
+// SAFETY: `__pinned_init` behaves exactly the same as `__init`.
+unsafe impl<T: ?Sized, E, I, F> PinInit<T, E> for ChainInit<I, F, T, E>
+where
+    I: Init<T, E>,
+    F: FnOnce(&mut T) -> Result<(), E>,
+{
+    unsafe fn __pinned_init(self, slot: *mut T) -> Result<(), E> {
+        // SAFETY: `__init` has less strict requirements compared to `__pinned_init`.
+        unsafe { self.__init(slot) }
+    }
+}
+
+impl<T: ?Sized, E, I, F> PinPlaceInit<T, E> for ChainInit<I, F, T, E>
+where
+    I: PlaceInit<T, E>,
+    F: FnOnce(&mut T) -> Result<(), E>,
+{
+}
+
+impl<T: ?Sized, E, I, F> PlaceInit<T, E> for ChainInit<I, F, T, E>
+where
+    I: PlaceInit<T, E>,
+    F: FnOnce(&mut T) -> Result<(), E>,
+{
+}
+
+/// An initializer returned by [`Init::zero_padding`].
+pub struct ZeroPadding<I, T, E>(I, __internal::Invariant<(E, *const T)>);
+
+// SAFETY: The `__init` function is implemented such that it
+// - returns `Ok(())` on successful initialization,
+// - returns `Err(err)` on error, the inner initializer leaves `slot` uninitialized.
+unsafe impl<T, E, I> Init<T, E> for ZeroPadding<I, T, E>
+where
+    I: Init<T, E>,
+{
+    unsafe fn __init(self, slot: *mut T) -> Result<(), E> {
+        // SAFETY: `slot` is valid for writes and it is uninitialized, so any bit pattern is fine.
+        unsafe { slot.write_bytes(0, 1) };
+        // SAFETY: All requirements fulfilled since this function is `__init`. The zeroes written
+        // above are considered uninitialized memory by `self.0`.
+        unsafe { self.0.__init(slot) }
+    }
+}
+
+// SAFETY: `__pinned_init` behaves exactly the same as `__init`.
+unsafe impl<T, E, I> PinInit<T, E> for ZeroPadding<I, T, E>
+where
+    I: Init<T, E>,
+{
+    unsafe fn __pinned_init(self, slot: *mut T) -> Result<(), E> {
+        // SAFETY: `__init` has less strict requirements compared to `__pinned_init`.
+        unsafe { self.__init(slot) }
+    }
+}
+
+/// An initializer returned by [`Init::with_len_check`].
+pub struct LenCheck<I, T, E>(I, __internal::Invariant<(E, *const T)>);
+
+// SAFETY: The `__init` function is implemented such that it
+// - returns `Ok(())` on successful initialization,
+// - returns `Err(err)` on error, the inner initializer leaves `slot` uninitialized,
+// - only panics when the inner initializer did not initialize `slot`, which breaks its safety
+//   contract. The value might be invalid, so it is leaked instead of being dropped.
+unsafe impl<T, E, I> Init<T, E> for LenCheck<I, T, E>
+where
+    I: Init<T, E>,
+{
+    unsafe fn __init(self, slot: *mut T) -> Result<(), E> {
+        // SAFETY: `slot` is valid for writes and does not contain an initialized value.
+        unsafe { __internal::poison(slot) };
+        // SAFETY: All requirements fulfilled since this function is `__init`. The poison written
+        // above is considered uninitialized memory by `self.0`.
+        unsafe { self.0.__init(slot)? };
+        #[cfg(all(feature = "debug-poison", debug_assertions))]
+        {
+            let bytes = slot.cast::<u8>();
+            for offset in 0..core::mem::size_of::<T>() {
+                // SAFETY: `offset` is in bounds of `slot`. Every byte was initialized by the poison
+                // above and the creator of `self` guarantees that `T` has no padding bytes,
+                // `MaybeUninit` or union fields, so writing the value did not uninitialize any of
+                // them.
+                if unsafe { bytes.add(offset).read() } == 0xAA {
+                    panic!(
+                        "byte {offset} of `{}` was not written by the initializer",
+                        core::any::type_name::<T>()
+                    );
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+// SAFETY: `__pinned_init` behaves exactly the same as `__init`.
+unsafe impl<T, E, I> PinInit<T, E> for LenCheck<I, T, E>
+where
+    I: Init<T, E>,
+{
+    unsafe fn __pinned_init(self, slot: *mut T) -> Result<(), E> {
+        // SAFETY: `__init` has less strict requirements compared to `__pinned_init`.
+        unsafe { self.__init(slot) }
+    }
+}
+
+/// Creates a new [`PinInit<T, E>`] from the given closure.
+///
+/// # Safety
+///
+/// The closure:
+/// - returns `Ok(())` if it initialized every field of `slot`,
+/// - returns `Err(err)` if it encountered an error and then cleaned `slot`, this means:
+///     - `slot` can be deallocated without UB occurring,
+///     - `slot` does not need to be dropped,
+///     - `slot` is not partially initialized.
+/// - cleans `slot` in the same way before unwinding, if it panics,
+/// - may assume that the `slot` does not move if `T: !Unpin`,
+/// - while constructing the `T` at `slot` it upholds the pinning invariants of `T`.
+#[inline]
+pub const unsafe fn pin_init_from_closure<T: ?Sized, E>(
+    f: impl FnOnce(*mut T) -> Result<(), E>,
+) -> impl PinPlaceInit<T, E> {
+    __internal::InitClosure(f, PhantomData)
+}
+
+/// Creates a new [`PinInit<T, E>`] from the given closure that receives the slot as a reference.
+///
+/// In contrast to [`pin_init_from_closure`], the slot is given to the closure as a
+/// `&'a mut MaybeUninit<T>`. This allows expressing that the lifetime `'a` of the slot is related
+/// to the lifetimes of borrowed inputs of the closure. For example a closure that registers the
+/// slot with a borrowed `&'a Registry` can only be created if the registry outlives the slot
+/// reference.
+///
+/// Note that `'a` is chosen by the caller of this function and is not tied to the actual lifetime
+/// of the allocation that is initialized. Since the slot is pinned, the memory stays valid until
+/// the value is dropped, so the reference must not be used after the initialized value has been
+/// dropped.
+///
+/// # Safety
+///
+/// The closure:
+/// - returns `Ok(())` if it initialized every field of `slot`,
+/// - returns `Err(err)` if it encountered an error and then cleaned `slot`, this means:
+///     - `slot` can be deallocated without UB occurring,
+///     - `slot` does not need to be dropped,
+///     - `slot` is not partially initialized.
+/// - cleans `slot` in the same way before unwinding, if it panics,
+/// - may assume that the `slot` does not move if `T: !Unpin`,
+/// - while constructing the `T` at `slot` it upholds the pinning invariants of `T`,
+/// - ensures that the reference to `slot` (and pointers derived from it) are not used after the
+///   value has been dropped.
+///
+/// # Examples
+///
+/// ```rust
+/// # use pinned_init::*;
+/// # use core::{cell::Cell, marker::PhantomPinned, mem::MaybeUninit, pin::Pin, ptr::NonNull};
+/// struct Registry {
+///     last: Cell<Option<NonNull<Entry>>>,
+/// }
+///
+/// struct Entry {
+///     id: u32,
+///     _pin: PhantomPinned,
+/// }
+///
+/// impl Entry {
+///     fn new<'a>(id: u32, registry: &'a Registry) -> impl PinInit<Self> + 'a {
+///         // SAFETY: The slot is fully initialized and the registry only stores the pointer.
+///         unsafe {
+///             pin_init_from_closure_ref(move |slot: &'a mut MaybeUninit<Self>| {
+///                 let entry = slot.write(Entry { id, _pin: PhantomPinned });
+///                 registry.last.set(Some(NonNull::from(entry)));
+///                 Ok(())
+///             })
+///         }
+///     }
+/// }
+///
+/// let registry = Registry { last: Cell::new(None) };
+/// let entry = Box::pin_init(Entry::new(42, &registry)).unwrap();
+/// assert_eq!(registry.last.get(), Some(NonNull::from(&*entry)));
+/// ```
+#[inline]
+pub unsafe fn pin_init_from_closure_ref<'a, T: 'a, E: 'a>(
+    f: impl FnOnce(&'a mut MaybeUninit<T>) -> Result<(), E> + 'a,
+) -> impl PinPlaceInit<T, E> + 'a {
+    // SAFETY: The caller guarantees that `f` upholds the requirements of `pin_init_from_closure`.
+    // `slot` is valid for reads and writes and `MaybeUninit<T>` has the same layout as `T`.
+    unsafe { pin_init_from_closure(move |slot: *mut T| f(&mut *slot.cast::<MaybeUninit<T>>())) }
+}
+
+/// Pointer to a slot that is being initialized, carrying the lifetime `'a` of the slot.
+///
+/// This is given to the closure of [`pin_init_from_closure_to_init`]. In contrast to the
+/// `&'a mut MaybeUninit<T>` used by [`pin_init_from_closure_ref`], this also supports unsized
+/// types.
+pub struct ToInit<'a, T: ?Sized>(*mut T, PhantomData<&'a mut T>);
+
+impl<'a, T: ?Sized> ToInit<'a, T> {
+    /// Returns the raw pointer to the slot.
+    ///
+    /// The pointer is valid for writes and the memory it points to is uninitialized.
+    #[inline]
+    pub fn as_ptr(&self) -> *mut T {
+        self.0
+    }
+}
+
+/// Creates a new [`PinInit<T, E>`] from the given closure that receives the slot as a
+/// [`ToInit<'a, T>`].
+///
+/// This is the same as [`pin_init_from_closure_ref`], but also works for unsized types. See there
+/// for the relationship between `'a` and the slot.
+///
+/// # Safety
+///
+/// The closure:
+/// - returns `Ok(())` if it initialized every field of `slot`,
+/// - returns `Err(err)` if it encountered an error and then cleaned `slot`, this means:
+///     - `slot` can be deallocated without UB occurring,
+///     - `slot` does not need to be dropped,
+///     - `slot` is not partially initialized.
+/// - cleans `slot` in the same way before unwinding, if it panics,
+/// - may assume that the `slot` does not move if `T: !Unpin`,
+/// - while constructing the `T` at `slot` it upholds the pinning invariants of `T`,
+/// - ensures that pointers derived from `slot` are not used after the value has been dropped.
+#[inline]
+pub unsafe fn pin_init_from_closure_to_init<'a, T: ?Sized + 'a, E: 'a>(
+    f: impl FnOnce(ToInit<'a, T>) -> Result<(), E> + 'a,
+) -> impl PinPlaceInit<T, E> + 'a {
+    // SAFETY: The caller guarantees that `f` upholds the requirements of `pin_init_from_closure`.
+    unsafe { pin_init_from_closure(move |slot: *mut T| f(ToInit(slot, PhantomData))) }
+}
+
+/// Creates a new [`Init<T, E>`] from the given closure.
+///
+/// # Safety
+///
+/// The closure:
+/// - returns `Ok(())` if it initialized every field of `slot`,
+/// - returns `Err(err)` if it encountered an error and then cleaned `slot`, this means:
+///     - `slot` can be deallocated without UB occurring,
+///     - `slot` does not need to be dropped,
+///     - `slot` is not partially initialized.
+/// - cleans `slot` in the same way before unwinding, if it panics,
+/// - the `slot` may move after initialization.
+/// - while constructing the `T` at `slot` it upholds the pinning invariants of `T`.
+#[inline]
+pub const unsafe fn init_from_closure<T: ?Sized, E>(
+    f: impl FnOnce(*mut T) -> Result<(), E>,
+) -> impl PlaceInit<T, E> {
+    __internal::InitClosure(f, PhantomData)
+}
+
+/// Creates a new [`Init<T, E>`] from a closure that returns the value.
+///
+/// The value is moved into the slot if the closure returns `Ok`. This is a safe alternative to
+/// [`init_from_closure`] for values that are constructed by-value.
+///
+/// # Examples
+///
+/// ```rust
+/// # #![feature(allocator_api)]
+/// # #[path = "../examples/error.rs"] mod error; use error::Error;
+/// # use pinned_init::*;
+/// fn parse_port(port: &str) -> impl Init<u16, Error> + '_ {
+///     init_from_fn(move || port.parse().map_err(|_| Error))
+/// }
+/// let port: Box<u16> = Box::try_init(parse_port("8080")).unwrap();
+/// assert_eq!(*port, 8080);
+/// assert!(Box::try_init(parse_port("http")).is_err());
+/// ```
+#[inline]
+pub fn init_from_fn<T, E>(f: impl FnOnce() -> Result<T, E>) -> impl Init<T, E> {
+    // SAFETY: The slot is initialized when `Ok` is returned and not touched otherwise.
+    unsafe {
+        init_from_closure(move |slot: *mut T| {
+            slot.write(f()?);
+            Ok(())
+        })
+    }
+}
+
+/// Creates a new [`PinInit<T, E>`] from a closure that returns the value.
+///
+/// See [`init_from_fn`] for the unpinned version.
+#[inline]
+pub fn pin_init_from_fn<T, E>(f: impl FnOnce() -> Result<T, E>) -> impl PinInit<T, E> {
+    // SAFETY: The slot is initialized when `Ok` is returned and not touched otherwise. Moving the
+    // value into the slot is fine, since it has not been pinned before.
+    unsafe {
+        pin_init_from_closure(move |slot: *mut T| {
+            slot.write(f()?);
+            Ok(())
+        })
+    }
+}
+
+/// Turns an infallible initializer into one with an arbitrary error type `E`.
+///
+/// This is useful when an [`Init<T, E>`] is required, but `E` does not implement
+/// `From<Infallible>`. See [`pin_infallible`] for the pinned version.
+///
+/// # Examples
+///
+/// ```rust
+/// # use pinned_init::*;
+/// #[derive(Debug)]
+/// struct Error;
+///
+/// fn checked(init: impl Init<[u8; 64], Error>) -> impl Init<[u8; 64], Error> {
+///     init.chain(|buf| {
+///         if buf.iter().all(|&b| b == 0) {
+///             Ok(())
+///         } else {
+///             Err(Error)
+///         }
+///     })
+/// }
+///
+/// stack_try_pin_init!(let buf = checked(infallible(zeroed())));
+/// assert!(buf.is_ok());
+/// ```
+#[inline]
+pub fn infallible<T: ?Sized, E>(init: impl Init<T, Infallible>) -> impl Init<T, E> {
+    // SAFETY: `init` initializes the slot, since it cannot fail.
+    unsafe {
+        init_from_closure(move |slot: *mut T| match init.__init(slot) {
+            Ok(()) => Ok(()),
+            Err(x) => match x {},
+        })
+    }
+}
+
+/// Turns an infallible pin-initializer into one with an arbitrary error type `E`.
+///
+/// See [`infallible`] for the unpinned version.
+#[inline]
+pub fn pin_infallible<T: ?Sized, E>(init: impl PinInit<T, Infallible>) -> impl PinInit<T, E> {
+    // SAFETY: `init` initializes the slot, since it cannot fail.
+    unsafe {
+        pin_init_from_closure(move |slot: *mut T| match init.__pinned_init(slot) {
+            Ok(()) => Ok(()),
+            Err(x) => match x {},
+        })
+    }
+}
+
+/// Creates an initializer for a byte array by reading exactly `N` bytes from `reader`.
+///
+/// The bytes are read directly into the slot, so no intermediate buffer is needed. The slot is
+/// zeroed before reading, since [`Read::read_exact`] requires an initialized buffer.
+///
+/// If reading fails, the error of [`Read::read_exact`] is returned. This includes reaching the
+/// end of `reader` before `N` bytes have been read. The slot is then considered uninitialized, even
+/// though some bytes might have been written to it.
+///
+/// # Examples
+///
+/// ```rust
+/// # use pinned_init::*;
+/// use std::io::Cursor;
+/// stack_try_pin_init!(let magic: [u8; 4] = read_exact_init(Cursor::new(b"\x7fELF\x02")));
+/// assert_eq!(&*magic.unwrap(), b"\x7fELF");
+/// ```
+///
+/// [`Read::read_exact`]: std::io::Read::read_exact
+#[cfg(feature = "std")]
+pub fn read_exact_init<R: std::io::Read, const N: usize>(
+    mut reader: R,
+) -> impl Init<[u8; N], std::io::Error> {
+    // SAFETY: The slot is fully initialized when `Ok` is returned. On error it only contains bytes,
+    // which do not need to be dropped.
+    unsafe {
+        init_from_closure(move |slot: *mut [u8; N]| {
+            // SAFETY: `slot` is valid for writes.
+            slot.write_bytes(0, 1);
+            // SAFETY: `slot` is valid and has been initialized above.
+            reader.read_exact(&mut *slot)
+        })
+    }
+}
+
+/// Creates an initializer for a [`String`] with a capacity of at least `cap` bytes, which is then
+/// filled by `f`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use pinned_init::*;
+/// let s: Box<String> = Box::init(string_init(16, |s| {
+///     for i in 0..4 {
+///         s.push(char::from(b'a' + i));
+///     }
+/// }))
+/// .unwrap();
+/// assert_eq!(*s, "abcd");
+/// assert!(s.capacity() >= 16);
+/// ```
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub fn string_init(cap: usize, f: impl FnOnce(&mut String)) -> impl Init<String> {
+    init_from_fn(move || {
+        let mut s = String::with_capacity(cap);
+        f(&mut s);
+        Ok(s)
+    })
+}
+
+/// Creates an initializer for a [`String`] containing the formatted `args`.
+///
+/// This is the initializer version of [`format!`], use [`format_args!`] to create `args`.
+///
+/// The string is formatted when this function is called, not when the initializer runs, since
+/// `args` usually borrows temporaries that do not live until the end of the enclosing statement.
+/// Only the [`String`] itself, which points to the formatted contents on the heap, is written to
+/// the slot.
+///
+/// # Panics
+///
+/// Panics if a formatting trait implementation returns an error, like [`format!`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use pinned_init::*;
+/// struct Device {
+///     id: u32,
+///     name: String,
+/// }
+///
+/// let id = 7;
+/// let dev = Box::init(init!(Device {
+///     id,
+///     name <- fmt_init(format_args!("dev{id}")),
+/// }))
+/// .unwrap();
+/// assert_eq!(dev.name, "dev7");
+/// ```
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub fn fmt_init(args: core::fmt::Arguments<'_>) -> impl Init<String> {
+    let mut s = String::new();
+    core::fmt::Write::write_fmt(&mut s, args).expect(
+        "a formatting trait implementation returned an error when the underlying stream did not",
+    );
+    s
+}
+
+/// Creates a new [`PinInit<T, E>`] from an initializer that produces another initializer.
+///
+/// The initializer `I` created by `outer` is first initialized on the stack and then used to
+/// initialize the slot. This is useful for builders that return their initializer only after
+/// some fallible setup.
+///
+/// # Examples
+///
+/// ```rust
+/// # #![feature(allocator_api)]
+/// # #[path = "../examples/error.rs"] mod error; use error::Error;
+/// # use pinned_init::*;
+/// # use core::pin::Pin;
+/// fn parse_array(len: &str) -> impl PinInit<[u8; 64], Error> + '_ {
+///     flatten_pin_init(init_from_fn(move || {
+///         let len: usize = len.parse().map_err(|_| Error)?;
+///         Ok(pin_init_array_from_fn(move |i| if i < len { 1 } else { 0 }))
+///     }))
+/// }
+///
+/// let array: Pin<Box<[u8; 64]>> = Box::try_pin_init(parse_array("3")).unwrap();
+/// assert_eq!(array.iter().sum::<u8>(), 3);
+/// ```
+#[inline]
+pub fn flatten_pin_init<I, T, E>(outer: impl Init<I, E>) -> impl PinInit<T, E>
+where
+    I: PinInit<T, E>,
+{
+    let init = move |slot: *mut T| {
+        let mut inner = MaybeUninit::<I>::uninit();
+        // SAFETY: `inner` is valid uninitialized memory that is not used when `Err` is returned.
+        unsafe { outer.__init(inner.as_mut_ptr())? };
+        // SAFETY: `inner` has been initialized above.
+        let inner = unsafe { inner.assume_init() };
+        // SAFETY: `slot` is valid and pinned, because we are inside of an initializer closure.
+        unsafe { inner.__pinned_init(slot) }
+    };
+    // SAFETY: The slot is initialized by `inner`, which also cleans up on error.
+    unsafe { pin_init_from_closure(init) }
+}
+
+/// Creates a new [`PinInit<T, E>`] that retries initialization up to `attempts` times.
+///
+/// Initializers can only be used once, so `make` is called to create a fresh initializer for every
+/// attempt. A failed attempt leaves the slot uninitialized, so the next one can reuse it. If every
+/// attempt fails, the error of the last one is returned. `make` is called at least once, even if
+/// `attempts` is zero.
+///
+/// # Examples
+///
+/// ```rust
+/// # #![feature(allocator_api)]
+/// # #[path = "../examples/error.rs"] mod error; use error::Error;
+/// # use pinned_init::*;
+/// let mut ready = [false, false, true].into_iter();
+/// stack_try_pin_init!(let value: u32 = retry_pin_init(3, || {
+///     let ready = ready.next().unwrap();
+///     pin_init_from_fn(move || if ready { Ok(42) } else { Err(Error) })
+/// }));
+/// assert_eq!(*value.unwrap(), 42);
+/// ```
+#[inline]
+pub fn retry_pin_init<T, E, I>(attempts: usize, mut make: impl FnMut() -> I) -> impl PinInit<T, E>
+where
+    I: PinInit<T, E>,
+{
+    let init = move |slot: *mut T| {
+        for _ in 1..attempts {
+            // SAFETY: `slot` is valid and pinned, because we are inside of an initializer closure.
+            // On error it is left uninitialized, so it can be used for the next attempt.
+            if unsafe { make().__pinned_init(slot) }.is_ok() {
+                return Ok(());
+            }
+        }
+        // SAFETY: `slot` is valid and pinned, because we are inside of an initializer closure.
+        unsafe { make().__pinned_init(slot) }
+    };
+    // SAFETY: The slot is initialized by the successful attempt. Every failed attempt cleans up
+    // after itself.
+    unsafe { pin_init_from_closure(init) }
+}
+
+/// An initializer that leaves the memory uninitialized.
+///
+/// The initializer is a no-op. The `slot` memory is not changed.
+#[inline]
+pub fn uninit<T, E>() -> impl Init<MaybeUninit<T>, E> {
+    // SAFETY: The memory is allowed to be uninitialized.
+    unsafe { init_from_closure(|_| Ok(())) }
+}
+
+/// Initializes the slot with a copy of `*src`.
+///
+/// In contrast to using the value as an initializer, this does not require ownership of the value,
+/// so it can be used to initialize fields from a borrowed template. The value is copied directly
+/// from `src` into the slot.
+///
+/// # Examples
+///
+/// ```rust
+/// # use pinned_init::*;
+/// let template = [7u32; 256];
+/// let table: Box<[u32; 256]> = Box::init(copy_init(&template)).unwrap();
+/// assert_eq!(*table, template);
+/// ```
+#[inline]
+pub fn copy_init<T: Copy>(src: &T) -> impl Init<T> + '_ {
+    let init = move |slot: *mut T| {
+        // SAFETY: `slot` is valid for writes and cannot overlap with `src`, since `slot` is
+        // uninitialized and `src` points to a valid value.
+        unsafe { ptr::copy_nonoverlapping(src, slot, 1) };
+        Ok(())
+    };
+    // SAFETY: The closure always initializes `slot`. Since `T: Copy`, the copy is a valid value.
+    unsafe { init_from_closure(init) }
+}
+
+/// Initializes a [`OnceCell`] that already contains the value initialized by `init`.
+///
+/// Since the layout of [`OnceCell`] is private, the value is first initialized on the stack and
+/// then moved into the cell. This means that the benefits of in-place initialization are lost, so
+/// only use this for types that are small enough to live on the stack.
+///
+/// # Examples
+///
+/// ```rust
+/// # use pinned_init::*;
+/// use core::cell::OnceCell;
+/// let cell: Box<OnceCell<usize>> = Box::init(once_cell_init(42)).unwrap();
+/// assert_eq!(cell.get(), Some(&42));
+/// ```
+///
+/// [`OnceCell`]: core::cell::OnceCell
+pub fn once_cell_init<T, E>(init: impl Init<T, E>) -> impl Init<OnceCell<T>, E> {
+    let init = move |slot: *mut OnceCell<T>| {
+        let mut value = MaybeUninit::<T>::uninit();
+        // SAFETY: `value` is valid uninitialized memory that is not used when `Err` is returned.
+        unsafe { init.__init(value.as_mut_ptr())? };
+        // SAFETY: `value` has been initialized above.
+        let value = unsafe { value.assume_init() };
+        // SAFETY: `slot` is valid uninitialized memory.
+        unsafe { slot.write(OnceCell::from(value)) };
+        Ok(())
+    };
+    // SAFETY: The closure initializes `slot` when it returns `Ok` and does not touch it otherwise.
+    unsafe { init_from_closure(init) }
+}
+
+/// Initializes a [`RefCell`] with the value initialized by `init`.
+///
+/// The layout of [`RefCell`] is private, so the value cannot be initialized in place. Instead it is
+/// first initialized on the stack and then moved into the cell via [`RefCell::new`]. This does not
+/// avoid the stack copy, so only use this for types that are small enough to live on the stack.
+/// The cell is not borrowed after initialization.
+///
+/// # Examples
+///
+/// ```rust
+/// # use pinned_init::*;
+/// # use core::convert::Infallible;
+/// use core::cell::RefCell;
+/// let init = init_array_from_fn(|i| i as u32);
+/// let cell: Box<RefCell<[u32; 4]>> = Box::init(ref_cell_init::<_, Infallible>(init)).unwrap();
+/// cell.borrow_mut()[0] = 7;
+/// assert_eq!(*cell.borrow(), [7, 1, 2, 3]);
+/// ```
+///
+/// [`RefCell`]: core::cell::RefCell
+pub fn ref_cell_init<T, E>(init: impl Init<T, E>) -> impl Init<RefCell<T>, E> {
+    let init = move |slot: *mut RefCell<T>| {
+        let mut value = MaybeUninit::<T>::uninit();
+        // SAFETY: `value` is valid uninitialized memory that is not used when `Err` is returned.
+        unsafe { init.__init(value.as_mut_ptr())? };
+        // SAFETY: `value` has been initialized above.
+        let value = unsafe { value.assume_init() };
+        // SAFETY: `slot` is valid uninitialized memory.
+        unsafe { slot.write(RefCell::new(value)) };
+        Ok(())
+    };
+    // SAFETY: The closure initializes `slot` when it returns `Ok` and does not touch it otherwise.
+    unsafe { init_from_closure(init) }
+}
+
+/// Initializes an [`UnsafeCell`] with the value initialized by `init`.
+///
+/// [`UnsafeCell<T>`] has the same in-memory representation as `T`, so the value is initialized in
+/// place. The value is pinned if the cell is, so this is commonly used for structurally pinned FFI
+/// types. Use [`raw_field!`] to get a pointer to the value afterwards.
+///
+/// # Examples
+///
+/// ```rust
+/// # use pinned_init::*;
+/// stack_pin_init!(let cell = unsafe_cell_init(zeroed::<[u8; 4]>()));
+/// assert_eq!(unsafe { *cell.get() }, [0; 4]);
+/// ```
+///
+/// [`UnsafeCell`]: core::cell::UnsafeCell
+/// [`UnsafeCell<T>`]: core::cell::UnsafeCell
+pub fn unsafe_cell_init<T: ?Sized, E>(init: impl PinInit<T, E>) -> impl PinInit<UnsafeCell<T>, E> {
+    let init = move |slot: *mut UnsafeCell<T>| {
+        // SAFETY: `UnsafeCell<T>` has the same layout as `T`, so `slot` points to valid
+        // uninitialized memory for a `T`. It is pinned, since `slot` is. If `init` fails, `slot`
+        // is left uninitialized.
+        unsafe { init.__pinned_init(UnsafeCell::raw_get(slot)) }
+    };
+    // SAFETY: The closure initializes `slot` when it returns `Ok` and leaves it uninitialized
+    // otherwise.
+    unsafe { pin_init_from_closure(init) }
+}
+
+/// Initializes an [`Option<T>`] to `Some` with the value initialized by `init`.
+///
+/// The layout of [`Option<T>`] is unspecified for most `T`, so the payload cannot be initialized in
+/// place. Instead the value is first initialized on the stack and then moved into the slot. This
+/// needs stack space for a whole `T`, so only use this for types that are small enough to live on
+/// the stack.
+///
+/// Since the error type of field initializers cannot be inferred, it has to be specified when
+/// `init` is a value: `some_init::<_, Infallible>(value)`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use pinned_init::*;
+/// # use core::convert::Infallible;
+/// struct Config {
+///     retries: u32,
+///     timeout: Option<[u64; 4]>,
+/// }
+///
+/// let config: Box<Config> = Box::init(init!(Config {
+///     retries: 3,
+///     timeout <- some_init::<_, Infallible>([10; 4]),
+/// }))
+/// .unwrap();
+/// assert_eq!(config.timeout, Some([10; 4]));
+/// ```
+pub fn some_init<T, E>(init: impl Init<T, E>) -> impl Init<Option<T>, E> {
+    let init = move |slot: *mut Option<T>| {
+        let mut value = MaybeUninit::<T>::uninit();
+        // SAFETY: `value` is valid uninitialized memory that is not used when `Err` is returned.
+        unsafe { init.__init(value.as_mut_ptr())? };
+        // SAFETY: `value` has been initialized above.
+        let value = unsafe { value.assume_init() };
+        // SAFETY: `slot` is valid uninitialized memory.
+        unsafe { slot.write(Some(value)) };
+        Ok(())
+    };
+    // SAFETY: The closure initializes `slot` when it returns `Ok` and does not touch it otherwise.
+    unsafe { init_from_closure(init) }
+}
+
+/// Initializes an [`Option<T>`] to `Some` with the value initialized by `init` if `cond` is true,
+/// otherwise to `None`.
+///
+/// When `cond` is false, `init` is dropped without being run. Otherwise this behaves like
+/// [`some_init`]: since the layout of [`Option<T>`] is unspecified, the payload is initialized on
+/// the stack and then moved into the slot.
+///
+/// # Examples
+///
+/// ```rust
+/// # use pinned_init::*;
+/// # use core::convert::Infallible;
+/// struct Device {
+///     id: u32,
+///     dma_buf: Option<[u8; 64]>,
+/// }
+///
+/// fn device(id: u32, dma: bool) -> impl Init<Device> {
+///     init!(Device {
+///         id,
+///         dma_buf <- option_init::<_, Infallible>(dma, [0; 64]),
+///     })
+/// }
+///
+/// let dev = Box::init(device(1, true)).unwrap();
+/// assert_eq!(dev.dma_buf, Some([0; 64]));
+/// let dev = Box::init(device(2, false)).unwrap();
+/// assert_eq!(dev.dma_buf, None);
+/// ```
+pub fn option_init<T, E>(cond: bool, init: impl Init<T, E>) -> impl Init<Option<T>, E> {
+    let init = some_init(init);
+    let init = move |slot: *mut Option<T>| {
+        if cond {
+            // SAFETY: `slot` is valid uninitialized memory, `init` only initializes it when it
+            // returns `Ok`.
+            unsafe { init.__init(slot) }
+        } else {
+            // SAFETY: `slot` is valid uninitialized memory.
+            unsafe { slot.write(None) };
+            Ok(())
+        }
+    };
+    // SAFETY: The closure initializes `slot` when it returns `Ok` and does not touch it otherwise.
+    unsafe { init_from_closure(init) }
+}
+
+/// Initializes an array by initializing each element via the provided initializer.
+///
+/// # Examples
+///
+/// ```rust
+/// # use pinned_init::*;
+/// use pinned_init::init_array_from_fn;
+/// let array: Box<[usize; 1_000]> = Box::init(init_array_from_fn(|i| i)).unwrap();
+/// assert_eq!(array.len(), 1_000);
+/// ```
+pub fn init_array_from_fn<I, const N: usize, T, E>(
+    mut make_init: impl FnMut(usize) -> I,
+) -> impl Init<[T; N], E>
+where
+    I: Init<T, E>,
+{
+    let init = move |slot: *mut [T; N]| {
+        let slot = slot.cast::<T>();
+        for i in 0..N {
+            // SAFETY: The loop has initialized the elements `slot[0..i]`. If `make_init` or `init`
+            // fails or panics, they are dropped by the guard and `slot` is considered
+            // uninitialized memory.
+            let guard =
+                unsafe { __internal::DropGuard::new(ptr::slice_from_raw_parts_mut(slot, i)) };
+            let init = make_init(i);
+            // SAFETY: Since 0 <= `i` < N, it is still in bounds of `[T; N]`.
+            let ptr = unsafe { slot.add(i) };
+            // SAFETY: The pointer is derived from `slot` and thus satisfies the `__init`
+            // requirements.
+            unsafe { init.__init(ptr)? };
+            core::mem::forget(guard);
+        }
+        Ok(())
+    };
+    // SAFETY: The initializer above initializes every element of the array. On failure or panic it
+    // drops any initialized elements.
+    unsafe { init_from_closure(init) }
+}
+
+/// Initializes every element of the array inside of a [`MaybeUninit`] via the provided
+/// initializer.
+///
+/// This is [`init_array_from_fn`] for a slot of type `MaybeUninit<[T; N]>`. On success, all
+/// elements are initialized, but the slot is still typed as uninitialized. This is useful for
+/// staged initialization, where the array is only considered initialized after some other step.
+/// Use [`MaybeUninit::assume_init`] afterwards, since the elements are not dropped otherwise.
+///
+/// # Examples
+///
+/// ```rust
+/// # use pinned_init::*;
+/// # use core::mem::MaybeUninit;
+/// let array: Box<MaybeUninit<[usize; 16]>> =
+///     Box::init(uninit_array_init_from_fn(|i| i * 2)).unwrap();
+/// // SAFETY: All elements have been initialized above.
+/// let array = unsafe { array.assume_init() };
+/// assert_eq!(array[15], 30);
+/// ```
+pub fn uninit_array_init_from_fn<I, const N: usize, T, E>(
+    make_init: impl FnMut(usize) -> I,
+) -> impl Init<MaybeUninit<[T; N]>, E>
+where
+    I: Init<T, E>,
+{
+    let init = init_array_from_fn(make_init);
+    // SAFETY: `MaybeUninit<[T; N]>` has the same layout as `[T; N]` and every value of `[T; N]` is
+    // a valid value of `MaybeUninit<[T; N]>`. `init` drops the initialized elements on failure, so
+    // nothing is leaked.
+    unsafe {
+        init_from_closure(move |slot: *mut MaybeUninit<[T; N]>| init.__init(slot.cast::<[T; N]>()))
+    }
+}
+
+/// Initializes an array by writing a clone of `elem` into each element.
+///
+/// In contrast to initializing with an array value such as `[elem; N]`, the array is never
+/// constructed on the stack, which makes this suitable for big arrays. If cloning panics, the
+/// already cloned elements and `elem` are dropped.
+///
+/// # Examples
+///
+/// ```rust
+/// # use pinned_init::*;
+/// let array: Box<[String; 1_000]> = Box::init(repeat_init("hi".to_owned())).unwrap();
+/// assert!(array.iter().all(|s| s == "hi"));
+/// ```
+pub fn repeat_init<T: Clone, const N: usize>(elem: T) -> impl Init<[T; N]> {
+    init_array_from_fn(move |_| elem.clone())
+}
+
+/// Initializes an array, where only the first `prefix_len` elements are configured individually.
+///
+/// The elements `0..prefix_len` are initialized by the initializer returned by `prefix`, which
+/// receives the index of the element. The remaining elements are initialized by the initializer
+/// returned by `default`. If `prefix_len` is at least `N`, `default` is never called.
+///
+/// Like [`init_array_from_fn`], the already initialized elements are dropped when an initializer
+/// fails.
+///
+/// # Examples
+///
+/// ```rust
+/// # use pinned_init::*;
+/// # use core::convert::Infallible;
+/// let ports = [8080, 8443];
+/// let array: Box<[u16; 8]> = Box::init(array_from_fn_with_default::<_, 8, _, Infallible>(
+///     ports.len(),
+///     |i| ports[i],
+///     || 0,
+/// ))
+/// .unwrap();
+/// assert_eq!(*array, [8080, 8443, 0, 0, 0, 0, 0, 0]);
+/// ```
+pub fn array_from_fn_with_default<I, const N: usize, T, E>(
+    prefix_len: usize,
+    mut prefix: impl FnMut(usize) -> I,
+    mut default: impl FnMut() -> I,
+) -> impl Init<[T; N], E>
+where
+    I: Init<T, E>,
+{
+    init_array_from_fn(move |i| if i < prefix_len { prefix(i) } else { default() })
+}
+
+/// Atomic types that can be initialized by writing their underlying value into the slot.
+///
+/// Used by [`atomic_init`].
+///
+/// # Safety
+///
+/// `Self` must have the same size and bit validity as [`Self::Value`], such that writing a
+/// `Self::Value` into a slot for `Self` initializes it.
+pub unsafe trait AtomicFromValue {
+    /// The underlying non-atomic type.
+    type Value;
+}
+
+macro_rules! impl_atomic_from_value {
+    ($($(#[$attr:meta])* $({$($generics:tt)*})? $atomic:ty => $value:ty,)*) => {
+        $(
+            $(#[$attr])*
+            // SAFETY: Atomics have the same in-memory representation as their underlying type.
+            unsafe impl$($($generics)*)? AtomicFromValue for $atomic {
+                type Value = $value;
+            }
+        )*
+    };
+}
+
+impl_atomic_from_value! {
+    #[cfg(target_has_atomic = "8")]
+    core::sync::atomic::AtomicBool => bool,
+    #[cfg(target_has_atomic = "8")]
+    core::sync::atomic::AtomicI8 => i8,
+    #[cfg(target_has_atomic = "8")]
+    core::sync::atomic::AtomicU8 => u8,
+    #[cfg(target_has_atomic = "16")]
+    core::sync::atomic::AtomicI16 => i16,
+    #[cfg(target_has_atomic = "16")]
+    core::sync::atomic::AtomicU16 => u16,
+    #[cfg(target_has_atomic = "32")]
+    core::sync::atomic::AtomicI32 => i32,
+    #[cfg(target_has_atomic = "32")]
+    core::sync::atomic::AtomicU32 => u32,
+    #[cfg(target_has_atomic = "64")]
+    core::sync::atomic::AtomicI64 => i64,
+    #[cfg(target_has_atomic = "64")]
+    core::sync::atomic::AtomicU64 => u64,
+    #[cfg(target_has_atomic = "ptr")]
+    core::sync::atomic::AtomicIsize => isize,
+    #[cfg(target_has_atomic = "ptr")]
+    core::sync::atomic::AtomicUsize => usize,
+    #[cfg(target_has_atomic = "ptr")]
+    {<T>} core::sync::atomic::AtomicPtr<T> => *mut T,
+}
+
+/// Initializes an atomic by writing `value` directly into the slot.
+///
+/// This is equivalent to `AtomicU64::new(value)` and friends, but does not move the atomic, which
+/// avoids stack copies when initializing big arrays of atomics.
+///
+/// # Examples
+///
+/// ```rust
+/// # use pinned_init::*;
+/// use core::sync::atomic::{AtomicU64, Ordering};
+/// let array: Box<[AtomicU64; 1_000]> =
+///     Box::init(init_array_from_fn(|i| atomic_init(i as u64))).unwrap();
+/// assert_eq!(array[42].load(Ordering::Relaxed), 42);
+/// ```
+pub fn atomic_init<A: AtomicFromValue>(value: A::Value) -> impl Init<A> {
+    let init = move |slot: *mut A| {
+        // SAFETY: `slot` is valid for writes and by the safety requirements of `AtomicFromValue`,
+        // `A` has the same size and bit validity as `A::Value`.
+        unsafe { slot.cast::<A::Value>().write(value) };
+        Ok(())
+    };
+    // SAFETY: The closure above always initializes `slot`.
+    unsafe { init_from_closure(init) }
+}
+
+/// Initializes an array by initializing each element via the provided initializer.
+///
+/// # Examples
+///
+/// ```rust
+/// # #![feature(allocator_api)]
+/// # #[path = "../examples/mutex.rs"] mod mutex; use mutex::*;
+/// # use pinned_init::*;
+/// # use core::pin::Pin;
+/// use pinned_init::pin_init_array_from_fn;
+/// use std::sync::Arc;
+/// let array: Pin<Arc<[CMutex<usize>; 1_000]>> =
+///     Arc::pin_init(pin_init_array_from_fn(|i| CMutex::new(i))).unwrap();
+/// assert_eq!(array.len(), 1_000);
+/// ```
+pub fn pin_init_array_from_fn<I, const N: usize, T, E>(
+    mut make_init: impl FnMut(usize) -> I,
+) -> impl PinInit<[T; N], E>
+where
+    I: PinInit<T, E>,
+{
+    let init = move |slot: *mut [T; N]| {
+        let slot = slot.cast::<T>();
+        for i in 0..N {
+            // SAFETY: The loop has initialized the elements `slot[0..i]`. If `make_init` or `init`
+            // fails or panics, they are dropped by the guard and `slot` is considered
+            // uninitialized memory.
+            let guard =
+                unsafe { __internal::DropGuard::new(ptr::slice_from_raw_parts_mut(slot, i)) };
+            let init = make_init(i);
+            // SAFETY: Since 0 <= `i` < N, it is still in bounds of `[T; N]`.
+            let ptr = unsafe { slot.add(i) };
+            // SAFETY: The pointer is derived from `slot` and thus satisfies the `__init`
+            // requirements.
+            unsafe { init.__pinned_init(ptr)? };
+            core::mem::forget(guard);
+        }
+        Ok(())
+    };
+    // SAFETY: The initializer above initializes every element of the array. On failure or panic it
+    // drops any initialized elements.
+    unsafe { pin_init_from_closure(init) }
+}
+
+/// Pin-initializes every element of an uninitialized slice via the provided initializer.
+///
+/// This is the runtime-length counterpart of [`pin_init_array_from_fn`] for memory that is provided
+/// by the caller. On success, the initialized slice is returned. If an initializer fails, the
+/// already initialized elements are dropped and the error is returned, leaving the slice
+/// uninitialized.
+///
+/// # Safety
+///
+/// [`MaybeUninit<T>`] never drops its contents, so the caller must ensure that the elements of the
+/// slice are dropped before the underlying memory is reused or deallocated, if this function
+/// returns `Ok`. Otherwise the drop guarantee of [`Pin`] is violated.
+///
+/// # Examples
+///
+/// ```rust
+/// # #![feature(allocator_api)]
+/// # #[path = "../examples/mutex.rs"] mod mutex; use mutex::*;
+/// # use pinned_init::*;
+/// # use core::{mem::MaybeUninit, pin::Pin, ptr};
+/// let mut buf = Box::pin([const { MaybeUninit::<CMutex<usize>>::uninit() }; 16]);
+/// let len = 10;
+/// // SAFETY: The elements are dropped below before `buf` is dropped.
+/// let slice = unsafe {
+///     pin_init_uninit_slice::<_, _, core::convert::Infallible>(
+///         buf.as_mut().map_unchecked_mut(|buf| &mut buf[..len]),
+///         |i| CMutex::new(i),
+///     )
+/// };
+/// let slice = slice.unwrap();
+/// assert_eq!(*slice[9].lock(), 9);
+/// // SAFETY: The elements are initialized and never used again.
+/// unsafe { ptr::drop_in_place(slice.get_unchecked_mut()) };
+/// ```
+pub unsafe fn pin_init_uninit_slice<T, I, E>(
+    slice: Pin<&mut [MaybeUninit<T>]>,
+    mut make_init: impl FnMut(usize) -> I,
+) -> Result<Pin<&mut [T]>, E>
+where
+    I: PinInit<T, E>,
+{
+    // SAFETY: We never move the elements of the slice.
+    let slice = unsafe { Pin::get_unchecked_mut(slice) };
+    let len = slice.len();
+    let slot = slice.as_mut_ptr().cast::<T>();
+    for i in 0..len {
+        // SAFETY: The loop has initialized the elements `slot[0..i]`. If `make_init` or `init`
+        // fails or panics, they are dropped by the guard and the slice is considered uninitialized
+        // memory.
+        let guard = unsafe { __internal::DropGuard::new(ptr::slice_from_raw_parts_mut(slot, i)) };
+        let init = make_init(i);
+        // SAFETY: Since 0 <= `i` < `len`, it is still in bounds of the slice.
+        let ptr = unsafe { slot.add(i) };
+        // SAFETY: The pointer is derived from a pinned slice and is valid for writes. The caller
+        // guarantees that the element is dropped before the memory is reused.
+        unsafe { init.__pinned_init(ptr)? };
+        core::mem::forget(guard);
+    }
+    // SAFETY: All `len` elements have been initialized by the loop above and `MaybeUninit<T>` has
+    // the same layout as `T`.
+    let slice = unsafe { &mut *ptr::slice_from_raw_parts_mut(slot, len) };
+    // SAFETY: The elements were pinned to begin with.
+    Ok(unsafe { Pin::new_unchecked(slice) })
+}
+
+/// Locks that can be initialized around data that is initialized in-place.
+///
+/// Implementing this trait allows creating an initializer for the lock via [`lock_init`], instead
+/// of every lock having to forward the initializer of its data manually.
+///
+/// # Safety
+///
+/// - [`LockInit::data_ptr`] must return a pointer to the data field of `slot` without accessing
+///   any other fields.
+/// - [`LockInit::init_lock`] must initialize every field of `slot` except the data field, or
+///   leave them uninitialized and return `Err`. It must not move the data.
+///
+/// # Examples
+///
+/// ```rust
+/// # #![expect(clippy::disallowed_names)]
+/// # use pinned_init::*;
+/// # use core::{cell::{Cell, UnsafeCell}, convert::Infallible, ptr::addr_of_mut};
+/// pub struct Lock<T> {
+///     locked: Cell<bool>,
+///     data: UnsafeCell<T>,
+/// }
+///
+/// // SAFETY: `data_ptr` only projects to `data` and `init_lock` initializes `locked`.
+/// unsafe impl<T> LockInit<T> for Lock<T> {
+///     type Error = Infallible;
+///
+///     unsafe fn data_ptr(slot: *mut Self) -> *mut T {
+///         // SAFETY: `slot` is valid and `UnsafeCell<T>` has the same layout as `T`.
+///         unsafe { addr_of_mut!((*slot).data) }.cast()
+///     }
+///
+///     unsafe fn init_lock(slot: *mut Self) -> Result<(), Infallible> {
+///         // SAFETY: `slot` is valid.
+///         unsafe { addr_of_mut!((*slot).locked).write(Cell::new(false)) };
+///         Ok(())
+///     }
+/// }
+///
+/// # use core::pin::Pin;
+/// let lock: Pin<Box<Lock<[u8; 1024]>>> = Box::pin_init(lock_init(zeroed())).unwrap();
+/// ```
+pub unsafe trait LockInit<T> {
+    /// The error that can occur while initializing the lock.
+    type Error;
+
+    /// Returns a pointer to the data field of the lock.
+    ///
+    /// # Safety
+    ///
+    /// `slot` must be a valid pointer to (possibly uninitialized) memory for a `Self`.
+    unsafe fn data_ptr(slot: *mut Self) -> *mut T;
+
+    /// Initializes every field of the lock except the data field.
+    ///
+    /// # Safety
+    ///
+    /// - `slot` must be a valid pointer to uninitialized memory for a `Self`,
+    /// - the data field of `slot` is initialized,
+    /// - `slot` will not move until it is dropped, i.e. it will be pinned.
+    unsafe fn init_lock(slot: *mut Self) -> Result<(), Self::Error>;
+}
+
+/// Creates an initializer for the lock `L` that initializes its data via `inner`.
+///
+/// The data is initialized first. If initializing the lock itself fails afterwards, the data is
+/// dropped again. See [`LockInit`] for an example.
+pub fn lock_init<L, T, E>(inner: impl PinInit<T, E>) -> impl PinInit<L, E>
+where
+    L: LockInit<T>,
+    E: From<L::Error>,
+{
+    let init = move |slot: *mut L| {
+        // SAFETY: `slot` is valid, because we are inside of an initializer closure.
+        let data = unsafe { L::data_ptr(slot) };
+        // SAFETY: `data` points into `slot`, which is valid and pinned.
+        unsafe { inner.__pinned_init(data)? };
+        // SAFETY: If `init_lock` fails, `data` is dropped by the guard and not accessed again,
+        // otherwise the guard is forgotten.
+        let guard = unsafe { __internal::DropGuard::new(data) };
+        // SAFETY: `slot` is valid and pinned and the data field is initialized.
+        unsafe { L::init_lock(slot)? };
+        core::mem::forget(guard);
+        Ok(())
+    };
+    // SAFETY: The closure above initializes the data and the lock. On failure or panic it drops the
+    // data if it has been initialized.
+    unsafe { pin_init_from_closure(init) }
+}
+
+/// Dyn-compatible version of [`PinInit`].
+///
+/// Since [`PinInit::__pinned_init`] takes `self` by value, `dyn PinInit<T, E>` cannot be used. This
+/// trait takes a boxed `self` instead and is implemented for every [`PinInit`]. Use
+/// [`boxed_pin_init`] to create a `Box<dyn PinInitDyn<T, E>>` and `into_pin_init` to use it as a
+/// [`PinInit`] again.
+///
+/// # Examples
+///
+/// ```rust
+/// # #![feature(allocator_api)]
+/// # #[path = "../examples/mutex.rs"] mod mutex; use mutex::*;
+/// # use pinned_init::*;
+/// # use core::convert::Infallible;
+/// let inits: Vec<Box<dyn PinInitDyn<CMutex<usize>, Infallible>>> = vec![
+///     boxed_pin_init(CMutex::new(0)),
+///     boxed_pin_init(CMutex::new(42)),
+/// ];
+/// let mutexes = inits
+///     .into_iter()
+///     .map(|init| Box::pin_init(init.into_pin_init()).unwrap())
+///     .collect::<Vec<_>>();
+/// assert_eq!(*mutexes[1].lock(), 42);
+/// ```
+///
+/// # Safety
+///
+/// When implementing this trait you need to uphold the same requirements as for
+/// [`PinInit::__pinned_init`].
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub unsafe trait PinInitDyn<T, E> {
+    /// Initializes `slot`.
+    ///
+    /// # Safety
+    ///
+    /// The same requirements as for [`PinInit::__pinned_init`] apply.
+    unsafe fn pinned_init_boxed(self: Box<Self>, slot: *mut T) -> Result<(), E>;
+}
+
+// SAFETY: `pinned_init_boxed` forwards to `__pinned_init`.
+#[cfg(any(feature = "std", feature = "alloc"))]
+unsafe impl<T, E, P: PinInit<T, E>> PinInitDyn<T, E> for P {
+    unsafe fn pinned_init_boxed(self: Box<Self>, slot: *mut T) -> Result<(), E> {
+        // SAFETY: The caller guarantees the requirements of `__pinned_init`.
+        unsafe { (*self).__pinned_init(slot) }
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<T, E> dyn PinInitDyn<T, E> {
+    /// Converts the boxed initializer back into a [`PinInit`].
+    pub fn into_pin_init(self: Box<Self>) -> impl PinInit<T, E> {
+        // SAFETY: `pinned_init_boxed` has the same requirements and guarantees as `__pinned_init`.
+        unsafe { pin_init_from_closure(move |slot| self.pinned_init_boxed(slot)) }
+    }
+}
+
+/// Boxes the given initializer, such that it can be stored as a `Box<dyn PinInitDyn<T, E>>`.
+///
+/// See [`PinInitDyn`] for an example.
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub fn boxed_pin_init<T, E>(init: impl PinInit<T, E> + 'static) -> Box<dyn PinInitDyn<T, E>> {
+    Box::new(init)
+}
+
+/// Boxes `init` for [`ErasedPinInit`] and [`ErasedInit`], converting an allocation failure into
+/// `E`.
+#[cfg(any(feature = "std", feature = "alloc"))]
+fn try_box_dyn<'a, T, E>(init: impl PinInit<T, E> + 'a) -> Result<Box<dyn PinInitDyn<T, E> + 'a>, E>
+where
+    E: From<AllocError>,
+{
+    #[cfg(feature = "alloc")]
+    let init = Box::try_new(init)?;
+    #[cfg(not(feature = "alloc"))]
+    let init = Box::new(init);
+    Ok(init)
+}
+
+/// An initializer returned by [`PinInit::erase`].
+///
+/// When the allocation of the boxed initializer has failed, this holds the converted error instead.
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub struct ErasedPinInit<'a, T, E = AllocError>(Result<Box<dyn PinInitDyn<T, E> + 'a>, E>);
+
+// SAFETY: `__pinned_init` forwards to the boxed initializer, or returns the allocation error without
+// touching `slot`.
+#[cfg(any(feature = "std", feature = "alloc"))]
+unsafe impl<T, E> PinInit<T, E> for ErasedPinInit<'_, T, E> {
+    unsafe fn __pinned_init(self, slot: *mut T) -> Result<(), E> {
+        // SAFETY: `pinned_init_boxed` has the same requirements as `__pinned_init`.
+        unsafe { self.0?.pinned_init_boxed(slot) }
+    }
+}
+
+/// An initializer returned by [`Init::erase_init`].
+///
+/// When the allocation of the boxed initializer has failed, this holds the converted error instead.
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub struct ErasedInit<'a, T, E = AllocError>(Result<Box<dyn PinInitDyn<T, E> + 'a>, E>);
+
+// SAFETY: The boxed initializer is an `InitAsPinInit`, which forwards to `Init::__init`, so it does
+// not require `slot` to be pinned. The allocation error is returned without touching `slot`.
+#[cfg(any(feature = "std", feature = "alloc"))]
+unsafe impl<T, E> Init<T, E> for ErasedInit<'_, T, E> {
+    unsafe fn __init(self, slot: *mut T) -> Result<(), E> {
+        // SAFETY: The boxed initializer only requires the guarantees of `__init`.
+        unsafe { self.0?.pinned_init_boxed(slot) }
+    }
+}
+
+// SAFETY: `__pinned_init` behaves exactly the same as `__init`.
+#[cfg(any(feature = "std", feature = "alloc"))]
+unsafe impl<T, E> PinInit<T, E> for ErasedInit<'_, T, E> {
+    unsafe fn __pinned_init(self, slot: *mut T) -> Result<(), E> {
+        // SAFETY: `__init` has less strict requirements compared to `__pinned_init`.
+        unsafe { self.__init(slot) }
+    }
+}
+
+/// Forwards `__pinned_init` to `__init`, such that an [`Init`] survives being erased into a
+/// [`PinInitDyn`].
+#[cfg(any(feature = "std", feature = "alloc"))]
+struct InitAsPinInit<I, T, E>(I, __internal::Invariant<(E, T)>);
+
+// SAFETY: `__init` has less strict requirements compared to `__pinned_init`.
+#[cfg(any(feature = "std", feature = "alloc"))]
+unsafe impl<T, E, I: Init<T, E>> PinInit<T, E> for InitAsPinInit<I, T, E> {
+    unsafe fn __pinned_init(self, slot: *mut T) -> Result<(), E> {
+        // SAFETY: `__init` has less strict requirements compared to `__pinned_init`.
+        unsafe { self.0.__init(slot) }
+    }
+}
+
+/// Pin-initializes a `T` inside of a new [`Box`] and leaks it.
+///
+/// The value is never dropped and its memory is never freed, so it stays pinned for the rest of the
+/// program. This is useful for global objects that are set up once at startup.
+///
+/// # Errors
+///
+/// Returns the error of `init`, or an [`AllocError`] converted into `E` if the allocation fails.
+///
+/// # Examples
+///
+/// ```rust
+/// # #![feature(allocator_api)]
+/// # #[path = "../examples/mutex.rs"] mod mutex; use mutex::*;
+/// # use pinned_init::*;
+/// # use core::{alloc::AllocError, pin::Pin};
+/// let devices: Pin<&'static mut CMutex<Vec<u32>>> =
+///     leak_pin_init::<_, AllocError>(pin_infallible(CMutex::new(Vec::new()))).unwrap();
+/// devices.lock().push(1);
+/// ```
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub fn leak_pin_init<T: 'static, E>(init: impl PinInit<T, E>) -> Result<Pin<&'static mut T>, E>
+where
+    E: From<AllocError>,
+{
+    let this = Box::try_pin_init(init)?;
+    // SAFETY: The box is leaked, so the value is never moved out of it and is never deallocated.
+    let this = unsafe { Pin::into_inner_unchecked(this) };
+    // SAFETY: The value has been pinned in the box above and stays at the same location.
+    Ok(unsafe { Pin::new_unchecked(Box::leak(this)) })
+}
+
+/// Reports the memory layout of the slot that an initializer needs.
 ///
-/// The closure:
-/// - returns `Ok(())` if it initialized every field of `slot`,
-/// - returns `Err(err)` if it encountered an error and then cleaned `slot`, this means:
-///     - `slot` can be deallocated without UB occurring,
-///     - `slot` does not need to be dropped,
-///     - `slot` is not partially initialized.
-/// - the `slot` may move after initialization.
-/// - while constructing the `T` at `slot` it upholds the pinning invariants of `T`.
+/// This trait is implemented for every initializer of a sized type. It allows allocators to
+/// reserve memory for an initializer before it is run, even if the concrete type of the initializer
+/// cannot be named, for example an `impl PinInit<T, E>`. In contrast to [`PinInit`], which requires
+/// `Self: Sized`, it can also be used as a `dyn InitLayout<T, E>` trait object.
+///
+/// # Examples
+///
+/// ```rust
+/// # use pinned_init::*;
+/// # use core::{alloc::Layout, convert::Infallible};
+/// fn reserve<T, E>(init: &impl InitLayout<T, E>) -> usize {
+///     let layout = init.layout();
+///     layout.size().next_multiple_of(layout.align())
+/// }
+///
+/// let init = init_array_from_fn::<_, 16, u32, Infallible>(|i| i as u32);
+/// assert_eq!(init.layout(), Layout::new::<[u32; 16]>());
+/// assert_eq!(reserve(&init), 64);
+/// ```
+pub trait InitLayout<T, E = Infallible> {
+    /// Returns the layout of `T`.
+    fn layout(&self) -> Layout {
+        Layout::new::<T>()
+    }
+}
+
+impl<T, E, I: PinInit<T, E>> InitLayout<T, E> for I {}
+
+// SAFETY: Every type can be initialized by-value.
+unsafe impl<T, E> Init<T, E> for T {
+    unsafe fn __init(self, slot: *mut T) -> Result<(), E> {
+        // SAFETY: TODO.
+        unsafe { slot.write(self) };
+        Ok(())
+    }
+}
+
+// SAFETY: Every type can be initialized by-value. `__pinned_init` calls `__init`.
+unsafe impl<T, E> PinInit<T, E> for T {
+    unsafe fn __pinned_init(self, slot: *mut T) -> Result<(), E> {
+        // SAFETY: TODO.
+        unsafe { self.__init(slot) }
+    }
+}
+
+/// A [`PinInit<T, E>`] that initializes the slot in place.
+///
+/// Every `T` is also a [`PinInit<T, E>`] that moves itself into the slot. So a function taking an
+/// `impl PinInit<T, E>` can be called with a `T` that was constructed on the stack, which is
+/// exactly what in-place initialization is meant to avoid for large types. In contrast, this trait
+/// is not implemented for `T`, so APIs can use it to require a genuine in-place initializer.
+///
+/// It is implemented for the initializers returned by [`pin_init!`], [`try_pin_init!`] and
+/// [`pin_init_from_closure`] as well as for the adapters of [`PinInit`] wrapping them. Functions
+/// that return such an initializer need to return `impl PinPlaceInit<T, E>` to keep the marker.
+///
+/// # Examples
+///
+/// ```rust
+/// # use pinned_init::*;
+/// # use core::{convert::Infallible, pin::Pin};
+/// #[pin_data]
+/// struct Buffer {
+///     data: [u8; 1024],
+/// }
+///
+/// fn new_buffer() -> impl PinPlaceInit<Buffer, Infallible> {
+///     pin_init!(Buffer { data <- zeroed() })
+/// }
+///
+/// fn store(init: impl PinPlaceInit<Buffer, Infallible>) -> Pin<Box<Buffer>> {
+///     Box::pin_init(init).unwrap()
+/// }
+///
+/// let buf = store(new_buffer());
+/// assert_eq!(buf.data, [0; 1024]);
+/// ```
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not an in-place initializer for `{T}` with error `{E}`",
+    label = "expected an `impl PinPlaceInit<{T}, {E}>`",
+    note = "values of type `{T}` cannot be used here, since they are constructed before being moved into place; consider using `[try_]pin_init!`"
+)]
+pub trait PinPlaceInit<T: ?Sized, E = Infallible>: PinInit<T, E> {}
+
+/// An [`Init<T, E>`] that initializes the slot in place.
+///
+/// This is the [`Init`] version of [`PinPlaceInit`]. It is implemented for the initializers
+/// returned by [`init!`], [`try_init!`] and [`init_from_closure`] as well as for the adapters of
+/// [`Init`] wrapping them, but not for `T`.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not an in-place initializer for `{T}` with error `{E}` that allows moving",
+    label = "expected an `impl PlaceInit<{T}, {E}>`",
+    note = "values of type `{T}` cannot be used here, since they are constructed before being moved into place; consider using `[try_]init!`"
+)]
+pub trait PlaceInit<T: ?Sized, E = Infallible>: Init<T, E> + PinPlaceInit<T, E> {}
+
+/// Smart pointer that can initialize memory in-place.
+///
+/// Initializers compose, so nested types are also initialized in-place without creating
+/// temporaries on the stack. When the inner type itself needs to be allocated, the inner smart
+/// pointer is allocated and initialized first, only the pointer is then moved into the outer
+/// allocation:
+///
+/// ```rust
+/// # #![feature(allocator_api)]
+/// # #[path = "../examples/mutex.rs"] mod mutex; use mutex::*;
+/// # use pinned_init::*;
+/// # use core::pin::Pin;
+/// use std::sync::Arc;
+/// // The array is directly zeroed inside of the `Arc`.
+/// let mtx: Pin<Arc<CMutex<[u8; 1024 * 1024]>>> = Arc::pin_init(CMutex::new(zeroed())).unwrap();
+/// // The array is directly zeroed inside of the `Box`, which is then moved into the `Arc`.
+/// let mtx: Pin<Arc<CMutex<Box<[u8; 1024 * 1024]>>>> =
+///     Arc::pin_init(CMutex::new(Box::init(zeroed()).unwrap())).unwrap();
+/// ```
+pub trait InPlaceInit<T>: Sized {
+    /// Use the given pin-initializer to pin-initialize a `T` inside of a new smart pointer of this
+    /// type.
+    ///
+    /// If `T: !Unpin` it will not be able to move afterwards.
+    fn try_pin_init<E>(init: impl PinInit<T, E>) -> Result<Pin<Self>, E>
+    where
+        E: From<AllocError>;
+
+    /// Use the given pin-initializer to pin-initialize a `T` inside of a new smart pointer of this
+    /// type.
+    ///
+    /// If `T: !Unpin` it will not be able to move afterwards.
+    ///
+    /// Every [`Init`] is also a [`PinInit`], so this also accepts initializers that do not require
+    /// pinning. This is useful when an API expects a `Pin<Self>`:
+    ///
+    /// ```rust
+    /// # #![feature(allocator_api)]
+    /// # use pinned_init::*;
+    /// # use core::pin::Pin;
+    /// use std::sync::Arc;
+    /// fn buffer() -> impl Init<[u8; 64]> {
+    ///     zeroed()
+    /// }
+    ///
+    /// let buf: Pin<Arc<[u8; 64]>> = Arc::pin_init(buffer()).unwrap();
+    /// assert_eq!(buf[..], [0; 64]);
+    /// ```
+    fn pin_init(init: impl PinInit<T>) -> Result<Pin<Self>, AllocError> {
+        // SAFETY: We delegate to `init` and only change the error type.
+        let init = unsafe {
+            pin_init_from_closure(|slot| match init.__pinned_init(slot) {
+                Ok(()) => Ok(()),
+                Err(i) => match i {},
+            })
+        };
+        Self::try_pin_init(init)
+    }
+
+    /// Use the given initializer to in-place initialize a `T`.
+    fn try_init<E>(init: impl Init<T, E>) -> Result<Self, E>
+    where
+        E: From<AllocError>;
+
+    /// Use the given initializer to in-place initialize a `T`.
+    fn init(init: impl Init<T>) -> Result<Self, AllocError> {
+        // SAFETY: We delegate to `init` and only change the error type.
+        let init = unsafe {
+            init_from_closure(|slot| match init.__init(slot) {
+                Ok(()) => Ok(()),
+                Err(i) => match i {},
+            })
+        };
+        Self::try_init(init)
+    }
+
+    /// Like [`InPlaceInit::try_pin_init`], but allocation failures report the requested layout.
+    ///
+    /// When the allocation fails, the error is a [`LayoutAllocError`] containing
+    /// `Layout::new::<T>()`. This helps to debug allocation failures of large types.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #![feature(allocator_api)]
+    /// # use pinned_init::*;
+    /// # use core::pin::Pin;
+    /// let buf: Result<Pin<Box<[u8; 4096]>>, LayoutAllocError> =
+    ///     Box::try_pin_init_verbose(init_array_from_fn(|_| 0));
+    /// assert_eq!(*buf.unwrap(), [0; 4096]);
+    /// ```
+    fn try_pin_init_verbose<E>(init: impl PinInit<T, E>) -> Result<Pin<Self>, E>
+    where
+        E: From<LayoutAllocError>,
+    {
+        // SAFETY: We delegate to `init` and only change the error type.
+        let init = unsafe {
+            pin_init_from_closure(|slot| init.__pinned_init(slot).map_err(VerboseError::Init))
+        };
+        Self::try_pin_init(init).map_err(VerboseError::into_inner::<T>)
+    }
+
+    /// Like [`InPlaceInit::try_init`], but allocation failures report the requested layout.
+    ///
+    /// When the allocation fails, the error is a [`LayoutAllocError`] containing
+    /// `Layout::new::<T>()`. This helps to debug allocation failures of large types.
+    fn try_init_verbose<E>(init: impl Init<T, E>) -> Result<Self, E>
+    where
+        E: From<LayoutAllocError>,
+    {
+        // SAFETY: We delegate to `init` and only change the error type.
+        let init =
+            unsafe { init_from_closure(|slot| init.__init(slot).map_err(VerboseError::Init)) };
+        Self::try_init(init).map_err(VerboseError::into_inner::<T>)
+    }
+}
+
+/// The error of an allocation that failed, returned by [`InPlaceInit::try_pin_init_verbose`] and
+/// [`InPlaceInit::try_init_verbose`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LayoutAllocError {
+    /// The layout of the allocation that was requested.
+    pub layout: Layout,
+}
+
+/// The error used by the verbose methods of [`InPlaceInit`] to tell allocation failures apart from
+/// errors of the initializer.
+enum VerboseError<E> {
+    Init(E),
+    Alloc,
+}
+
+impl<E> From<AllocError> for VerboseError<E> {
+    fn from(_: AllocError) -> Self {
+        Self::Alloc
+    }
+}
+
+impl<E: From<LayoutAllocError>> VerboseError<E> {
+    fn into_inner<T>(self) -> E {
+        match self {
+            Self::Init(err) => err,
+            Self::Alloc => LayoutAllocError {
+                layout: Layout::new::<T>(),
+            }
+            .into(),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+macro_rules! try_new_uninit {
+    ($type:ident) => {
+        $type::try_new_uninit()?
+    };
+}
+#[cfg(all(feature = "std", not(feature = "alloc")))]
+macro_rules! try_new_uninit {
+    ($type:ident) => {
+        $type::new_uninit()
+    };
+}
+
+/// Smart pointers containing uninitialized memory for a `T`.
+///
+/// This is used to implement [`InPlaceInit`] for every smart pointer with the same code.
+///
+/// # Safety
+///
+/// - [`UninitSink::slot`] returns a pointer to the memory owned by `self` that is valid for writes
+///   of a `T`,
+/// - [`UninitSink::assume_init`] returns a smart pointer to the same memory, which does not move
+///   the value out of the memory.
+#[cfg(any(feature = "std", feature = "alloc"))]
+unsafe trait UninitSink<T>: Sized {
+    /// The smart pointer to the initialized value.
+    type Init: core::ops::Deref<Target = T>;
+
+    /// Allocates uninitialized memory for a `T`.
+    fn try_uninit() -> Result<Self, AllocError>;
+
+    /// Returns a pointer to the uninitialized memory.
+    ///
+    /// # Safety
+    ///
+    /// `self` has been returned by [`UninitSink::try_uninit`] and has not been shared.
+    unsafe fn slot(&mut self) -> *mut T;
+
+    /// Turns `self` into a smart pointer to the initialized value.
+    ///
+    /// # Safety
+    ///
+    /// The memory returned by [`UninitSink::slot`] has been initialized.
+    unsafe fn assume_init(self) -> Self::Init;
+}
+
+// SAFETY: The slot is the memory of the box and `assume_init` returns the same box.
+#[cfg(any(feature = "std", feature = "alloc"))]
+unsafe impl<T> UninitSink<T> for Box<MaybeUninit<T>> {
+    type Init = Box<T>;
+
+    #[inline]
+    fn try_uninit() -> Result<Self, AllocError> {
+        Ok(try_new_uninit!(Box))
+    }
+
+    #[inline]
+    unsafe fn slot(&mut self) -> *mut T {
+        self.as_mut_ptr()
+    }
+
+    #[inline]
+    unsafe fn assume_init(self) -> Self::Init {
+        // SAFETY: The caller guarantees that the value has been initialized.
+        unsafe { Box::<MaybeUninit<T>>::assume_init(self) }
+    }
+}
+
+// SAFETY: The slot is the memory of the `Arc` and `assume_init` returns the same `Arc`.
+#[cfg(any(feature = "std", feature = "alloc"))]
+unsafe impl<T> UninitSink<T> for Arc<MaybeUninit<T>> {
+    type Init = Arc<T>;
+
+    #[inline]
+    fn try_uninit() -> Result<Self, AllocError> {
+        Ok(try_new_uninit!(Arc))
+    }
+
+    #[inline]
+    unsafe fn slot(&mut self) -> *mut T {
+        let Some(slot) = Arc::get_mut(self) else {
+            // SAFETY: the Arc has just been created and has no external referecnes
+            unsafe { core::hint::unreachable_unchecked() }
+        };
+        slot.as_mut_ptr()
+    }
+
+    #[inline]
+    unsafe fn assume_init(self) -> Self::Init {
+        // SAFETY: The caller guarantees that the value has been initialized.
+        unsafe { Arc::<MaybeUninit<T>>::assume_init(self) }
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<T> InPlaceInit<T> for Box<T> {
+    #[inline]
+    fn try_pin_init<E>(init: impl PinInit<T, E>) -> Result<Pin<Self>, E>
+    where
+        E: From<AllocError>,
+    {
+        try_pin_init_in::<Box<MaybeUninit<T>>, T, E>(init)
+    }
+
+    #[inline]
+    fn try_init<E>(init: impl Init<T, E>) -> Result<Self, E>
+    where
+        E: From<AllocError>,
+    {
+        try_init_in::<Box<MaybeUninit<T>>, T, E>(init)
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<T> InPlaceInit<T> for Arc<T> {
+    #[inline]
+    fn try_pin_init<E>(init: impl PinInit<T, E>) -> Result<Pin<Self>, E>
+    where
+        E: From<AllocError>,
+    {
+        try_pin_init_in::<Arc<MaybeUninit<T>>, T, E>(init)
+    }
+
+    #[inline]
+    fn try_init<E>(init: impl Init<T, E>) -> Result<Self, E>
+    where
+        E: From<AllocError>,
+    {
+        try_init_in::<Arc<MaybeUninit<T>>, T, E>(init)
+    }
+}
+
+/// Pin-initializes a `T` inside of new memory allocated by `S`.
+#[cfg(any(feature = "std", feature = "alloc"))]
 #[inline]
-pub const unsafe fn init_from_closure<T: ?Sized, E>(
-    f: impl FnOnce(*mut T) -> Result<(), E>,
-) -> impl Init<T, E> {
-    __internal::InitClosure(f, PhantomData)
+fn try_pin_init_in<S, T, E>(init: impl PinInit<T, E>) -> Result<Pin<S::Init>, E>
+where
+    S: UninitSink<T>,
+    E: From<AllocError>,
+{
+    let mut this = S::try_uninit()?;
+    // SAFETY: `this` has just been created and is not shared.
+    let slot = unsafe { this.slot() };
+    // SAFETY: `slot` is valid and uninitialized.
+    unsafe { __internal::poison(slot) };
+    // SAFETY: When init errors/panics, slot will get deallocated but not dropped,
+    // slot is valid and will not be moved, because we pin it later.
+    unsafe { init.__pinned_init(slot)? };
+    // SAFETY: All fields have been initialized.
+    let this = unsafe { this.assume_init() };
+    // SAFETY: The smart pointer does not move the value and it is the only pointer to it.
+    Ok(unsafe { Pin::new_unchecked(this) })
 }
 
-/// An initializer that leaves the memory uninitialized.
+/// Initializes a `T` inside of new memory allocated by `S`.
+#[cfg(any(feature = "std", feature = "alloc"))]
+#[inline]
+fn try_init_in<S, T, E>(init: impl Init<T, E>) -> Result<S::Init, E>
+where
+    S: UninitSink<T>,
+    E: From<AllocError>,
+{
+    let mut this = S::try_uninit()?;
+    // SAFETY: `this` has just been created and is not shared.
+    let slot = unsafe { this.slot() };
+    // SAFETY: `slot` is valid and uninitialized.
+    unsafe { __internal::poison(slot) };
+    // SAFETY: When init errors/panics, slot will get deallocated but not dropped,
+    // slot is valid.
+    unsafe { init.__init(slot)? };
+    // SAFETY: All fields have been initialized.
+    Ok(unsafe { this.assume_init() })
+}
+
+/// Initializes a [`Box<T>`] field by allocating the box and initializing `T` inside of it.
+///
+/// The allocation happens when the returned initializer runs, so the outer initializer decides
+/// when the box is allocated. The value is directly initialized inside of the new box, only the
+/// pointer is written to the slot.
+///
+/// The returned initializer has the error type `E2`, which needs to be constructible from both `E`
+/// and [`AllocError`]. Since the error type of field initializers cannot be inferred, you have to
+/// specify it explicitly: `boxed_init::<_, _, Error>(init)`. When `init` is a value, `E` also has to
+/// be specified.
+///
+/// # Examples
+///
+/// ```rust
+/// # #![feature(allocator_api)]
+/// # #[path = "../examples/error.rs"] mod error; use error::Error;
+/// # use pinned_init::*;
+/// # use core::convert::Infallible;
+/// struct Buffers {
+///     small: Box<[u8; 16]>,
+///     big: Box<[u8; 1024 * 1024]>,
+/// }
 ///
-/// The initializer is a no-op. The `slot` memory is not changed.
-#[inline]
-pub fn uninit<T, E>() -> impl Init<MaybeUninit<T>, E> {
-    // SAFETY: The memory is allowed to be uninitialized.
-    unsafe { init_from_closure(|_| Ok(())) }
+/// let buffers = Box::try_init(try_init!(Buffers {
+///     small <- boxed_init::<_, Infallible, Error>([1; 16]),
+///     big <- boxed_init::<_, _, Error>(zeroed()),
+/// }? Error))
+/// .unwrap();
+/// assert_eq!((buffers.small[0], buffers.big[0]), (1, 0));
+/// ```
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub fn boxed_init<T, E, E2>(init: impl Init<T, E>) -> impl Init<Box<T>, E2>
+where
+    E2: From<E> + From<AllocError>,
+{
+    let init = move |slot: *mut Box<T>| {
+        let value = try_new_uninit!(Box).write_init(init).map_err(E2::from)?;
+        // SAFETY: `slot` is valid uninitialized memory.
+        unsafe { slot.write(value) };
+        Ok(())
+    };
+    // SAFETY: The closure initializes `slot` when it returns `Ok` and does not touch it otherwise.
+    unsafe { init_from_closure(init) }
 }
 
-/// Initializes an array by initializing each element via the provided initializer.
+/// Initializes a [`Pin<Box<T>>`] field by allocating the box and pin-initializing `T` inside of it.
+///
+/// This is the pinned version of [`boxed_init`]. Since the value is pinned inside of its own
+/// allocation, the field itself does not need to be structurally pinned and the outer struct can
+/// be moved.
+///
+/// The returned initializer has the error type `E2`, which needs to be constructible from both `E`
+/// and [`AllocError`]. It has to be specified explicitly: `box_pin_init::<_, _, Error>(init)`.
 ///
 /// # Examples
 ///
 /// ```rust
+/// # #![feature(allocator_api)]
+/// # #[path = "../examples/error.rs"] mod error; use error::Error;
+/// # #[path = "../examples/mutex.rs"] mod mutex; use mutex::*;
 /// # use pinned_init::*;
-/// use pinned_init::init_array_from_fn;
-/// let array: Box<[usize; 1_000]> = Box::init(init_array_from_fn(|i| i)).unwrap();
-/// assert_eq!(array.len(), 1_000);
+/// # use core::pin::Pin;
+/// struct Device {
+///     id: u32,
+///     state: Pin<Box<CMutex<u32>>>,
+/// }
+///
+/// let dev = Box::try_init(try_init!(Device {
+///     id: 1,
+///     state <- box_pin_init::<_, _, Error>(CMutex::new(0)),
+/// }? Error))
+/// .unwrap();
+/// assert_eq!(*dev.state.lock(), 0);
 /// ```
-pub fn init_array_from_fn<I, const N: usize, T, E>(
-    mut make_init: impl FnMut(usize) -> I,
-) -> impl Init<[T; N], E>
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub fn box_pin_init<T, E, E2>(init: impl PinInit<T, E>) -> impl Init<Pin<Box<T>>, E2>
 where
-    I: Init<T, E>,
+    E2: From<E> + From<AllocError>,
 {
-    let init = move |slot: *mut [T; N]| {
-        let slot = slot.cast::<T>();
-        for i in 0..N {
-            let init = make_init(i);
-            // SAFETY: Since 0 <= `i` < N, it is still in bounds of `[T; N]`.
-            let ptr = unsafe { slot.add(i) };
-            // SAFETY: The pointer is derived from `slot` and thus satisfies the `__init`
-            // requirements.
-            match unsafe { init.__init(ptr) } {
-                Ok(()) => {}
-                Err(e) => {
-                    // SAFETY: The loop has initialized the elements `slot[0..i]` and since we
-                    // return `Err` below, `slot` will be considered uninitialized memory.
-                    unsafe { ptr::drop_in_place(ptr::slice_from_raw_parts_mut(slot, i)) };
-                    return Err(e);
-                }
-            }
-        }
+    let init = move |slot: *mut Pin<Box<T>>| {
+        let value = try_new_uninit!(Box)
+            .write_pin_init(init)
+            .map_err(E2::from)?;
+        // SAFETY: `slot` is valid uninitialized memory.
+        unsafe { slot.write(value) };
         Ok(())
     };
-    // SAFETY: The initializer above initializes every element of the array. On failure it drops
-    // any initialized elements and returns `Err`.
+    // SAFETY: The closure initializes `slot` when it returns `Ok` and does not touch it otherwise.
     unsafe { init_from_closure(init) }
 }
 
-/// Initializes an array by initializing each element via the provided initializer.
+/// Initializes a [`Pin<Arc<T>>`] field by allocating the `Arc` and pin-initializing `T` inside of
+/// it.
+///
+/// This is the [`Arc`] version of [`box_pin_init`]. The value is pinned inside of its own
+/// allocation, so the outer struct can be moved.
+///
+/// The returned initializer has the error type `E2`, which needs to be constructible from both `E`
+/// and [`AllocError`]. It has to be specified explicitly: `arc_pin_init::<_, _, Error>(init)`.
 ///
 /// # Examples
 ///
 /// ```rust
 /// # #![feature(allocator_api)]
+/// # #[path = "../examples/error.rs"] mod error; use error::Error;
 /// # #[path = "../examples/mutex.rs"] mod mutex; use mutex::*;
 /// # use pinned_init::*;
 /// # use core::pin::Pin;
-/// use pinned_init::pin_init_array_from_fn;
 /// use std::sync::Arc;
-/// let array: Pin<Arc<[CMutex<usize>; 1_000]>> =
-///     Arc::pin_init(pin_init_array_from_fn(|i| CMutex::new(i))).unwrap();
-/// assert_eq!(array.len(), 1_000);
+/// struct Device {
+///     id: u32,
+///     state: Pin<Arc<CMutex<u32>>>,
+/// }
+///
+/// let dev = Box::try_init(try_init!(Device {
+///     id: 1,
+///     state <- arc_pin_init::<_, _, Error>(CMutex::new(0)),
+/// }? Error))
+/// .unwrap();
+/// let state = dev.state.clone();
+/// *state.lock() = 3;
+/// assert_eq!(*dev.state.lock(), 3);
 /// ```
-pub fn pin_init_array_from_fn<I, const N: usize, T, E>(
-    mut make_init: impl FnMut(usize) -> I,
-) -> impl PinInit<[T; N], E>
+///
+/// [`Arc`]: alloc::sync::Arc
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub fn arc_pin_init<T, E, E2>(init: impl PinInit<T, E>) -> impl Init<Pin<Arc<T>>, E2>
 where
-    I: PinInit<T, E>,
+    E2: From<E> + From<AllocError>,
 {
-    let init = move |slot: *mut [T; N]| {
-        let slot = slot.cast::<T>();
-        for i in 0..N {
-            let init = make_init(i);
-            // SAFETY: Since 0 <= `i` < N, it is still in bounds of `[T; N]`.
-            let ptr = unsafe { slot.add(i) };
-            // SAFETY: The pointer is derived from `slot` and thus satisfies the `__init`
-            // requirements.
-            match unsafe { init.__pinned_init(ptr) } {
-                Ok(()) => {}
-                Err(e) => {
-                    // SAFETY: The loop has initialized the elements `slot[0..i]` and since we
-                    // return `Err` below, `slot` will be considered uninitialized memory.
-                    unsafe { ptr::drop_in_place(ptr::slice_from_raw_parts_mut(slot, i)) };
-                    return Err(e);
-                }
-            }
-        }
+    let init = move |slot: *mut Pin<Arc<T>>| {
+        // SAFETY: We delegate to `init` and only change the error type.
+        let init =
+            unsafe { pin_init_from_closure(|slot| init.__pinned_init(slot).map_err(E2::from)) };
+        let value = Arc::try_pin_init(init)?;
+        // SAFETY: `slot` is valid uninitialized memory.
+        unsafe { slot.write(value) };
         Ok(())
     };
-    // SAFETY: The initializer above initializes every element of the array. On failure it drops
-    // any initialized elements and returns `Err`.
-    unsafe { pin_init_from_closure(init) }
+    // SAFETY: The closure initializes `slot` when it returns `Ok` and does not touch it otherwise.
+    unsafe { init_from_closure(init) }
 }
 
-// SAFETY: Every type can be initialized by-value.
-unsafe impl<T, E> Init<T, E> for T {
-    unsafe fn __init(self, slot: *mut T) -> Result<(), E> {
-        // SAFETY: TODO.
-        unsafe { slot.write(self) };
-        Ok(())
-    }
+/// Allocates and initializes a header and a zeroed tail of `tail_len` elements.
+///
+/// This emulates a C struct with a flexible array member, where the header and the trailing array
+/// are allocated separately. The header is initialized in-place by `header`, the tail is zeroed
+/// without creating it on the stack first.
+///
+/// # Examples
+///
+/// ```rust
+/// # #![feature(allocator_api)]
+/// # use pinned_init::*;
+/// struct Header {
+///     len: usize,
+/// }
+///
+/// let (header, tail) =
+///     alloc_header_with_zeroed_tail::<Header, u32>(init!(Header { len: 16 }), 16).unwrap();
+/// assert_eq!(header.len, tail.len());
+/// assert!(tail.iter().all(|x| *x == 0));
+/// ```
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub fn alloc_header_with_zeroed_tail<H, T: Zeroable>(
+    header: impl Init<H>,
+    tail_len: usize,
+) -> Result<(Box<H>, Box<[T]>), AllocError> {
+    let header = Box::init(header)?;
+    #[cfg(feature = "alloc")]
+    let mut tail = Box::<[T]>::try_new_uninit_slice(tail_len)?;
+    #[cfg(not(feature = "alloc"))]
+    let mut tail = Box::<[T]>::new_uninit_slice(tail_len);
+    // SAFETY: `tail` is valid for writes of `tail_len` elements.
+    unsafe { ptr::write_bytes(tail.as_mut_ptr(), 0, tail_len) };
+    // SAFETY: All elements have been zeroed and `T: Zeroable`.
+    Ok((header, unsafe { tail.assume_init() }))
 }
 
-// SAFETY: Every type can be initialized by-value. `__pinned_init` calls `__init`.
-unsafe impl<T, E> PinInit<T, E> for T {
-    unsafe fn __pinned_init(self, slot: *mut T) -> Result<(), E> {
-        // SAFETY: TODO.
-        unsafe { self.__init(slot) }
-    }
-}
+/// Smart pointers that can be pin-initialized with a weak pointer to the value that is being
+/// initialized.
+///
+/// This is the in-place version of [`Arc::new_cyclic`], it allows storing a weak pointer to the
+/// value inside of the value itself.
+///
+/// # Examples
+///
+/// ```rust
+/// # #![feature(allocator_api)]
+/// # use pinned_init::*;
+/// # use core::{convert::Infallible, marker::PhantomPinned, pin::Pin};
+/// use std::sync::{Arc, Weak};
+///
+/// #[pin_data]
+/// struct Node {
+///     me: Weak<Node>,
+///     #[pin]
+///     pin: PhantomPinned,
+/// }
+///
+/// let node: Pin<Arc<Node>> = Arc::pin_init_cyclic(|me: &Weak<Node>| {
+///     let me = me.clone();
+///     pin_init!(Node {
+///         me,
+///         pin: PhantomPinned,
+///     })
+/// })
+/// .unwrap_or_else(|e: Infallible| match e {});
+/// assert!(core::ptr::eq(&*node.me.upgrade().unwrap(), &*node));
+/// ```
+#[cfg(feature = "cyclic")]
+pub trait InPlaceInitCyclic<T>: Sized {
+    /// The weak pointer type of this smart pointer.
+    type Weak;
 
-/// Smart pointer that can initialize memory in-place.
-pub trait InPlaceInit<T>: Sized {
     /// Use the given pin-initializer to pin-initialize a `T` inside of a new smart pointer of this
-    /// type.
+    /// type, the pin-initializer is created by `init` from a weak pointer to the new value.
     ///
-    /// If `T: !Unpin` it will not be able to move afterwards.
-    fn try_pin_init<E>(init: impl PinInit<T, E>) -> Result<Pin<Self>, E>
+    /// Since the fields are initialized when the returned pin-initializer runs, the weak pointer
+    /// has to be cloned by `init` to be stored in the value. Upgrading the weak pointer fails until
+    /// the initialization has succeeded. If it fails, the weak pointer can never be upgraded.
+    ///
+    /// In contrast to [`InPlaceInit::try_pin_init`], allocation failure is not returned as an
+    /// error: the weak pointer has to exist before the value is initialized, which needs a
+    /// `UniqueArc`, and `UniqueArc` has no fallible constructor. So the allocation aborts or panics
+    /// on failure, like [`Arc::new_cyclic`].
+    fn pin_init_cyclic<I, E>(init: impl FnOnce(&Self::Weak) -> I) -> Result<Pin<Self>, E>
     where
-        E: From<AllocError>;
+        I: PinInit<T, E>;
+}
 
-    /// Use the given pin-initializer to pin-initialize a `T` inside of a new smart pointer of this
-    /// type.
-    ///
-    /// If `T: !Unpin` it will not be able to move afterwards.
-    fn pin_init(init: impl PinInit<T>) -> Result<Pin<Self>, AllocError> {
-        // SAFETY: We delegate to `init` and only change the error type.
-        let init = unsafe {
-            pin_init_from_closure(|slot| match init.__pinned_init(slot) {
-                Ok(()) => Ok(()),
-                Err(i) => match i {},
-            })
-        };
-        Self::try_pin_init(init)
-    }
+#[cfg(feature = "cyclic")]
+impl<T> InPlaceInitCyclic<T> for Arc<T> {
+    type Weak = alloc::sync::Weak<T>;
 
-    /// Use the given initializer to in-place initialize a `T`.
-    fn try_init<E>(init: impl Init<T, E>) -> Result<Self, E>
+    fn pin_init_cyclic<I, E>(init: impl FnOnce(&Self::Weak) -> I) -> Result<Pin<Self>, E>
     where
-        E: From<AllocError>;
+        I: PinInit<T, E>,
+    {
+        use alloc::sync::{UniqueArc, Weak};
 
-    /// Use the given initializer to in-place initialize a `T`.
-    fn init(init: impl Init<T>) -> Result<Self, AllocError> {
-        // SAFETY: We delegate to `init` and only change the error type.
-        let init = unsafe {
-            init_from_closure(|slot| match init.__init(slot) {
-                Ok(()) => Ok(()),
-                Err(i) => match i {},
-            })
-        };
-        Self::try_init(init)
+        // While `this` exists, the strong count is zero, so the weak pointers cannot be upgraded.
+        // There is no `UniqueArc::try_new`, so this aborts on allocation failure.
+        let mut this = UniqueArc::new(MaybeUninit::<T>::uninit());
+        let weak = Weak::into_raw(UniqueArc::downgrade(&this));
+        // SAFETY: `weak` has been created by `Weak::into_raw` above. `MaybeUninit<T>` has the same
+        // size and alignment as `T`.
+        let weak = unsafe { Weak::from_raw(weak.cast::<T>()) };
+        let slot = this.as_mut_ptr();
+        // SAFETY: `slot` is valid and uninitialized.
+        unsafe { __internal::poison(slot) };
+        let init = init(&weak);
+        // SAFETY: When init errors/panics, slot will get deallocated but not dropped,
+        // slot is valid and will not be moved, because we pin it later.
+        unsafe { init.__pinned_init(slot)? };
+        // SAFETY: All fields have been initialized.
+        let this = unsafe { UniqueArc::into_arc(this).assume_init() };
+        // SAFETY: The value is only accessible via `this` and the weak pointers, it is never moved.
+        Ok(unsafe { Pin::new_unchecked(this) })
     }
 }
 
-#[cfg(feature = "alloc")]
-macro_rules! try_new_uninit {
-    ($type:ident) => {
-        $type::try_new_uninit()?
-    };
-}
-#[cfg(all(feature = "std", not(feature = "alloc")))]
-macro_rules! try_new_uninit {
-    ($type:ident) => {
-        $type::new_uninit()
-    };
+/// Smart pointer containing uninitialized memory and that can write a value.
+pub trait InPlaceWrite<T> {
+    /// The type `Self` turns into when the contents are initialized.
+    type Initialized;
+
+    /// Use the given initializer to write a value into `self`.
+    ///
+    /// Does not drop the current value and considers it as uninitialized memory.
+    fn write_init<E>(self, init: impl Init<T, E>) -> Result<Self::Initialized, E>;
+
+    /// Use the given pin-initializer to write a value into `self`.
+    ///
+    /// Does not drop the current value and considers it as uninitialized memory.
+    fn write_pin_init<E>(self, init: impl PinInit<T, E>) -> Result<Pin<Self::Initialized>, E>;
 }
 
 #[cfg(any(feature = "std", feature = "alloc"))]
-impl<T> InPlaceInit<T> for Box<T> {
-    #[inline]
-    fn try_pin_init<E>(init: impl PinInit<T, E>) -> Result<Pin<Self>, E>
-    where
-        E: From<AllocError>,
-    {
-        try_new_uninit!(Box).write_pin_init(init)
-    }
+impl<T> InPlaceWrite<T> for Box<MaybeUninit<T>> {
+    type Initialized = Box<T>;
 
-    #[inline]
-    fn try_init<E>(init: impl Init<T, E>) -> Result<Self, E>
-    where
-        E: From<AllocError>,
-    {
-        try_new_uninit!(Box).write_init(init)
+    fn write_init<E>(mut self, init: impl Init<T, E>) -> Result<Self::Initialized, E> {
+        let slot = self.as_mut_ptr();
+        // SAFETY: `slot` is valid and uninitialized.
+        unsafe { __internal::poison(slot) };
+        // SAFETY: When init errors/panics, slot will get deallocated but not dropped,
+        // slot is valid.
+        unsafe { init.__init(slot)? };
+        // SAFETY: All fields have been initialized.
+        Ok(unsafe { self.assume_init() })
     }
-}
 
-#[cfg(any(feature = "std", feature = "alloc"))]
-impl<T> InPlaceInit<T> for Arc<T> {
-    #[inline]
-    fn try_pin_init<E>(init: impl PinInit<T, E>) -> Result<Pin<Self>, E>
-    where
-        E: From<AllocError>,
-    {
-        let mut this = try_new_uninit!(Arc);
-        let Some(slot) = Arc::get_mut(&mut this) else {
-            // SAFETY: the Arc has just been created and has no external referecnes
-            unsafe { core::hint::unreachable_unchecked() }
-        };
-        let slot = slot.as_mut_ptr();
+    fn write_pin_init<E>(mut self, init: impl PinInit<T, E>) -> Result<Pin<Self::Initialized>, E> {
+        let slot = self.as_mut_ptr();
+        // SAFETY: `slot` is valid and uninitialized.
+        unsafe { __internal::poison(slot) };
         // SAFETY: When init errors/panics, slot will get deallocated but not dropped,
         // slot is valid and will not be moved, because we pin it later.
         unsafe { init.__pinned_init(slot)? };
-        // SAFETY: All fields have been initialized and this is the only `Arc` to that data.
-        Ok(unsafe { Pin::new_unchecked(this.assume_init()) })
+        // SAFETY: All fields have been initialized.
+        Ok(unsafe { self.assume_init() }.into())
     }
+}
 
-    #[inline]
-    fn try_init<E>(init: impl Init<T, E>) -> Result<Self, E>
-    where
-        E: From<AllocError>,
-    {
-        let mut this = try_new_uninit!(Arc);
-        let Some(slot) = Arc::get_mut(&mut this) else {
-            // SAFETY: the Arc has just been created and has no external referecnes
-            unsafe { core::hint::unreachable_unchecked() }
-        };
-        let slot = slot.as_mut_ptr();
-        // SAFETY: When init errors/panics, slot will get deallocated but not dropped,
-        // slot is valid.
-        unsafe { init.__init(slot)? };
-        // SAFETY: All fields have been initialized.
-        Ok(unsafe { this.assume_init() })
+/// Smart pointer containing uninitialized memory that can be reused when initialization fails.
+pub trait InPlaceWriteKeepAlloc<T>: InPlaceWrite<T> + Sized {
+    /// Use the given pin-initializer to write a value into `self`, returning `self` on error.
+    ///
+    /// In contrast to [`InPlaceWrite::write_pin_init`], the memory is not freed when `init` fails.
+    /// This allows pools to keep the allocation and to retry the initialization in the same memory.
+    ///
+    /// Does not drop the current value and considers it as uninitialized memory.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #![feature(allocator_api)]
+    /// # #[path = "../examples/error.rs"] mod error; use error::Error;
+    /// # use pinned_init::*;
+    /// # use core::mem::MaybeUninit;
+    /// let slot: Box<MaybeUninit<[u64; 512]>> = Box::new_uninit();
+    /// let failing = pin_init_from_fn(|| Err(Error));
+    /// let (slot, _) = slot.write_pin_init_keep_alloc(failing).err().unwrap();
+    /// let array = slot.write_pin_init_keep_alloc(zeroed()).ok().unwrap();
+    /// assert_eq!(array[511], 0);
+    /// ```
+    fn write_pin_init_keep_alloc<E>(
+        self,
+        init: impl PinInit<T, E>,
+    ) -> Result<Pin<Self::Initialized>, (Self, E)>;
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<T> InPlaceWriteKeepAlloc<T> for Box<MaybeUninit<T>> {
+    fn write_pin_init_keep_alloc<E>(
+        mut self,
+        init: impl PinInit<T, E>,
+    ) -> Result<Pin<Self::Initialized>, (Self, E)> {
+        let slot = self.as_mut_ptr();
+        // SAFETY: `slot` is valid and uninitialized.
+        unsafe { __internal::poison(slot) };
+        // SAFETY: When init errors, slot is uninitialized again and is given back, when it panics,
+        // slot will get deallocated but not dropped. Slot is valid and will not be moved, because
+        // we pin it later.
+        match unsafe { init.__pinned_init(slot) } {
+            // SAFETY: All fields have been initialized.
+            Ok(()) => Ok(unsafe { self.assume_init() }.into()),
+            Err(err) => Err((self, err)),
+        }
     }
 }
 
-/// Smart pointer containing uninitialized memory and that can write a value.
-pub trait InPlaceWrite<T> {
-    /// The type `Self` turns into when the contents are initialized.
-    type Initialized;
-
-    /// Use the given initializer to write a value into `self`.
-    ///
-    /// Does not drop the current value and considers it as uninitialized memory.
-    fn write_init<E>(self, init: impl Init<T, E>) -> Result<Self::Initialized, E>;
-
-    /// Use the given pin-initializer to write a value into `self`.
-    ///
-    /// Does not drop the current value and considers it as uninitialized memory.
-    fn write_pin_init<E>(self, init: impl PinInit<T, E>) -> Result<Pin<Self::Initialized>, E>;
+/// Pin-initializes the value inside of a pinned [`MaybeUninit<T>`].
+///
+/// This is the primitive that [`stack_pin_init!`] uses, exposed for custom pinned storage. The
+/// current contents of `slot` are considered uninitialized and are not dropped.
+///
+/// # Safety
+///
+/// Since dropping a [`MaybeUninit<T>`] does not drop the `T`, the caller has to uphold the drop
+/// guarantee of [`Pin`]: when this function returns `Ok`, the value has to be dropped in place
+/// before the memory of `slot` is deallocated or reused.
+///
+/// # Examples
+///
+/// ```rust
+/// # #![feature(allocator_api)]
+/// # #[path = "../examples/mutex.rs"] mod mutex; use mutex::*;
+/// # use pinned_init::*;
+/// # use core::{convert::Infallible, mem::MaybeUninit, pin::{pin, Pin}, ptr};
+/// let slot = pin!(MaybeUninit::uninit());
+/// // SAFETY: The mutex is dropped below, before `slot` goes out of scope.
+/// let mutex = unsafe { pin_init_pinned_slot::<_, Infallible>(slot, CMutex::new(42)) }.unwrap();
+/// assert_eq!(*mutex.lock(), 42);
+/// // SAFETY: The mutex is not used afterwards.
+/// unsafe { ptr::drop_in_place(Pin::get_unchecked_mut(mutex)) };
+/// ```
+pub unsafe fn pin_init_pinned_slot<T, E>(
+    slot: Pin<&mut MaybeUninit<T>>,
+    init: impl PinInit<T, E>,
+) -> Result<Pin<&mut T>, E> {
+    // SAFETY: We do not move out of `slot`, the value is initialized in place.
+    let slot = unsafe { Pin::into_inner_unchecked(slot) };
+    let ptr = slot.as_mut_ptr();
+    // SAFETY: `ptr` is valid and uninitialized.
+    unsafe { __internal::poison(ptr) };
+    // SAFETY: `ptr` is valid for writes and uninitialized. It will not be moved, since `slot` was
+    // pinned.
+    unsafe { init.__pinned_init(ptr)? };
+    // SAFETY: The value has been initialized above and stays pinned, since `slot` was pinned. The
+    // caller guarantees that it is dropped before the memory is reused.
+    Ok(unsafe { Pin::new_unchecked(slot.assume_init_mut()) })
+}
+
+/// Pin-initializes a `T` at the fixed address `addr` and returns a pinned `'static` reference to it.
+///
+/// This is useful for memory that is not managed by Rust, such as memory-mapped I/O regions, or
+/// for static buffers that are set up once. In contrast to the other ways of initializing a value,
+/// the slot is not poisoned when the `debug-poison` feature is enabled, since writing to
+/// memory-mapped registers can have side effects.
+///
+/// If `init` fails, the error is returned and the memory at `addr` is considered uninitialized
+/// again, so it can be used for another attempt.
+///
+/// # Safety
+///
+/// - `addr` must be non-null and aligned for `T`.
+/// - `addr` must be valid for reads and writes of `size_of::<T>()` bytes for the rest of the
+///   program. The memory must never be deallocated or reused, since the value is never dropped.
+/// - The memory at `addr` is considered uninitialized, anything stored there is overwritten
+///   without being dropped.
+/// - When this function returns `Ok`, the returned reference must be the only way the memory is
+///   accessed for the rest of the program. No other pointer or reference to it may be used, also
+///   not by other threads or by hardware, unless `T` is designed for such accesses (for example,
+///   by wrapping the registers in [`UnsafeCell`] and only accessing them with volatile
+///   operations).
+/// - The initializer writes to `addr` with ordinary stores, the memory has to allow them.
+///
+/// # Examples
+///
+/// ```rust
+/// # #![feature(allocator_api)]
+/// # #[path = "../examples/mutex.rs"] mod mutex; use mutex::*;
+/// # use pinned_init::*;
+/// # use core::{convert::Infallible, mem::MaybeUninit, ptr::addr_of_mut};
+/// static mut LOG: MaybeUninit<CMutex<[u8; 16]>> = MaybeUninit::uninit();
+///
+/// // SAFETY: `LOG` is only accessed here and lives for the rest of the program.
+/// let log = unsafe { pin_init_at::<_, Infallible>(addr_of_mut!(LOG).cast(), CMutex::new([0; 16])) }
+///     .unwrap();
+/// log.lock()[0] = 1;
+/// ```
+pub unsafe fn pin_init_at<T, E>(
+    addr: *mut T,
+    init: impl PinInit<T, E>,
+) -> Result<Pin<&'static mut T>, E> {
+    // SAFETY: `addr` is valid for writes, aligned and uninitialized. It will not be moved, since
+    // the caller guarantees that it is only accessed through the returned reference.
+    unsafe { init.__pinned_init(addr)? };
+    // SAFETY: The value has been initialized above. It is never moved, deallocated or accessed in
+    // any other way, as guaranteed by the caller.
+    Ok(unsafe { Pin::new_unchecked(&mut *addr) })
 }
 
-#[cfg(any(feature = "std", feature = "alloc"))]
-impl<T> InPlaceWrite<T> for Box<MaybeUninit<T>> {
-    type Initialized = Box<T>;
+/// Drops the pinned value in place and re-initializes the memory with the given initializer.
+///
+/// If the initializer fails or panics, the memory is zeroed, so the value is still valid and can
+/// be used and dropped normally. Use [`set_pin_init_unchecked`] for types that do not implement
+/// [`Zeroable`].
+///
+/// # Examples
+///
+/// ```rust
+/// # #![feature(allocator_api)]
+/// # #[path = "../examples/error.rs"] mod error; use error::Error;
+/// # use pinned_init::*;
+/// let mut buf = Box::pin([1u8; 64]);
+/// set_pin_init::<_, Error>(buf.as_mut(), init_array_from_fn(|i| i as u8)).unwrap();
+/// assert_eq!(buf[63], 63);
+/// // SAFETY: The initializer fails without touching the slot.
+/// let fail = unsafe { pin_init_from_closure(|_| Err(Error)) };
+/// assert!(set_pin_init(buf.as_mut(), fail).is_err());
+/// assert_eq!(*buf, [0; 64]);
+/// ```
+pub fn set_pin_init<T: Zeroable, E>(slot: Pin<&mut T>, init: impl PinInit<T, E>) -> Result<(), E> {
+    /// Zeroes the slot when dropped.
+    struct ZeroGuard<T>(*mut T);
 
-    fn write_init<E>(mut self, init: impl Init<T, E>) -> Result<Self::Initialized, E> {
-        let slot = self.as_mut_ptr();
-        // SAFETY: When init errors/panics, slot will get deallocated but not dropped,
-        // slot is valid.
-        unsafe { init.__init(slot)? };
-        // SAFETY: All fields have been initialized.
-        Ok(unsafe { self.assume_init() })
+    impl<T> Drop for ZeroGuard<T> {
+        fn drop(&mut self) {
+            // SAFETY: The slot is valid for writes and zero is a valid bit pattern for `T`, since
+            // we only construct this guard for `T: Zeroable`.
+            unsafe { self.0.write_bytes(0, 1) };
+        }
     }
 
-    fn write_pin_init<E>(mut self, init: impl PinInit<T, E>) -> Result<Pin<Self::Initialized>, E> {
-        let slot = self.as_mut_ptr();
-        // SAFETY: When init errors/panics, slot will get deallocated but not dropped,
-        // slot is valid and will not be moved, because we pin it later.
-        unsafe { init.__pinned_init(slot)? };
-        // SAFETY: All fields have been initialized.
-        Ok(unsafe { self.assume_init() }.into())
-    }
+    // SAFETY: We do not move the value, it is dropped in place below.
+    let slot = unsafe { Pin::into_inner_unchecked(slot) } as *mut T;
+    // If dropping or initializing panics or initializing fails, the memory is zeroed.
+    let guard = ZeroGuard(slot);
+    // SAFETY: `slot` is valid and initialized.
+    unsafe { ptr::drop_in_place(slot) };
+    // SAFETY: The value has been dropped, so `slot` is uninitialized and it stays pinned, since we
+    // got it from a `Pin<&mut T>`. On failure, the guard zeroes the memory.
+    unsafe { init.__pinned_init(slot)? };
+    core::mem::forget(guard);
+    Ok(())
+}
+
+/// Drops the pinned value in place and re-initializes the memory with the given initializer.
+///
+/// See [`set_pin_init`] for the safe version for [`Zeroable`] types.
+///
+/// # Safety
+///
+/// If this function returns `Err` or panics, the value is dropped and the memory is
+/// uninitialized. The caller must ensure that the value is not used or dropped again in that case,
+/// for example by aborting or by deallocating the memory without dropping it.
+///
+/// # Examples
+///
+/// ```rust
+/// # #![feature(allocator_api)]
+/// # #[path = "../examples/mutex.rs"] mod mutex; use mutex::*;
+/// # use pinned_init::*;
+/// # use core::convert::Infallible;
+/// let mut mtx = Box::pin_init(CMutex::new(42)).unwrap();
+/// // SAFETY: The initializer cannot fail and `CMutex::new` does not panic.
+/// unsafe { set_pin_init_unchecked::<_, Infallible>(mtx.as_mut(), CMutex::new(0)) }.unwrap();
+/// assert_eq!(*mtx.lock(), 0);
+/// ```
+pub unsafe fn set_pin_init_unchecked<T, E>(
+    slot: Pin<&mut T>,
+    init: impl PinInit<T, E>,
+) -> Result<(), E> {
+    // SAFETY: We do not move the value, it is dropped in place below.
+    let slot = unsafe { Pin::into_inner_unchecked(slot) } as *mut T;
+    // SAFETY: `slot` is valid and initialized.
+    unsafe { ptr::drop_in_place(slot) };
+    // SAFETY: The value has been dropped, so `slot` is uninitialized and it stays pinned, since we
+    // got it from a `Pin<&mut T>`. The caller ensures that the value is not used on failure.
+    unsafe { init.__pinned_init(slot) }
 }
 
 /// Trait facilitating pinned destruction.
@@ -1340,6 +4716,23 @@ impl<T> InPlaceWrite<T> for Box<MaybeUninit<T>> {
 /// }
 /// ```
 ///
+/// If the type is [`Unpin`], the destructor can also take `&mut self`:
+///
+/// ```rust
+/// # use pinned_init::*;
+/// #[pin_data(PinnedDrop)]
+/// struct Bar {
+///     buf: Vec<u8>,
+/// }
+///
+/// #[pinned_drop]
+/// impl PinnedDrop for Bar {
+///     fn drop(&mut self) {
+///         self.buf.clear();
+///     }
+/// }
+/// ```
+///
 /// # Safety
 ///
 /// This trait must be implemented via the [`pinned_drop`] proc-macro attribute on the impl.
@@ -1365,23 +4758,192 @@ pub unsafe trait PinnedDrop: __internal::HasPinData {
 /// ```rust,ignore
 /// let val: Self = unsafe { core::mem::zeroed() };
 /// ```
+///
+/// # FFI types
+///
+/// The scalar types of [`core::ffi`] such as [`c_int`] are type aliases of primitives and thus
+/// implement this trait. [`c_void`] does not implement it, since it should only be used behind
+/// pointers, which are `Zeroable`. This makes it possible to derive `Zeroable` for most structs
+/// used in C APIs.
+///
+/// [`c_int`]: core::ffi::c_int
+/// [`c_void`]: core::ffi::c_void
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` cannot be zeroed",
+    label = "`{Self}` does not implement `Zeroable`",
+    note = "consider adding `#[derive(Zeroable)]` to `{Self}` if all of its fields are `Zeroable`"
+)]
 pub unsafe trait Zeroable {}
 
 /// Create a new zeroed T.
 ///
-/// The returned initializer will write `0x00` to every byte of the given `slot`.
+/// The returned initializer will write `0x00` to every byte of the given `slot`. It is `Copy`, so a
+/// single value can be used for multiple initializations.
+///
+/// # Examples
+///
+/// ```rust
+/// # use pinned_init::*;
+/// let zero = zeroed::<[u64; 4]>();
+/// let a: Box<[u64; 4]> = Box::init(zero).unwrap();
+/// let b: Box<[u64; 4]> = Box::init(zero).unwrap();
+/// assert_eq!(*a, *b);
+/// ```
+#[inline]
+pub fn zeroed<T: Zeroable>() -> impl Init<T> + Copy {
+    ZeroedInit(PhantomData)
+}
+
+/// The initializer returned by [`zeroed`].
+struct ZeroedInit<T>(__internal::Invariant<T>);
+
+impl<T> Clone for ZeroedInit<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for ZeroedInit<T> {}
+
+// SAFETY: Because `T: Zeroable`, all bytes zero is a valid bit pattern for `T` and because we
+// write all zeroes, the memory is initialized. This never fails.
+unsafe impl<T: Zeroable> Init<T> for ZeroedInit<T> {
+    unsafe fn __init(self, slot: *mut T) -> Result<(), Infallible> {
+        // SAFETY: The caller guarantees that `slot` is valid for writes.
+        unsafe { slot.write_bytes(0, 1) };
+        Ok(())
+    }
+}
+
+// SAFETY: `__pinned_init` behaves exactly the same as `__init`.
+unsafe impl<T: Zeroable> PinInit<T> for ZeroedInit<T> {
+    unsafe fn __pinned_init(self, slot: *mut T) -> Result<(), Infallible> {
+        // SAFETY: `__init` has less strict requirements compared to `__pinned_init`.
+        unsafe { self.__init(slot) }
+    }
+}
+
+/// Create a new zeroed slice of `T`.
+///
+/// The returned initializer will write `0x00` to every byte of the given `slot`, the length of the
+/// slice is taken from the metadata of the `slot` pointer.
+///
+/// # Examples
+///
+/// ```rust
+/// # use pinned_init::*;
+/// # use core::ptr;
+/// let mut buf = Box::<[u32]>::new_uninit_slice(1024);
+/// let slot = ptr::slice_from_raw_parts_mut(buf.as_mut_ptr().cast::<u32>(), buf.len());
+/// // SAFETY: `slot` is valid for writes and uninitialized.
+/// let Ok(()) = unsafe { zeroed_slice().__init(slot) };
+/// // SAFETY: The slice has been initialized above.
+/// let buf = unsafe { buf.assume_init() };
+/// assert!(buf.iter().all(|&x| x == 0));
+/// ```
 #[inline]
-pub fn zeroed<T: Zeroable>() -> impl Init<T> {
-    // SAFETY: Because `T: Zeroable`, all bytes zero is a valid bit pattern for `T`
-    // and because we write all zeroes, the memory is initialized.
+pub fn zeroed_slice<T: Zeroable>() -> impl Init<[T]> {
+    // SAFETY: Because `T: Zeroable`, all bytes zero is a valid bit pattern for every element of
+    // the slice and because we write zeroes to all `slot.len()` elements, the memory is
+    // initialized.
     unsafe {
-        init_from_closure(|slot: *mut T| {
-            slot.write_bytes(0, 1);
+        init_from_closure(|slot: *mut [T]| {
+            slot.cast::<T>().write_bytes(0, slot.len());
             Ok(())
         })
     }
 }
 
+/// An initializer that leaves the memory of a slice uninitialized.
+///
+/// This is the slice version of [`uninit`]. The `slot` memory is not changed.
+#[inline]
+pub fn uninit_slice<T, E>() -> impl Init<[MaybeUninit<T>], E> {
+    // SAFETY: The memory is allowed to be uninitialized.
+    unsafe { init_from_closure(|_| Ok(())) }
+}
+
+/// Create a new zeroed `T` and then apply the given patches.
+///
+/// Every patch `(offset, bytes)` copies `bytes` to the given byte `offset` inside of the slot. The
+/// patches are applied in order, so later patches overwrite earlier ones. This is useful for
+/// building command buffers that are passed to hardware or C code.
+///
+/// # Panics
+///
+/// Panics if a patch does not fit into `T`, the slot is then considered uninitialized.
+///
+/// # Safety
+///
+/// Applying the patches to a zeroed `T` must result in a valid bit pattern for `T`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use pinned_init::*;
+/// #[repr(C)]
+/// #[derive(Zeroable)]
+/// struct Command {
+///     opcode: u8,
+///     flags: u8,
+///     len: u16,
+/// }
+///
+/// let patches: &[(usize, &[u8])] = &[(0, &[0x12]), (2, &4u16.to_ne_bytes())];
+/// // SAFETY: All fields of `Command` are integers, so every bit pattern is valid.
+/// let cmd = Box::init(unsafe { zeroed_with_patches::<Command>(patches) }).unwrap();
+/// assert_eq!((cmd.opcode, cmd.flags, cmd.len), (0x12, 0, 4));
+/// ```
+#[inline]
+pub unsafe fn zeroed_with_patches<'a, T: Zeroable + 'a>(
+    patches: &'a [(usize, &'a [u8])],
+) -> impl Init<T> + 'a {
+    let init = move |slot: *mut T| {
+        // SAFETY: Because `T: Zeroable`, all bytes zero is a valid bit pattern for `T`.
+        unsafe { slot.write_bytes(0, 1) };
+        let base = slot.cast::<u8>();
+        for &(offset, bytes) in patches {
+            let in_bounds = offset
+                .checked_add(bytes.len())
+                .is_some_and(|end| end <= size_of::<T>());
+            assert!(
+                in_bounds,
+                "patch at offset {offset} with length {} does not fit into {} bytes",
+                bytes.len(),
+                size_of::<T>(),
+            );
+            // SAFETY: The patch is in bounds of `slot` as checked above and it cannot overlap
+            // with the slot, since `slot` is uninitialized.
+            unsafe { ptr::copy_nonoverlapping(bytes.as_ptr(), base.add(offset), bytes.len()) };
+        }
+        Ok(())
+    };
+    // SAFETY: The slot is zeroed and the caller guarantees that the patched bytes are a valid
+    // bit pattern for `T`. If a patch is out of bounds, we panic. The slot only contains copied
+    // bytes then, so it does not need to be dropped.
+    unsafe { init_from_closure(init) }
+}
+
+/// Create a new zeroed T by value.
+///
+/// In contrast to [`zeroed`], this function is `const` and can thus be used to initialize `static`
+/// and `const` items.
+///
+/// # Examples
+///
+/// ```rust
+/// # use pinned_init::*;
+/// static BUF: [u8; 4096] = zeroed_value();
+/// static COUNTERS: (usize, Option<core::num::NonZeroU32>) = zeroed_value();
+/// assert!(BUF.iter().all(|b| *b == 0));
+/// assert_eq!(COUNTERS, (0, None));
+/// ```
+#[inline]
+pub const fn zeroed_value<T: Zeroable>() -> T {
+    // SAFETY: Because `T: Zeroable`, all bytes zero is a valid bit pattern for `T`.
+    unsafe { MaybeUninit::zeroed().assume_init() }
+}
+
 macro_rules! impl_zeroable {
     ($($(#[$attr:meta])*$({$($generics:tt)*})? $t:ty, )*) => {
         $(
@@ -1394,6 +4956,9 @@ macro_rules! impl_zeroable {
 
 impl_zeroable! {
     // SAFETY: All primitives that are allowed to be zero.
+    //
+    // The scalar types of `core::ffi` (`c_int`, `c_char`, `c_ulong`, ...) are type aliases of these
+    // primitives, so they are covered as well.
     bool,
     char,
     u8, u16, u32, u64, u128, usize,
@@ -1411,6 +4976,9 @@ impl_zeroable! {
     {<T: ?Sized>} PhantomData<T>, core::marker::PhantomPinned, (),
 
     // SAFETY: Type is allowed to take any value, including all zeros.
+    //
+    // This also covers `MaybeUninit<[T; N]>` and, together with the array impl below,
+    // `[MaybeUninit<T>; N]` for every `T`.
     {<T>} MaybeUninit<T>,
 
     // SAFETY: `T: Zeroable` and `UnsafeCell` is `repr(transparent)`.
@@ -1436,6 +5004,9 @@ impl_zeroable! {
     //
     // When `Pointee` gets stabilized, we could use
     // `T: ?Sized where <T as Pointee>::Metadata: Zeroable`
+    //
+    // This also covers `*mut c_void`. Note that `core::ffi::c_void` itself is not `Zeroable`: it is
+    // an opaque type that is only meant to be used behind pointers, a value of it is never created.
     {<T>} *mut T, {<T>} *const T,
 
     // SAFETY: `null` pointer is valid and the metadata part of these fat pointers is allowed to be
@@ -1444,6 +5015,10 @@ impl_zeroable! {
 
     // SAFETY: `T` is `Zeroable`.
     {<const N: usize, T: Zeroable>} [T; N], {<T: Zeroable>} Wrapping<T>,
+
+    // SAFETY: `Range` consists of the two fields `start` and `end` of type `T`, which is
+    // `Zeroable`.
+    {<T: Zeroable>} core::ops::Range<T>,
 }
 
 macro_rules! impl_tuple_zeroable {
@@ -1456,3 +5031,186 @@ macro_rules! impl_tuple_zeroable {
 }
 
 impl_tuple_zeroable!(A, B, C, D, E, F, G, H, I, J);
+
+/// Marker trait for types whose [`Default`] value consists of only zeroes.
+///
+/// For these types [`zeroed_or_default`] can be used instead of [`Default::default`] to take the
+/// faster path of writing zeroes to the memory.
+///
+/// Use the derive macro `#[derive(ZeroableDefault)]` to implement this trait for structs. The derive
+/// macro cannot check that [`Default::default`] returns the zeroed value, consider comparing them in
+/// a test.
+///
+/// This trait is not `unsafe`, since all zeroes is a valid bit pattern of `Self` and thus an
+/// incorrect implementation will only result in a different, but valid value.
+pub trait ZeroableDefault: Zeroable + Default {}
+
+/// Create a new `T` that is equal to `T::default()` by zeroing it.
+///
+/// # Examples
+///
+/// ```rust
+/// # use pinned_init::*;
+/// let val: Box<(u64, Option<Box<u8>>, [u32; 32])> = Box::init(zeroed_or_default()).unwrap();
+/// assert_eq!(*val, Default::default());
+/// ```
+#[inline]
+pub fn zeroed_or_default<T: ZeroableDefault>() -> impl Init<T> {
+    zeroed()
+}
+
+macro_rules! impl_zeroable_default {
+    ($($(#[$attr:meta])*$({$($generics:tt)*})? $t:ty, )*) => {
+        $(
+            $(#[$attr])*
+            impl$($($generics)*)? ZeroableDefault for $t {}
+        )*
+    };
+}
+
+impl_zeroable_default! {
+    bool,
+    char,
+    u8, u16, u32, u64, u128, usize,
+    i8, i16, i32, i64, i128, isize,
+    f32, f64,
+
+    {<T: ?Sized>} PhantomData<T>, core::marker::PhantomPinned, (),
+
+    {<T: ZeroableDefault>} UnsafeCell<T>,
+
+    Option<NonZeroU8>, Option<NonZeroU16>, Option<NonZeroU32>, Option<NonZeroU64>,
+    Option<NonZeroU128>, Option<NonZeroUsize>,
+    Option<NonZeroI8>, Option<NonZeroI16>, Option<NonZeroI32>, Option<NonZeroI64>,
+    Option<NonZeroI128>, Option<NonZeroIsize>,
+
+    {<T: ?Sized>} Option<NonNull<T>>,
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    {<T: ?Sized>} Option<Box<T>>,
+
+    {<T: ZeroableDefault>} Wrapping<T>,
+}
+
+impl<const N: usize, T: ZeroableDefault> ZeroableDefault for [T; N] where [T; N]: Default {}
+
+macro_rules! impl_tuple_zeroable_default {
+    ($(,)?) => {};
+    ($first:ident, $($t:ident),* $(,)?) => {
+        impl<$first: ZeroableDefault, $($t: ZeroableDefault),*> ZeroableDefault
+            for ($first, $($t),*) {}
+        impl_tuple_zeroable_default!($($t),* ,);
+    }
+}
+
+impl_tuple_zeroable_default!(A, B, C, D, E, F, G, H, I, J);
+
+/// Marker trait for types that can be initialized by copying arbitrary bytes into them.
+///
+/// Use the derive macro `#[derive(FromBytes)]` to implement this trait for structs whose fields all
+/// implement it. This allows using [`from_bytes_init`] to initialize them from raw bytes, e.g. when
+/// parsing wire formats.
+///
+/// # Safety
+///
+/// Every bit pattern is a valid bit pattern for this type. In other words, this is not UB for any
+/// `bytes` of the correct length:
+///
+/// ```rust,ignore
+/// let val: Self = unsafe { core::ptr::read_unaligned(bytes.as_ptr().cast()) };
+/// ```
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` cannot be created from arbitrary bytes",
+    label = "`{Self}` does not implement `FromBytes`",
+    note = "consider adding `#[derive(FromBytes)]` to `{Self}` if all of its fields are `FromBytes`"
+)]
+pub unsafe trait FromBytes: Zeroable {}
+
+macro_rules! impl_from_bytes {
+    ($($(#[$attr:meta])*$({$($generics:tt)*})? $t:ty, )*) => {
+        $(
+            $(#[$attr])*
+            // SAFETY: Safety comments written in the macro invocation.
+            unsafe impl$($($generics)*)? FromBytes for $t {}
+        )*
+    };
+}
+
+impl_from_bytes! {
+    // SAFETY: All integer and floating point primitives can take any bit pattern.
+    u8, u16, u32, u64, u128, usize,
+    i8, i16, i32, i64, i128, isize,
+    f32, f64,
+
+    // SAFETY: These are inhabited ZSTs; there are no bytes to copy.
+    {<T: ?Sized>} PhantomData<T>, core::marker::PhantomPinned, (),
+
+    // SAFETY: Type is allowed to take any value.
+    {<T>} MaybeUninit<T>,
+
+    // SAFETY: The elements can take any bit pattern and there is no padding between them.
+    {<const N: usize, T: FromBytes>} [T; N],
+
+    // SAFETY: `Wrapping` is `repr(transparent)`.
+    {<T: FromBytes>} Wrapping<T>,
+}
+
+macro_rules! impl_tuple_from_bytes {
+    ($(,)?) => {};
+    ($first:ident, $($t:ident),* $(,)?) => {
+        // SAFETY: All elements can take any bit pattern and padding may hold any value.
+        unsafe impl<$first: FromBytes, $($t: FromBytes),*> FromBytes for ($first, $($t),*) {}
+        impl_tuple_from_bytes!($($t),* ,);
+    }
+}
+
+impl_tuple_from_bytes!(A, B, C, D, E, F, G, H, I, J);
+
+/// The error returned by [`from_bytes_init`] when the number of bytes does not match the size of the
+/// type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SizeError {
+    /// The size of the type that should have been initialized.
+    pub expected: usize,
+    /// The number of bytes that were given.
+    pub found: usize,
+}
+
+/// Initializes a `T` by copying `src` into the slot.
+///
+/// The initializer fails with [`SizeError`] if `src` does not contain exactly
+/// `size_of::<T>()` bytes. No alignment is required of `src`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use pinned_init::*;
+/// #[derive(Zeroable, FromBytes)]
+/// #[repr(C)]
+/// struct Header {
+///     magic: [u8; 4],
+///     len: u32,
+/// }
+///
+/// let wire = [&b"PINI"[..], &7u32.to_ne_bytes()].concat();
+/// stack_try_pin_init!(let header: Header = from_bytes_init(&wire));
+/// let header = header.unwrap();
+/// assert_eq!((&header.magic, header.len), (b"PINI", 7));
+/// ```
+pub fn from_bytes_init<'a, T: FromBytes + 'a>(src: &'a [u8]) -> impl Init<T, SizeError> + 'a {
+    let init = move |slot: *mut T| {
+        if src.len() != size_of::<T>() {
+            return Err(SizeError {
+                expected: size_of::<T>(),
+                found: src.len(),
+            });
+        }
+        // SAFETY: `src` is valid for reads of `size_of::<T>()` bytes and `slot` is valid for
+        // writes of a `T`. They do not overlap, since `slot` is exclusively owned by this
+        // initializer. `u8` has no alignment requirement.
+        unsafe { ptr::copy_nonoverlapping(src.as_ptr(), slot.cast::<u8>(), src.len()) };
+        Ok(())
+    };
+    // SAFETY: The closure initializes `slot` when it returns `Ok`, since every bit pattern is valid
+    // for `T`, and does not touch it otherwise.
+    unsafe { init_from_closure(init) }
+}
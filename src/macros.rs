@@ -249,9 +249,10 @@
 //!                     // Since initialization could fail later (not in this case, since the
 //!                     // error type is `Infallible`) we will need to drop this field if there
 //!                     // is an error later. This `DropGuard` will drop the field when it gets
-//!                     // dropped and has not yet been forgotten.
+//!                     // dropped and has not yet been forgotten. `new_field` returns `None` for
+//!                     // zero-sized types without drop glue, since they need no guard.
 //!                     let __t_guard = unsafe {
-//!                         ::pinned_init::__internal::DropGuard::new(::core::addr_of_mut!((*slot).t))
+//!                         ::pinned_init::__internal::DropGuard::new_field(::core::addr_of_mut!((*slot).t))
 //!                     };
 //!                     // Expansion of `x: 0,`:
 //!                     // Since this can be an arbitrary expression we cannot place it inside
@@ -262,7 +263,7 @@
 //!                     }
 //!                     // We again create a `DropGuard`.
 //!                     let __x_guard = unsafe {
-//!                         ::pinned_init::__internal::DropGuard::new(::core::addr_of_mut!((*slot).x))
+//!                         ::pinned_init::__internal::DropGuard::new_field(::core::addr_of_mut!((*slot).x))
 //!                     };
 //!                     // Since initialization has successfully completed, we can now forget
 //!                     // the guards. This is not `mem::forget`, since we only have
@@ -277,6 +278,9 @@
 //!                     // accessible from this scope.
 //!                     #[allow(unreachable_code, clippy::diverging_sub_expression)]
 //!                     let _ = || {
+//!                         // Generic parameters have to be unique, this points at both
+//!                         // occurrences of a field that is mentioned twice.
+//!                         fn __check_duplicate_fields<t, x>() {}
 //!                         unsafe {
 //!                             ::core::ptr::write(
 //!                                 slot,
@@ -462,17 +466,18 @@
 //!                 unsafe { ::core::ptr::write(::core::addr_of_mut!((*slot).a), a) };
 //!             }
 //!             let __a_guard = unsafe {
-//!                 ::pinned_init::__internal::DropGuard::new(::core::addr_of_mut!((*slot).a))
+//!                 ::pinned_init::__internal::DropGuard::new_field(::core::addr_of_mut!((*slot).a))
 //!             };
 //!             let init = Bar::new(36);
 //!             unsafe { data.b(::core::addr_of_mut!((*slot).b), b)? };
 //!             let __b_guard = unsafe {
-//!                 ::pinned_init::__internal::DropGuard::new(::core::addr_of_mut!((*slot).b))
+//!                 ::pinned_init::__internal::DropGuard::new_field(::core::addr_of_mut!((*slot).b))
 //!             };
 //!             ::core::mem::forget(__b_guard);
 //!             ::core::mem::forget(__a_guard);
 //!             #[allow(unreachable_code, clippy::diverging_sub_expression)]
 //!             let _ = || {
+//!                 fn __check_duplicate_fields<a, b>() {}
 //!                 unsafe {
 //!                     ::core::ptr::write(
 //!                         slot,
@@ -504,8 +509,44 @@
 #[doc(hidden)]
 #[macro_export]
 macro_rules! __pinned_drop {
+    // `Unpin` types are allowed to use `&mut self` as the receiver.
     (
         @impl_sig($($impl_sig:tt)*),
+        @impl_generics($($impl_generics:tt)*),
+        @impl_for($($impl_for:tt)*),
+        @impl_body(
+            $(#[$($attr:tt)*])*
+            fn drop(&mut $self:ident) {
+                $($inner:tt)*
+            }
+        ),
+    ) => {
+        // SAFETY: TODO.
+        unsafe $($impl_sig)* {
+            fn drop(
+                self: ::core::pin::Pin<&mut Self>,
+                _: $crate::__internal::OnlyCallFromDrop,
+            ) {
+                trait UnpinnedDrop {
+                    fn drop(&mut self);
+                }
+                impl $($impl_generics)* UnpinnedDrop $($impl_for)* {
+                    // Inherit all attributes and the `self` token for the signature.
+                    $(#[$($attr)*])*
+                    fn drop(&mut $self) {
+                        $($inner)*
+                    }
+                }
+                // `Pin::into_inner` requires `Self: Unpin`, so this only compiles for `Unpin`
+                // types.
+                UnpinnedDrop::drop(::core::pin::Pin::into_inner(self))
+            }
+        }
+    };
+    (
+        @impl_sig($($impl_sig:tt)*),
+        @impl_generics($($impl_generics:tt)*),
+        @impl_for($($impl_for:tt)*),
         @impl_body(
             $(#[$($attr:tt)*])*
             fn drop($($sig:tt)*) {
@@ -913,213 +954,1270 @@ macro_rules! __pin_data {
             );
         };
     };
-    // When no `PinnedDrop` was specified, then we have to prevent implementing drop.
-    (drop_prevention:
-        @name($name:ident),
+    // Proc-macro entry point for enums, this is supplied by the proc-macro pre-parsing.
+    (parse_input:
+        @args($($pinned_drop:ident)?),
+        @sig(
+            $(#[$($enum_attr:tt)*])*
+            $vis:vis enum $name:ident
+            $(where $($whr:tt)*)?
+        ),
         @impl_generics($($impl_generics:tt)*),
         @ty_generics($($ty_generics:tt)*),
-        @where($($whr:tt)*),
-        @pinned_drop(),
+        @decl_generics($($decl_generics:tt)*),
+        @body({ $($variants:tt)* }),
     ) => {
-        // We prevent this by creating a trait that will be implemented for all types implementing
-        // `Drop`. Additionally we will implement this trait for the struct leading to a conflict,
-        // if it also implements `Drop`
-        trait MustNotImplDrop {}
-        #[expect(drop_bounds)]
-        impl<T: ::core::ops::Drop> MustNotImplDrop for T {}
-        impl<$($impl_generics)*> MustNotImplDrop for $name<$($ty_generics)*>
-        where $($whr)* {}
-        // We also take care to prevent users from writing a useless `PinnedDrop` implementation.
-        // They might implement `PinnedDrop` correctly for the struct, but forget to give
-        // `PinnedDrop` as the parameter to `#[pin_data]`.
-        #[expect(non_camel_case_types)]
-        trait UselessPinnedDropImpl_you_need_to_specify_PinnedDrop {}
-        impl<T: $crate::PinnedDrop>
-            UselessPinnedDropImpl_you_need_to_specify_PinnedDrop for T {}
-        impl<$($impl_generics)*>
-            UselessPinnedDropImpl_you_need_to_specify_PinnedDrop for $name<$($ty_generics)*>
-        where $($whr)* {}
+        // We use token munching to iterate through all of the variants and then through the fields
+        // of each variant. This works similar to `find_pinned_fields`, but since the variants do
+        // not get projection functions, only the types of the pinned fields are collected for the
+        // unpin analysis.
+        $crate::__pin_data!(find_pinned_variants:
+            // Attributes on the enum itself, these will just be propagated to be put onto the enum
+            // definition.
+            @enum_attrs($(#[$($enum_attr)*])*),
+            @vis($vis),
+            @name($name),
+            @impl_generics($($impl_generics)*),
+            @ty_generics($($ty_generics)*),
+            @decl_generics($($decl_generics)*),
+            @where($($($whr)*)?),
+            // The remaining variant tokens that need to be processed.
+            // We add a `,` at the end to ensure correct parsing.
+            @variants_munch($($variants)* ,),
+            // The types of the pinned fields of all variants.
+            @pinned(),
+            // All variants, with the `#[pin]` attributes removed.
+            @variants(),
+            @pinned_drop($($pinned_drop)?),
+        );
     };
-    // When `PinnedDrop` was specified we just implement `Drop` and delegate.
-    (drop_prevention:
+    (find_pinned_variants:
+        @enum_attrs($($enum_attrs:tt)*),
+        @vis($vis:vis),
         @name($name:ident),
         @impl_generics($($impl_generics:tt)*),
         @ty_generics($($ty_generics:tt)*),
+        @decl_generics($($decl_generics:tt)*),
         @where($($whr:tt)*),
-        @pinned_drop(PinnedDrop),
+        // We reached the end of the variants, plus an optional additional comma, since we added
+        // one before and the user is also allowed to put a trailing comma.
+        @variants_munch($(,)?),
+        @pinned($($pinned:tt)*),
+        @variants($($variants:tt)*),
+        @pinned_drop($($pinned_drop:ident)?),
     ) => {
-        impl<$($impl_generics)*> ::core::ops::Drop for $name<$($ty_generics)*>
+        // Declare the enum with all variants in the correct order.
+        $($enum_attrs)*
+        $vis enum $name <$($decl_generics)*>
         where $($whr)*
         {
-            fn drop(&mut self) {
-                // SAFETY: Since this is a destructor, `self` will not move after this function
-                // terminates, since it is inaccessible.
-                let pinned = unsafe { ::core::pin::Pin::new_unchecked(self) };
-                // SAFETY: Since this is a drop function, we can create this token to call the
-                // pinned destructor of this type.
-                let token = unsafe { $crate::__internal::OnlyCallFromDrop::new() };
-                $crate::PinnedDrop::drop(pinned, token);
-            }
+            $($variants)*
         }
+
+        // We put the rest into this const item, because it then will not be accessible to anything
+        // outside.
+        const _: () = {
+            // Enums do not have any projection functions, but `PinnedDrop` requires the type to
+            // implement `HasPinData`.
+            $vis struct __ThePinData<$($impl_generics)*>
+            where $($whr)*
+            {
+                __phantom: ::core::marker::PhantomData<
+                    fn($name<$($ty_generics)*>) -> $name<$($ty_generics)*>
+                >,
+            }
+
+            impl<$($impl_generics)*> ::core::clone::Clone for __ThePinData<$($ty_generics)*>
+            where $($whr)*
+            {
+                fn clone(&self) -> Self { *self }
+            }
+
+            impl<$($impl_generics)*> ::core::marker::Copy for __ThePinData<$($ty_generics)*>
+            where $($whr)*
+            {}
+
+            // SAFETY: `__ThePinData` has no projection functions, so it cannot be used to
+            // initialize any field.
+            unsafe impl<$($impl_generics)*>
+                $crate::__internal::HasPinData for $name<$($ty_generics)*>
+            where $($whr)*
+            {
+                type PinData = __ThePinData<$($ty_generics)*>;
+
+                unsafe fn __pin_data() -> Self::PinData {
+                    __ThePinData { __phantom: ::core::marker::PhantomData }
+                }
+            }
+
+            // SAFETY: `Datee` is the enum that `__ThePinData` has been created for, which also names
+            // `__ThePinData` as its `HasPinData::PinData` above. `__ThePinData` has no projection
+            // functions, so it cannot be used to initialize or access the fields of any variant,
+            // pinned or not.
+            unsafe impl<$($impl_generics)*>
+                $crate::__internal::PinData for __ThePinData<$($ty_generics)*>
+            where $($whr)*
+            {
+                type Datee = $name<$($ty_generics)*>;
+            }
+
+            // This struct will be used for the unpin analysis. The pinned fields of all variants
+            // are relevant, so their types are collected into a tuple struct, since fields of
+            // different variants might have the same name.
+            #[allow(dead_code)]
+            struct __Unpin <'__pin, $($impl_generics)*> (
+                ::core::marker::PhantomData<fn(&'__pin ()) -> &'__pin ()>,
+                ::core::marker::PhantomData<
+                    fn($name<$($ty_generics)*>) -> $name<$($ty_generics)*>
+                >,
+                // Only the pinned fields.
+                $($pinned)*
+            )
+            where $($whr)*;
+
+            #[doc(hidden)]
+            impl<'__pin, $($impl_generics)*> ::core::marker::Unpin for $name<$($ty_generics)*>
+            where
+                __Unpin<'__pin, $($ty_generics)*>: ::core::marker::Unpin,
+                $($whr)*
+            {}
+
+            // We need to disallow normal `Drop` implementation, the exact behavior depends on
+            // whether `PinnedDrop` was specified as the parameter.
+            $crate::__pin_data!(drop_prevention:
+                @name($name),
+                @impl_generics($($impl_generics)*),
+                @ty_generics($($ty_generics)*),
+                @where($($whr)*),
+                @pinned_drop($($pinned_drop)?),
+            );
+        };
     };
-    // If some other parameter was specified, we emit a readable error.
-    (drop_prevention:
+    (find_pinned_variants:
+        @enum_attrs($($enum_attrs:tt)*),
+        @vis($vis:vis),
         @name($name:ident),
         @impl_generics($($impl_generics:tt)*),
         @ty_generics($($ty_generics:tt)*),
+        @decl_generics($($decl_generics:tt)*),
         @where($($whr:tt)*),
-        @pinned_drop($($rest:tt)*),
+        // A variant with named fields.
+        @variants_munch(
+            $(#[$($variant_attr:tt)*])* $variant:ident { $($fields:tt)* } $(= $disc:expr)?,
+            $($rest:tt)*
+        ),
+        @pinned($($pinned:tt)*),
+        @variants($($variants:tt)*),
+        @pinned_drop($($pinned_drop:ident)?),
     ) => {
-        compile_error!(
-            "Wrong parameters to `#[pin_data]`, expected nothing or `PinnedDrop`, got '{}'.",
-            stringify!($($rest)*),
+        $crate::__pin_data!(find_pinned_variant_fields:
+            @enum_attrs($($enum_attrs)*),
+            @vis($vis),
+            @name($name),
+            @impl_generics($($impl_generics)*),
+            @ty_generics($($ty_generics)*),
+            @decl_generics($($decl_generics)*),
+            @where($($whr)*),
+            @variants_munch($($rest)*),
+            @pinned($($pinned)*),
+            @variants($($variants)*),
+            @pinned_drop($($pinned_drop)?),
+            // The attributes and name of the current variant.
+            @variant($(#[$($variant_attr)*])* $variant),
+            // Either `named` or `tuple`, depending on how the fields are declared.
+            @kind(named),
+            // The discriminant of the current variant.
+            @disc($(= $disc)?),
+            // The remaining field tokens of the current variant.
+            @fields_munch($($fields)* ,),
+            // All fields of the current variant.
+            @fields(),
+            @accum(),
+            @is_pinned(),
         );
     };
-    (make_pin_data:
-        @pin_data($pin_data:ident),
+    (find_pinned_variants:
+        @enum_attrs($($enum_attrs:tt)*),
+        @vis($vis:vis),
+        @name($name:ident),
         @impl_generics($($impl_generics:tt)*),
         @ty_generics($($ty_generics:tt)*),
+        @decl_generics($($decl_generics:tt)*),
         @where($($whr:tt)*),
-        @pinned($($(#[$($p_attr:tt)*])* $pvis:vis $p_field:ident : $p_type:ty),* $(,)?),
-        @not_pinned($($(#[$($attr:tt)*])* $fvis:vis $field:ident : $type:ty),* $(,)?),
+        // A tuple variant.
+        @variants_munch(
+            $(#[$($variant_attr:tt)*])* $variant:ident ( $($fields:tt)* ) $(= $disc:expr)?,
+            $($rest:tt)*
+        ),
+        @pinned($($pinned:tt)*),
+        @variants($($variants:tt)*),
+        @pinned_drop($($pinned_drop:ident)?),
     ) => {
-        // For every field, we create a projection function according to its projection type. If a
-        // field is structurally pinned, then it must be initialized via `PinInit`, if it is not
-        // structurally pinned, then it can be initialized via `Init`.
-        //
-        // The functions are `unsafe` to prevent accidentally calling them.
-        #[allow(dead_code)]
-        #[expect(clippy::missing_safety_doc)]
-        impl<$($impl_generics)*> $pin_data<$($ty_generics)*>
-        where $($whr)*
-        {
-            $(
-                $pvis unsafe fn $p_field<E>(
-                    self,
-                    slot: *mut $p_type,
-                    init: impl $crate::PinInit<$p_type, E>,
-                ) -> ::core::result::Result<(), E> {
-                    // SAFETY: TODO.
-                    unsafe { $crate::PinInit::__pinned_init(init, slot) }
-                }
-            )*
-            $(
-                $fvis unsafe fn $field<E>(
-                    self,
-                    slot: *mut $type,
-                    init: impl $crate::Init<$type, E>,
-                ) -> ::core::result::Result<(), E> {
-                    // SAFETY: TODO.
-                    unsafe { $crate::Init::__init(init, slot) }
-                }
-            )*
-        }
+        $crate::__pin_data!(find_pinned_variant_fields:
+            @enum_attrs($($enum_attrs)*),
+            @vis($vis),
+            @name($name),
+            @impl_generics($($impl_generics)*),
+            @ty_generics($($ty_generics)*),
+            @decl_generics($($decl_generics)*),
+            @where($($whr)*),
+            @variants_munch($($rest)*),
+            @pinned($($pinned)*),
+            @variants($($variants)*),
+            @pinned_drop($($pinned_drop)?),
+            // The attributes and name of the current variant.
+            @variant($(#[$($variant_attr)*])* $variant),
+            // Either `named` or `tuple`, depending on how the fields are declared.
+            @kind(tuple),
+            // The discriminant of the current variant.
+            @disc($(= $disc)?),
+            // The remaining field tokens of the current variant.
+            @fields_munch($($fields)* ,),
+            // All fields of the current variant.
+            @fields(),
+            @accum(),
+            @is_pinned(),
+        );
     };
-}
-
-pub use paste::paste;
-
-/// The internal init macro. Do not call manually!
-///
-/// This is called by the `{try_}{pin_}init!` macros with various inputs.
-///
-/// This macro has multiple internal call configurations, these are always the very first ident:
-/// - nothing: this is the base case and called by the `{try_}{pin_}init!` macros.
-/// - `with_update_parsed`: when the `..Zeroable::zeroed()` syntax has been handled.
-/// - `init_slot`: recursively creates the code that initializes all fields in `slot`.
-/// - `make_initializer`: recursively create the struct initializer that guarantees that every
-///   field has been initialized exactly once.
-#[doc(hidden)]
-#[macro_export]
-macro_rules! __init_internal {
-    (
-        @this($($this:ident)?),
-        @typ($t:path),
-        @fields($($fields:tt)*),
-        @error($err:ty),
-        // Either `PinData` or `InitData`, `$use_data` should only be present in the `PinData`
-        // case.
-        @data($data:ident, $($use_data:ident)?),
-        // `HasPinData` or `HasInitData`.
-        @has_data($has_data:ident, $get_data:ident),
-        // `pin_init_from_closure` or `init_from_closure`.
-        @construct_closure($construct_closure:ident),
-        @munch_fields(),
+    (find_pinned_variants:
+        @enum_attrs($($enum_attrs:tt)*),
+        @vis($vis:vis),
+        @name($name:ident),
+        @impl_generics($($impl_generics:tt)*),
+        @ty_generics($($ty_generics:tt)*),
+        @decl_generics($($decl_generics:tt)*),
+        @where($($whr:tt)*),
+        // A unit variant, it does not have any fields.
+        @variants_munch(
+            $(#[$($variant_attr:tt)*])* $variant:ident $(= $disc:expr)?,
+            $($rest:tt)*
+        ),
+        @pinned($($pinned:tt)*),
+        @variants($($variants:tt)*),
+        @pinned_drop($($pinned_drop:ident)?),
     ) => {
-        $crate::__init_internal!(with_update_parsed:
-            @this($($this)?),
-            @typ($t),
-            @fields($($fields)*),
-            @error($err),
-            @data($data, $($use_data)?),
-            @has_data($has_data, $get_data),
-            @construct_closure($construct_closure),
-            @zeroed(), // Nothing means default behavior.
-        )
+        $crate::__pin_data!(find_pinned_variants:
+            @enum_attrs($($enum_attrs)*),
+            @vis($vis),
+            @name($name),
+            @impl_generics($($impl_generics)*),
+            @ty_generics($($ty_generics)*),
+            @decl_generics($($decl_generics)*),
+            @where($($whr)*),
+            @variants_munch($($rest)*),
+            @pinned($($pinned)*),
+            @variants($($variants)* $(#[$($variant_attr)*])* $variant $(= $disc)?,),
+            @pinned_drop($($pinned_drop)?),
+        );
     };
-    (
-        @this($($this:ident)?),
-        @typ($t:path),
+    (find_pinned_variant_fields:
+        @enum_attrs($($enum_attrs:tt)*),
+        @vis($vis:vis),
+        @name($name:ident),
+        @impl_generics($($impl_generics:tt)*),
+        @ty_generics($($ty_generics:tt)*),
+        @decl_generics($($decl_generics:tt)*),
+        @where($($whr:tt)*),
+        @variants_munch($($variants_rest:tt)*),
+        @pinned($($pinned:tt)*),
+        @variants($($variants:tt)*),
+        @pinned_drop($($pinned_drop:ident)?),
+        @variant($($variant:tt)*),
+        @kind(named),
+        @disc($($disc:tt)*),
+        // We reached the end of the fields of this variant.
+        @fields_munch($(,)?),
+        @fields($($fields:tt)*),
+        @accum(),
+        @is_pinned(),
+    ) => {
+        $crate::__pin_data!(find_pinned_variants:
+            @enum_attrs($($enum_attrs)*),
+            @vis($vis),
+            @name($name),
+            @impl_generics($($impl_generics)*),
+            @ty_generics($($ty_generics)*),
+            @decl_generics($($decl_generics)*),
+            @where($($whr)*),
+            @variants_munch($($variants_rest)*),
+            @pinned($($pinned)*),
+            @variants($($variants)* $($variant)* { $($fields)* } $($disc)*,),
+            @pinned_drop($($pinned_drop)?),
+        );
+    };
+    (find_pinned_variant_fields:
+        @enum_attrs($($enum_attrs:tt)*),
+        @vis($vis:vis),
+        @name($name:ident),
+        @impl_generics($($impl_generics:tt)*),
+        @ty_generics($($ty_generics:tt)*),
+        @decl_generics($($decl_generics:tt)*),
+        @where($($whr:tt)*),
+        @variants_munch($($variants_rest:tt)*),
+        @pinned($($pinned:tt)*),
+        @variants($($variants:tt)*),
+        @pinned_drop($($pinned_drop:ident)?),
+        @variant($($variant:tt)*),
+        @kind(tuple),
+        @disc($($disc:tt)*),
+        // We reached the end of the fields of this variant.
+        @fields_munch($(,)?),
+        @fields($($fields:tt)*),
+        @accum(),
+        @is_pinned(),
+    ) => {
+        $crate::__pin_data!(find_pinned_variants:
+            @enum_attrs($($enum_attrs)*),
+            @vis($vis),
+            @name($name),
+            @impl_generics($($impl_generics)*),
+            @ty_generics($($ty_generics)*),
+            @decl_generics($($decl_generics)*),
+            @where($($whr)*),
+            @variants_munch($($variants_rest)*),
+            @pinned($($pinned)*),
+            @variants($($variants)* $($variant)* ( $($fields)* ) $($disc)*,),
+            @pinned_drop($($pinned_drop)?),
+        );
+    };
+    (find_pinned_variant_fields:
+        @enum_attrs($($enum_attrs:tt)*),
+        @vis($vis:vis),
+        @name($name:ident),
+        @impl_generics($($impl_generics:tt)*),
+        @ty_generics($($ty_generics:tt)*),
+        @decl_generics($($decl_generics:tt)*),
+        @where($($whr:tt)*),
+        @variants_munch($($variants_rest:tt)*),
+        @pinned($($pinned:tt)*),
+        @variants($($variants:tt)*),
+        @pinned_drop($($pinned_drop:ident)?),
+        @variant($($variant:tt)*),
+        @kind($kind:ident),
+        @disc($($disc:tt)*),
+        // We found the `#[pin]` attr.
+        @fields_munch(#[pin] $($rest:tt)*),
+        @fields($($fields:tt)*),
+        @accum($($accum:tt)*),
+        @is_pinned($($is_pinned:ident)?),
+    ) => {
+        $crate::__pin_data!(find_pinned_variant_fields:
+            @enum_attrs($($enum_attrs)*),
+            @vis($vis),
+            @name($name),
+            @impl_generics($($impl_generics)*),
+            @ty_generics($($ty_generics)*),
+            @decl_generics($($decl_generics)*),
+            @where($($whr)*),
+            @variants_munch($($variants_rest)*),
+            @pinned($($pinned)*),
+            @variants($($variants)*),
+            @pinned_drop($($pinned_drop)?),
+            @variant($($variant)*),
+            @kind($kind),
+            @disc($($disc)*),
+            @fields_munch($($rest)*),
+            @fields($($fields)*),
+            @accum($($accum)*),
+            @is_pinned(yes),
+        );
+    };
+    (find_pinned_variant_fields:
+        @enum_attrs($($enum_attrs:tt)*),
+        @vis($vis:vis),
+        @name($name:ident),
+        @impl_generics($($impl_generics:tt)*),
+        @ty_generics($($ty_generics:tt)*),
+        @decl_generics($($decl_generics:tt)*),
+        @where($($whr:tt)*),
+        @variants_munch($($variants_rest:tt)*),
+        @pinned($($pinned:tt)*),
+        @variants($($variants:tt)*),
+        @pinned_drop($($pinned_drop:ident)?),
+        @variant($($variant:tt)*),
+        @kind($kind:ident),
+        @disc($($disc:tt)*),
+        // Some other attribute, just put it into `$accum`.
+        @fields_munch(#[$($attr:tt)*] $($rest:tt)*),
+        @fields($($fields:tt)*),
+        @accum($($accum:tt)*),
+        @is_pinned($($is_pinned:ident)?),
+    ) => {
+        $crate::__pin_data!(find_pinned_variant_fields:
+            @enum_attrs($($enum_attrs)*),
+            @vis($vis),
+            @name($name),
+            @impl_generics($($impl_generics)*),
+            @ty_generics($($ty_generics)*),
+            @decl_generics($($decl_generics)*),
+            @where($($whr)*),
+            @variants_munch($($variants_rest)*),
+            @pinned($($pinned)*),
+            @variants($($variants)*),
+            @pinned_drop($($pinned_drop)?),
+            @variant($($variant)*),
+            @kind($kind),
+            @disc($($disc)*),
+            @fields_munch($($rest)*),
+            @fields($($fields)*),
+            @accum($($accum)* #[$($attr)*]),
+            @is_pinned($($is_pinned)?),
+        );
+    };
+    (find_pinned_variant_fields:
+        @enum_attrs($($enum_attrs:tt)*),
+        @vis($vis:vis),
+        @name($name:ident),
+        @impl_generics($($impl_generics:tt)*),
+        @ty_generics($($ty_generics:tt)*),
+        @decl_generics($($decl_generics:tt)*),
+        @where($($whr:tt)*),
+        @variants_munch($($variants_rest:tt)*),
+        @pinned($($pinned:tt)*),
+        @variants($($variants:tt)*),
+        @pinned_drop($($pinned_drop:ident)?),
+        @variant($($variant:tt)*),
+        @kind(named),
+        @disc($($disc:tt)*),
+        // We reached the field declaration.
+        @fields_munch($field:ident : $type:ty, $($rest:tt)*),
+        @fields($($fields:tt)*),
+        @accum($($accum:tt)*),
+        // This field is pinned.
+        @is_pinned(yes),
+    ) => {
+        $crate::__pin_data!(find_pinned_variant_fields:
+            @enum_attrs($($enum_attrs)*),
+            @vis($vis),
+            @name($name),
+            @impl_generics($($impl_generics)*),
+            @ty_generics($($ty_generics)*),
+            @decl_generics($($decl_generics)*),
+            @where($($whr)*),
+            @variants_munch($($variants_rest)*),
+            @pinned($($pinned)* $($accum)* $type,),
+            @variants($($variants)*),
+            @pinned_drop($($pinned_drop)?),
+            @variant($($variant)*),
+            @kind(named),
+            @disc($($disc)*),
+            @fields_munch($($rest)*),
+            @fields($($fields)* $($accum)* $field: $type,),
+            @accum(),
+            @is_pinned(),
+        );
+    };
+    (find_pinned_variant_fields:
+        @enum_attrs($($enum_attrs:tt)*),
+        @vis($vis:vis),
+        @name($name:ident),
+        @impl_generics($($impl_generics:tt)*),
+        @ty_generics($($ty_generics:tt)*),
+        @decl_generics($($decl_generics:tt)*),
+        @where($($whr:tt)*),
+        @variants_munch($($variants_rest:tt)*),
+        @pinned($($pinned:tt)*),
+        @variants($($variants:tt)*),
+        @pinned_drop($($pinned_drop:ident)?),
+        @variant($($variant:tt)*),
+        @kind(named),
+        @disc($($disc:tt)*),
+        // We reached the field declaration.
+        @fields_munch($field:ident : $type:ty, $($rest:tt)*),
+        @fields($($fields:tt)*),
+        @accum($($accum:tt)*),
+        // This field is not pinned.
+        @is_pinned(),
+    ) => {
+        $crate::__pin_data!(find_pinned_variant_fields:
+            @enum_attrs($($enum_attrs)*),
+            @vis($vis),
+            @name($name),
+            @impl_generics($($impl_generics)*),
+            @ty_generics($($ty_generics)*),
+            @decl_generics($($decl_generics)*),
+            @where($($whr)*),
+            @variants_munch($($variants_rest)*),
+            @pinned($($pinned)*),
+            @variants($($variants)*),
+            @pinned_drop($($pinned_drop)?),
+            @variant($($variant)*),
+            @kind(named),
+            @disc($($disc)*),
+            @fields_munch($($rest)*),
+            @fields($($fields)* $($accum)* $field: $type,),
+            @accum(),
+            @is_pinned(),
+        );
+    };
+    (find_pinned_variant_fields:
+        @enum_attrs($($enum_attrs:tt)*),
+        @vis($vis:vis),
+        @name($name:ident),
+        @impl_generics($($impl_generics:tt)*),
+        @ty_generics($($ty_generics:tt)*),
+        @decl_generics($($decl_generics:tt)*),
+        @where($($whr:tt)*),
+        @variants_munch($($variants_rest:tt)*),
+        @pinned($($pinned:tt)*),
+        @variants($($variants:tt)*),
+        @pinned_drop($($pinned_drop:ident)?),
+        @variant($($variant:tt)*),
+        @kind(tuple),
+        @disc($($disc:tt)*),
+        // We reached the field declaration.
+        @fields_munch($type:ty, $($rest:tt)*),
+        @fields($($fields:tt)*),
+        @accum($($accum:tt)*),
+        // This field is pinned.
+        @is_pinned(yes),
+    ) => {
+        $crate::__pin_data!(find_pinned_variant_fields:
+            @enum_attrs($($enum_attrs)*),
+            @vis($vis),
+            @name($name),
+            @impl_generics($($impl_generics)*),
+            @ty_generics($($ty_generics)*),
+            @decl_generics($($decl_generics)*),
+            @where($($whr)*),
+            @variants_munch($($variants_rest)*),
+            @pinned($($pinned)* $($accum)* $type,),
+            @variants($($variants)*),
+            @pinned_drop($($pinned_drop)?),
+            @variant($($variant)*),
+            @kind(tuple),
+            @disc($($disc)*),
+            @fields_munch($($rest)*),
+            @fields($($fields)* $($accum)* $type,),
+            @accum(),
+            @is_pinned(),
+        );
+    };
+    (find_pinned_variant_fields:
+        @enum_attrs($($enum_attrs:tt)*),
+        @vis($vis:vis),
+        @name($name:ident),
+        @impl_generics($($impl_generics:tt)*),
+        @ty_generics($($ty_generics:tt)*),
+        @decl_generics($($decl_generics:tt)*),
+        @where($($whr:tt)*),
+        @variants_munch($($variants_rest:tt)*),
+        @pinned($($pinned:tt)*),
+        @variants($($variants:tt)*),
+        @pinned_drop($($pinned_drop:ident)?),
+        @variant($($variant:tt)*),
+        @kind(tuple),
+        @disc($($disc:tt)*),
+        // We reached the field declaration.
+        @fields_munch($type:ty, $($rest:tt)*),
+        @fields($($fields:tt)*),
+        @accum($($accum:tt)*),
+        // This field is not pinned.
+        @is_pinned(),
+    ) => {
+        $crate::__pin_data!(find_pinned_variant_fields:
+            @enum_attrs($($enum_attrs)*),
+            @vis($vis),
+            @name($name),
+            @impl_generics($($impl_generics)*),
+            @ty_generics($($ty_generics)*),
+            @decl_generics($($decl_generics)*),
+            @where($($whr)*),
+            @variants_munch($($variants_rest)*),
+            @pinned($($pinned)*),
+            @variants($($variants)*),
+            @pinned_drop($($pinned_drop)?),
+            @variant($($variant)*),
+            @kind(tuple),
+            @disc($($disc)*),
+            @fields_munch($($rest)*),
+            @fields($($fields)* $($accum)* $type,),
+            @accum(),
+            @is_pinned(),
+        );
+    };
+    // When no `PinnedDrop` was specified, then we have to prevent implementing drop.
+    (drop_prevention:
+        @name($name:ident),
+        @impl_generics($($impl_generics:tt)*),
+        @ty_generics($($ty_generics:tt)*),
+        @where($($whr:tt)*),
+        @pinned_drop(),
+    ) => {
+        // We prevent this by creating a trait that will be implemented for all types implementing
+        // `Drop`. Additionally we will implement this trait for the struct leading to a conflict,
+        // if it also implements `Drop`
+        trait MustNotImplDrop {}
+        #[expect(drop_bounds)]
+        impl<T: ::core::ops::Drop> MustNotImplDrop for T {}
+        impl<$($impl_generics)*> MustNotImplDrop for $name<$($ty_generics)*>
+        where $($whr)* {}
+        // We also take care to prevent users from writing a useless `PinnedDrop` implementation.
+        // They might implement `PinnedDrop` correctly for the struct, but forget to give
+        // `PinnedDrop` as the parameter to `#[pin_data]`.
+        #[expect(non_camel_case_types)]
+        trait UselessPinnedDropImpl_you_need_to_specify_PinnedDrop {}
+        impl<T: $crate::PinnedDrop>
+            UselessPinnedDropImpl_you_need_to_specify_PinnedDrop for T {}
+        impl<$($impl_generics)*>
+            UselessPinnedDropImpl_you_need_to_specify_PinnedDrop for $name<$($ty_generics)*>
+        where $($whr)* {}
+    };
+    // When `PinnedDrop` was specified we just implement `Drop` and delegate.
+    (drop_prevention:
+        @name($name:ident),
+        @impl_generics($($impl_generics:tt)*),
+        @ty_generics($($ty_generics:tt)*),
+        @where($($whr:tt)*),
+        @pinned_drop(PinnedDrop),
+    ) => {
+        impl<$($impl_generics)*> ::core::ops::Drop for $name<$($ty_generics)*>
+        where $($whr)*
+        {
+            fn drop(&mut self) {
+                // SAFETY: Since this is a destructor, `self` will not move after this function
+                // terminates, since it is inaccessible.
+                let pinned = unsafe { ::core::pin::Pin::new_unchecked(self) };
+                // SAFETY: Since this is a drop function, we can create this token to call the
+                // pinned destructor of this type.
+                let token = unsafe { $crate::__internal::OnlyCallFromDrop::new() };
+                $crate::PinnedDrop::drop(pinned, token);
+            }
+        }
+    };
+    // If some other parameter was specified, we emit a readable error.
+    (drop_prevention:
+        @name($name:ident),
+        @impl_generics($($impl_generics:tt)*),
+        @ty_generics($($ty_generics:tt)*),
+        @where($($whr:tt)*),
+        @pinned_drop($($rest:tt)*),
+    ) => {
+        compile_error!(
+            "Wrong parameters to `#[pin_data]`, expected nothing, `PinnedDrop`, `Zeroable` or \
+                `Default`, got '{}'.",
+            stringify!($($rest)*),
+        );
+    };
+    // When `Default` was specified, we implement `Default` by zeroing the struct.
+    (zeroed_default:
+        @sig(
+            $(#[$($struct_attr:tt)*])*
+            $vis:vis struct $name:ident
+            $(where $($whr:tt)*)?
+        ),
+        @impl_generics($($impl_generics:tt)*),
+        @ty_generics($($ty_generics:tt)*),
+    ) => {
+        // The `Zeroable` bound ensures that all fields are `Zeroable`, since it can only be
+        // implemented via `#[derive(Zeroable)]` (or `#[pin_data(Zeroable)]`) or manually and
+        // unsafely.
+        impl<$($impl_generics)*> ::core::default::Default for $name<$($ty_generics)*>
+        where
+            $name<$($ty_generics)*>: $crate::Zeroable,
+            $($($whr)*)?
+        {
+            fn default() -> Self {
+                $crate::zeroed_value()
+            }
+        }
+    };
+    // Generates the `new_pin_init` constructor requested via `#[pin_data(constructor)]`.
+    (constructor:
+        @sig(
+            $(#[$($struct_attr:tt)*])*
+            $vis:vis struct $name:ident
+            $(where $($whr:tt)*)?
+        ),
+        @impl_generics($($impl_generics:tt)*),
+        @ty_generics($($ty_generics:tt)*),
+        @body({ $($fields:tt)* }),
+    ) => {
+        $crate::__pin_data!(constructor_munch:
+            @vis($vis),
+            @name($name),
+            @impl_generics($($impl_generics)*),
+            @ty_generics($($ty_generics)*),
+            @where($($($whr)*)?),
+            // We add a `,` at the end to ensure correct parsing.
+            @fields_munch($($fields)* ,),
+            // The parameters of the constructor, one initializer per field.
+            @params(),
+            // The fields of the `try_pin_init!` invocation.
+            @inits(),
+            @is_pinned(),
+        );
+    };
+    (constructor:
+        @sig($($sig:tt)*),
+        @impl_generics($($impl_generics:tt)*),
+        @ty_generics($($ty_generics:tt)*),
+        @body($($body:tt)*),
+    ) => {
+        ::core::compile_error!("`#[pin_data(constructor)]` can only be used on structs.");
+    };
+    (constructor_munch:
+        @vis($vis:vis),
+        @name($name:ident),
+        @impl_generics($($impl_generics:tt)*),
+        @ty_generics($($ty_generics:tt)*),
+        @where($($whr:tt)*),
+        @fields_munch(#[pin] $($rest:tt)*),
+        @params($($params:tt)*),
+        @inits($($inits:tt)*),
+        @is_pinned($($is_pinned:ident)?),
+    ) => {
+        $crate::__pin_data!(constructor_munch:
+            @vis($vis),
+            @name($name),
+            @impl_generics($($impl_generics)*),
+            @ty_generics($($ty_generics)*),
+            @where($($whr)*),
+            @fields_munch($($rest)*),
+            @params($($params)*),
+            @inits($($inits)*),
+            @is_pinned(yes),
+        );
+    };
+    (constructor_munch:
+        @vis($vis:vis),
+        @name($name:ident),
+        @impl_generics($($impl_generics:tt)*),
+        @ty_generics($($ty_generics:tt)*),
+        @where($($whr:tt)*),
+        // Other attributes are not needed for the constructor.
+        @fields_munch(#[$($attr:tt)*] $($rest:tt)*),
+        @params($($params:tt)*),
+        @inits($($inits:tt)*),
+        @is_pinned($($is_pinned:ident)?),
+    ) => {
+        $crate::__pin_data!(constructor_munch:
+            @vis($vis),
+            @name($name),
+            @impl_generics($($impl_generics)*),
+            @ty_generics($($ty_generics)*),
+            @where($($whr)*),
+            @fields_munch($($rest)*),
+            @params($($params)*),
+            @inits($($inits)*),
+            @is_pinned($($is_pinned)?),
+        );
+    };
+    (constructor_munch:
+        @vis($vis:vis),
+        @name($name:ident),
+        @impl_generics($($impl_generics:tt)*),
+        @ty_generics($($ty_generics:tt)*),
+        @where($($whr:tt)*),
+        @fields_munch($fvis:vis $field:ident : $type:ty, $($rest:tt)*),
+        @params($($params:tt)*),
+        @inits($($inits:tt)*),
+        // This field is pinned.
+        @is_pinned(yes),
+    ) => {
+        $crate::__pin_data!(constructor_munch:
+            @vis($vis),
+            @name($name),
+            @impl_generics($($impl_generics)*),
+            @ty_generics($($ty_generics)*),
+            @where($($whr)*),
+            @fields_munch($($rest)*),
+            @params($($params)* $field: impl $crate::PinInit<$type, __E>,),
+            @inits($($inits)* $field <- $field,),
+            @is_pinned(),
+        );
+    };
+    (constructor_munch:
+        @vis($vis:vis),
+        @name($name:ident),
+        @impl_generics($($impl_generics:tt)*),
+        @ty_generics($($ty_generics:tt)*),
+        @where($($whr:tt)*),
+        @fields_munch($fvis:vis $field:ident : $type:ty, $($rest:tt)*),
+        @params($($params:tt)*),
+        @inits($($inits:tt)*),
+        // This field is not pinned.
+        @is_pinned(),
+    ) => {
+        $crate::__pin_data!(constructor_munch:
+            @vis($vis),
+            @name($name),
+            @impl_generics($($impl_generics)*),
+            @ty_generics($($ty_generics)*),
+            @where($($whr)*),
+            @fields_munch($($rest)*),
+            @params($($params)* $field: impl $crate::Init<$type, __E>,),
+            @inits($($inits)* $field <- $field,),
+            @is_pinned(),
+        );
+    };
+    (constructor_munch:
+        @vis($vis:vis),
+        @name($name:ident),
+        @impl_generics($($impl_generics:tt)*),
+        @ty_generics($($ty_generics:tt)*),
+        @where($($whr:tt)*),
+        @fields_munch($(,)?),
+        @params($($params:tt)*),
+        @inits($($inits:tt)*),
+        @is_pinned(),
+    ) => {
+        impl<$($impl_generics)*> $name<$($ty_generics)*>
+        where $($whr)*
+        {
+            /// Creates a pin-initializer from one initializer for every field, in declaration order.
+            #[allow(dead_code, clippy::too_many_arguments)]
+            $vis fn new_pin_init<__E>($($params)*) -> impl $crate::PinInit<Self, __E> {
+                $crate::try_pin_init!($name {
+                    $($inits)*
+                }? __E)
+            }
+        }
+    };
+    (make_pin_data:
+        @pin_data($pin_data:ident),
+        @impl_generics($($impl_generics:tt)*),
+        @ty_generics($($ty_generics:tt)*),
+        @where($($whr:tt)*),
+        @pinned($($(#[$($p_attr:tt)*])* $pvis:vis $p_field:ident : $p_type:ty),* $(,)?),
+        @not_pinned($($(#[$($attr:tt)*])* $fvis:vis $field:ident : $type:ty),* $(,)?),
+    ) => {
+        // For every field, we create a projection function according to its projection type. If a
+        // field is structurally pinned, then it must be initialized via `PinInit`, if it is not
+        // structurally pinned, then it can be initialized via `Init`.
+        //
+        // The functions are `unsafe` to prevent accidentally calling them.
+        #[allow(dead_code)]
+        #[expect(clippy::missing_safety_doc)]
+        impl<$($impl_generics)*> $pin_data<$($ty_generics)*>
+        where $($whr)*
+        {
+            // The `#[cfg]` attributes of a field also need to be put onto its projection function,
+            // otherwise it would still refer to the type of a field that has been configured out.
+            $(
+                $crate::__pin_data!(cfg_item:
+                    @attrs($(#[$($p_attr)*])*),
+                    @cfgs(),
+                    @item(
+                        $pvis unsafe fn $p_field<E>(
+                            self,
+                            slot: *mut $p_type,
+                            init: impl $crate::PinInit<$p_type, E>,
+                        ) -> ::core::result::Result<(), E> {
+                            // SAFETY: TODO.
+                            unsafe { $crate::PinInit::__pinned_init(init, slot) }
+                        }
+                    ),
+                );
+            )*
+            $(
+                $crate::__pin_data!(cfg_item:
+                    @attrs($(#[$($attr)*])*),
+                    @cfgs(),
+                    @item(
+                        $fvis unsafe fn $field<E>(
+                            self,
+                            slot: *mut $type,
+                            init: impl $crate::Init<$type, E>,
+                        ) -> ::core::result::Result<(), E> {
+                            // SAFETY: TODO.
+                            unsafe { $crate::Init::__init(init, slot) }
+                        }
+                    ),
+                );
+            )*
+        }
+    };
+    // Emits `$item` with only the `#[cfg]` attributes out of `$attrs`.
+    (cfg_item:
+        @attrs(#[cfg($($cfg:tt)*)] $($rest:tt)*),
+        @cfgs($($cfgs:tt)*),
+        @item($($item:tt)*),
+    ) => {
+        $crate::__pin_data!(cfg_item:
+            @attrs($($rest)*),
+            @cfgs($($cfgs)* #[cfg($($cfg)*)]),
+            @item($($item)*),
+        );
+    };
+    (cfg_item:
+        @attrs(#[$($attr:tt)*] $($rest:tt)*),
+        @cfgs($($cfgs:tt)*),
+        @item($($item:tt)*),
+    ) => {
+        $crate::__pin_data!(cfg_item:
+            @attrs($($rest)*),
+            @cfgs($($cfgs)*),
+            @item($($item)*),
+        );
+    };
+    (cfg_item:
+        @attrs(),
+        @cfgs($($cfgs:tt)*),
+        @item($($item:tt)*),
+    ) => {
+        $($cfgs)*
+        $($item)*
+    };
+}
+
+pub use paste::paste;
+
+/// The internal init macro. Do not call manually!
+///
+/// This is called by the `{try_}{pin_}init!` macros with various inputs.
+///
+/// This macro has multiple internal call configurations, these are always the very first ident:
+/// - nothing: this is the base case and called by the `{try_}{pin_}init!` macros.
+/// - `with_update_parsed`: when the `..Zeroable::zeroed()` or `..Default::default()` syntax has
+///   been handled.
+/// - `init_slot`: recursively creates the code that initializes all fields in `slot`.
+/// - `field_ref`: makes an initialized field accessible via `field_ref!`.
+/// - `make_initializer`: recursively create the struct initializer that guarantees that every
+///   field has been initialized exactly once.
+/// - `check_duplicates`: rejects fields that are mentioned more than once, pointing at both
+///   occurrences.
+/// - `check_update`: rejects `<-` after the `..` of the struct update syntax.
+/// - `check_zeroed_ty`: rejects types other than the initialized struct in
+///   `..<T as Zeroable>::zeroed()`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __init_internal {
+    (
+        @this($($this:ident)?),
+        @this_ptr($($this_ptr:ident)?),
+        @typ($t:ident $(::<$($generics:ty),*>)?),
+        @fields($($fields:tt)*),
+        @error($err:ty),
+        // Either `PinData` or `InitData`, `$use_data` should only be present in the `PinData`
+        // case.
+        @data($data:ident, $($use_data:ident)?),
+        // `GetPinData` or `HasInitData`.
+        @has_data($has_data:ident, $get_data:ident),
+        // `pin_init_from_closure` or `init_from_closure`.
+        @construct_closure($construct_closure:ident),
+        $(@munched($($munched:tt)*),)?
+        @munch_fields(),
+    ) => {
+        $crate::__init_internal!(with_update_parsed:
+            @this($($this)?),
+            @this_ptr($($this_ptr)?),
+            @typ($t $(::<$($generics),*>)?),
+            @fields($($fields)*),
+            @error($err),
+            @data($data, $($use_data)?),
+            @has_data($has_data, $get_data),
+            @construct_closure($construct_closure),
+            @zeroed(), // Nothing means default behavior.
+            @default(),
+        )
+    };
+    (
+        @this($($this:ident)?),
+        @this_ptr($($this_ptr:ident)?),
+        @typ($t:ident $(::<$($generics:ty),*>)?),
         @fields($($fields:tt)*),
         @error($err:ty),
         // Either `PinData` or `InitData`, `$use_data` should only be present in the `PinData`
         // case.
         @data($data:ident, $($use_data:ident)?),
-        // `HasPinData` or `HasInitData`.
+        // `GetPinData` or `HasInitData`.
         @has_data($has_data:ident, $get_data:ident),
         // `pin_init_from_closure` or `init_from_closure`.
         @construct_closure($construct_closure:ident),
+        $(@munched($($munched:tt)*),)?
         @munch_fields(..Zeroable::zeroed()),
     ) => {
         $crate::__init_internal!(with_update_parsed:
             @this($($this)?),
-            @typ($t),
+            @this_ptr($($this_ptr)?),
+            @typ($t $(::<$($generics),*>)?),
             @fields($($fields)*),
             @error($err),
             @data($data, $($use_data)?),
             @has_data($has_data, $get_data),
             @construct_closure($construct_closure),
             @zeroed(()), // `()` means zero all fields not mentioned.
+            @default(),
         )
     };
     (
         @this($($this:ident)?),
-        @typ($t:path),
+        @this_ptr($($this_ptr:ident)?),
+        @typ($t:ident $(::<$($generics:ty),*>)?),
         @fields($($fields:tt)*),
         @error($err:ty),
         // Either `PinData` or `InitData`, `$use_data` should only be present in the `PinData`
         // case.
         @data($data:ident, $($use_data:ident)?),
-        // `HasPinData` or `HasInitData`.
+        // `GetPinData` or `HasInitData`.
         @has_data($has_data:ident, $get_data:ident),
         // `pin_init_from_closure` or `init_from_closure`.
         @construct_closure($construct_closure:ident),
-        @munch_fields($ignore:tt $($rest:tt)*),
+        $(@munched($($munched:tt)*),)?
+        @munch_fields(..$(::)? pinned_init :: Zeroable :: zeroed()),
     ) => {
+        // Qualified forms of `..Zeroable::zeroed()` are normalized, so the remaining arms only
+        // need to handle the short form.
+        $crate::__init_internal!(
+            @this($($this)?),
+            @this_ptr($($this_ptr)?),
+            @typ($t $(::<$($generics),*>)?),
+            @fields($($($munched)*)? ..Zeroable::zeroed()),
+            @error($err),
+            @data($data, $($use_data)?),
+            @has_data($has_data, $get_data),
+            @construct_closure($construct_closure),
+            @munch_fields(..Zeroable::zeroed()),
+        )
+    };
+    (
+        @this($($this:ident)?),
+        @this_ptr($($this_ptr:ident)?),
+        @typ($t:ident $(::<$($generics:ty),*>)?),
+        @fields($($fields:tt)*),
+        @error($err:ty),
+        // Either `PinData` or `InitData`, `$use_data` should only be present in the `PinData`
+        // case.
+        @data($data:ident, $($use_data:ident)?),
+        // `GetPinData` or `HasInitData`.
+        @has_data($has_data:ident, $get_data:ident),
+        // `pin_init_from_closure` or `init_from_closure`.
+        @construct_closure($construct_closure:ident),
+        $(@munched($($munched:tt)*),)?
+        @munch_fields(..<$zeroed_ty:ident as Zeroable>::zeroed()),
+    ) => {{
+        // Qualified forms of `..Zeroable::zeroed()` are normalized, so the remaining arms only
+        // need to handle the short form.
+        $crate::__init_internal!(check_zeroed_ty:
+            @dollar($),
+            @typ($t),
+            @zeroed_ty($zeroed_ty),
+        );
         $crate::__init_internal!(
             @this($($this)?),
+            @this_ptr($($this_ptr)?),
+            @typ($t $(::<$($generics),*>)?),
+            @fields($($($munched)*)? ..Zeroable::zeroed()),
+            @error($err),
+            @data($data, $($use_data)?),
+            @has_data($has_data, $get_data),
+            @construct_closure($construct_closure),
+            @munch_fields(..Zeroable::zeroed()),
+        )
+    }};
+    (
+        @this($($this:ident)?),
+        @this_ptr($($this_ptr:ident)?),
+        @typ($t:ident $(::<$($generics:ty),*>)?),
+        @fields($($fields:tt)*),
+        @error($err:ty),
+        // Either `PinData` or `InitData`, `$use_data` should only be present in the `PinData`
+        // case.
+        @data($data:ident, $($use_data:ident)?),
+        // `GetPinData` or `HasInitData`.
+        @has_data($has_data:ident, $get_data:ident),
+        // `pin_init_from_closure` or `init_from_closure`.
+        @construct_closure($construct_closure:ident),
+        $(@munched($($munched:tt)*),)?
+        @munch_fields(..<$zeroed_ty:ident as $(::)? pinned_init :: Zeroable>::zeroed()),
+    ) => {{
+        // Qualified forms of `..Zeroable::zeroed()` are normalized, so the remaining arms only
+        // need to handle the short form.
+        $crate::__init_internal!(check_zeroed_ty:
+            @dollar($),
             @typ($t),
+            @zeroed_ty($zeroed_ty),
+        );
+        $crate::__init_internal!(
+            @this($($this)?),
+            @this_ptr($($this_ptr)?),
+            @typ($t $(::<$($generics),*>)?),
+            @fields($($($munched)*)? ..Zeroable::zeroed()),
+            @error($err),
+            @data($data, $($use_data)?),
+            @has_data($has_data, $get_data),
+            @construct_closure($construct_closure),
+            @munch_fields(..Zeroable::zeroed()),
+        )
+    }};
+    (
+        @this($($this:ident)?),
+        @this_ptr($($this_ptr:ident)?),
+        @typ($t:ident $(::<$($generics:ty),*>)?),
+        @fields($($fields:tt)*),
+        @error($err:ty),
+        // Either `PinData` or `InitData`, `$use_data` should only be present in the `PinData`
+        // case.
+        @data($data:ident, $($use_data:ident)?),
+        // `GetPinData` or `HasInitData`.
+        @has_data($has_data:ident, $get_data:ident),
+        // `pin_init_from_closure` or `init_from_closure`.
+        @construct_closure($construct_closure:ident),
+        $(@munched($($munched:tt)*),)?
+        @munch_fields(..$(::)? $krate:ident :: Zeroable :: zeroed()),
+    ) => {
+        ::core::compile_error!(
+            "`..Zeroable::zeroed()` can only be qualified with the `pinned_init` crate"
+        )
+    };
+    (
+        @this($($this:ident)?),
+        @this_ptr($($this_ptr:ident)?),
+        @typ($t:ident $(::<$($generics:ty),*>)?),
+        @fields($($fields:tt)*),
+        @error($err:ty),
+        // Either `PinData` or `InitData`, `$use_data` should only be present in the `PinData`
+        // case.
+        @data($data:ident, $($use_data:ident)?),
+        // `GetPinData` or `HasInitData`.
+        @has_data($has_data:ident, $get_data:ident),
+        // `pin_init_from_closure` or `init_from_closure`.
+        @construct_closure($construct_closure:ident),
+        $(@munched($($munched:tt)*),)?
+        @munch_fields(..<$zeroed_ty:ty as $zeroable:path>::zeroed()),
+    ) => {
+        ::core::compile_error!(
+            "use `..<Self as Zeroable>::zeroed()` or `..Zeroable::zeroed()` to zero the remaining \
+                fields"
+        )
+    };
+    (
+        @this($($this:ident)?),
+        @this_ptr($($this_ptr:ident)?),
+        @typ($t:ident $(::<$($generics:ty),*>)?),
+        @fields($($fields:tt)*),
+        @error($err:ty),
+        // Either `PinData` or `InitData`, `$use_data` should only be present in the `PinData`
+        // case.
+        @data($data:ident, $($use_data:ident)?),
+        // `GetPinData` or `HasInitData`.
+        @has_data($has_data:ident, $get_data:ident),
+        // `pin_init_from_closure` or `init_from_closure`.
+        @construct_closure($construct_closure:ident),
+        $(@munched($($munched:tt)*),)?
+        @munch_fields(..Default::default()),
+    ) => {
+        $crate::__init_internal!(with_update_parsed:
+            @this($($this)?),
+            @this_ptr($($this_ptr)?),
+            @typ($t $(::<$($generics),*>)?),
+            @fields($($fields)*),
+            @error($err),
+            @data($data, $($use_data)?),
+            @has_data($has_data, $get_data),
+            @construct_closure($construct_closure),
+            @zeroed(),
+            @default(()), // `()` means that all fields not mentioned are set to their default.
+        )
+    };
+    (
+        @this($($this:ident)?),
+        @this_ptr($($this_ptr:ident)?),
+        @typ($t:ident $(::<$($generics:ty),*>)?),
+        @fields($($fields:tt)*),
+        @error($err:ty),
+        // Either `PinData` or `InitData`, `$use_data` should only be present in the `PinData`
+        // case.
+        @data($data:ident, $($use_data:ident)?),
+        // `GetPinData` or `HasInitData`.
+        @has_data($has_data:ident, $get_data:ident),
+        // `pin_init_from_closure` or `init_from_closure`.
+        @construct_closure($construct_closure:ident),
+        $(@munched($($munched:tt)*),)?
+        @munch_fields(.. $($rest:tt)*),
+    ) => {
+        // This is either the struct update syntax with an unsupported expression, or a range
+        // expression in a field value. Reject `<-` in the former case with a readable error.
+        $crate::__init_internal!(check_update:
+            @tokens($($rest)*),
+            @then($crate::__init_internal!(
+                @this($($this)?),
+                @this_ptr($($this_ptr)?),
+                @typ($t $(::<$($generics),*>)?),
+                @fields($($fields)*),
+                @error($err),
+                @data($data, $($use_data)?),
+                @has_data($has_data, $get_data),
+                @construct_closure($construct_closure),
+                @munched($($($munched)*)? ..),
+                @munch_fields($($rest)*),
+            )),
+        )
+    };
+    (
+        @this($($this:ident)?),
+        @this_ptr($($this_ptr:ident)?),
+        @typ($t:ident $(::<$($generics:ty),*>)?),
+        @fields($($fields:tt)*),
+        @error($err:ty),
+        // Either `PinData` or `InitData`, `$use_data` should only be present in the `PinData`
+        // case.
+        @data($data:ident, $($use_data:ident)?),
+        // `GetPinData` or `HasInitData`.
+        @has_data($has_data:ident, $get_data:ident),
+        // `pin_init_from_closure` or `init_from_closure`.
+        @construct_closure($construct_closure:ident),
+        $(@munched($($munched:tt)*),)?
+        @munch_fields($ignore:tt $($rest:tt)*),
+    ) => {
+        $crate::__init_internal!(
+            @this($($this)?),
+            @this_ptr($($this_ptr)?),
+            @typ($t $(::<$($generics),*>)?),
             @fields($($fields)*),
             @error($err),
             @data($data, $($use_data)?),
             @has_data($has_data, $get_data),
             @construct_closure($construct_closure),
+            // The munched tokens are only needed to normalize the `..Zeroable::zeroed()` syntax.
+            @munched($($($munched)*)? $ignore),
             @munch_fields($($rest)*),
         )
     };
+    (check_update: @tokens(<- $($rest:tt)*), @then($($then:tt)*),) => {
+        ::core::compile_error!(
+            "`<-` cannot be used after `..`, use `..Zeroable::zeroed()` or `..Default::default()` \
+                to initialize the remaining fields"
+        )
+    };
+    // A comma means that the `..` was part of a range expression in a field value.
+    (check_update: @tokens($(, $($rest:tt)*)?), @then($($then:tt)*),) => {
+        $($then)*
+    };
+    (check_update: @tokens($ignore:tt $($rest:tt)*), @then($($then:tt)*),) => {
+        $crate::__init_internal!(check_update: @tokens($($rest)*), @then($($then)*),)
+    };
+    // Only the initialized struct itself can be named in `..<T as Zeroable>::zeroed()`. A local
+    // macro is used to compare the identifiers, `$d` is a `$` token for its metavariables.
+    (check_zeroed_ty: @dollar($d:tt), @typ($t:ident), @zeroed_ty($zeroed_ty:ident),) => {
+        macro_rules! __check_zeroed_ty {
+            (Self) => {};
+            ($t) => {};
+            ($d other:ident) => {
+                ::core::compile_error!(
+                    "only `Self` or the initialized struct can be zeroed by \
+                        `..<T as Zeroable>::zeroed()`"
+                );
+            };
+        }
+        __check_zeroed_ty!($zeroed_ty);
+    };
     (with_update_parsed:
         @this($($this:ident)?),
-        @typ($t:path),
+        @this_ptr($($this_ptr:ident)?),
+        @typ($t:ident $(::<$($generics:ty),*>)?),
         @fields($($fields:tt)*),
         @error($err:ty),
         // Either `PinData` or `InitData`, `$use_data` should only be present in the `PinData`
         // case.
         @data($data:ident, $($use_data:ident)?),
-        // `HasPinData` or `HasInitData`.
+        // `GetPinData` or `HasInitData`.
         @has_data($has_data:ident, $get_data:ident),
         // `pin_init_from_closure` or `init_from_closure`.
         @construct_closure($construct_closure:ident),
         @zeroed($($init_zeroed:expr)?),
+        @default($($init_default:expr)?),
     ) => {{
         // We do not want to allow arbitrary returns, so we declare this type as the `Ok` return
         // type and shadow it later when we insert the arbitrary user code. That way there will be
@@ -1130,35 +2228,74 @@ macro_rules! __init_internal {
         // SAFETY: TODO.
         let data = unsafe {
             use $crate::__internal::$has_data;
-            // Here we abuse `paste!` to retokenize `$t`. Declarative macros have some internal
-            // information that is associated to already parsed fragments, so a path fragment
-            // cannot be used in this position. Doing the retokenization results in valid rust
-            // code.
-            $crate::macros::paste!($t::$get_data())
+            $t $(::<$($generics),*>)?::$get_data()
         };
         // Ensure that `data` really is of type `$data` and help with type inference:
         let init = $crate::__internal::$data::make_closure::<_, __InitOk, $err>(
             data,
             move |slot| {
+                // `skip_rest!()` returns early, so it needs a `__InitOk` that is created before
+                // the structure is shadowed.
+                $(let __skip_ok = {
+                    let () = $init_zeroed; // This will be `()` if set.
+                    __InitOk
+                };)?
                 {
                     // Shadow the structure so it cannot be used to return early.
                     struct __InitOk;
                     // If `$init_zeroed` is present we should zero the slot now and not emit an
                     // error when fields are missing (since they will be zeroed). We also have to
                     // check that the type actually implements `Zeroable`.
-                    $({
+                    $(let __skipped = {
                         fn assert_zeroable<T: $crate::Zeroable>(_: *mut T) {}
                         // Ensure that the struct is indeed `Zeroable`.
                         assert_zeroable(slot);
                         // SAFETY: The type implements `Zeroable` by the check above.
                         unsafe { ::core::ptr::write_bytes(slot, 0, 1) };
-                        $init_zeroed // This will be `()` if set.
-                    })?
+                        let () = $init_zeroed; // This will be `()` if set.
+                        // Set by `skip_rest!()`, the guards of the fields check this flag.
+                        ::core::cell::Cell::new(false)
+                    };
+                    // Since the slot has been zeroed, the remaining fields can be left alone and
+                    // the initialization can finish early. The fields that have already been
+                    // initialized must not be dropped, so their guards are disabled.
+                    #[allow(unused_macros)]
+                    macro_rules! skip_rest {
+                        () => {{
+                            __skipped.set(true);
+                            return ::core::result::Result::Ok(__skip_ok);
+                        }};
+                    }
+                    )?
+                    // If `$init_default` is present we should write the default value to the slot
+                    // now. Since the default value needs to be dropped on failure, all fields are
+                    // guarded by a single guard for the whole slot.
+                    $(let __default_guard = {
+                        // SAFETY: `slot` is valid, because we are inside of an initializer closure.
+                        unsafe { $crate::__internal::write_default(slot) };
+                        let () = $init_default; // This will be `()` if set.
+                        // SAFETY: The slot is fully initialized. We forget the guard when the
+                        // initialization has succeeded.
+                        unsafe { $crate::__internal::DropGuard::new(slot) }
+                    };)?
                     // Create the `this` so it can be referenced by the user inside of the
                     // expressions creating the individual fields.
                     $(let $this = unsafe { ::core::ptr::NonNull::new_unchecked(slot) };)?
+                    // Create the `this` raw pointer, if the user requested it via `&raw this in`.
+                    $(let $this_ptr = slot;)?
+                    // Allows the expressions of the fields to access fields that have already been
+                    // initialized. The `__ $name _ref` macros are defined after each field has been
+                    // initialized, so using a field that is initialized later fails to compile.
+                    #[allow(unused_macros)]
+                    macro_rules! field_ref {
+                        ($name:ident) => {
+                            $crate::macros::paste! { [< __ $name _ref >]!() }
+                        };
+                    }
                     // Initialize every field.
                     $crate::__init_internal!(init_slot($($use_data)?):
+                        @default($($init_default, __default_guard)?),
+                        @zeroed($($init_zeroed, __skipped)?),
                         @data(data),
                         @slot(slot),
                         @guards(),
@@ -1169,11 +2306,14 @@ macro_rules! __init_internal {
                     // very natural error message if a field is forgotten/mentioned more than once.
                     #[allow(unreachable_code, clippy::diverging_sub_expression)]
                     let _ = || {
+                        // Only the name of the struct is given, its generics are inferred from
+                        // `slot`.
                         $crate::__init_internal!(make_initializer:
                             @slot(slot),
                             @type_name($t),
                             @munch_fields($($fields)*,),
                             @acc(),
+                            @names(),
                         );
                     };
                 }
@@ -1188,6 +2328,8 @@ macro_rules! __init_internal {
         init
     }};
     (init_slot($($use_data:ident)?):
+        @default(),
+        @zeroed($($init_zeroed:tt, $skipped:ident)?),
         @data($data:ident),
         @slot($slot:ident),
         @guards($($guards:ident,)*),
@@ -1198,6 +2340,8 @@ macro_rules! __init_internal {
         $(::core::mem::forget($guards);)*
     };
     (init_slot($use_data:ident): // `use_data` is present, so we use the `data` to init fields.
+        @default(),
+        @zeroed($($init_zeroed:tt, $skipped:ident)?),
         @data($data:ident),
         @slot($slot:ident),
         @guards($($guards:ident,)*),
@@ -1218,10 +2362,19 @@ macro_rules! __init_internal {
         $crate::macros::paste! {
             // SAFETY: We forget the guard later when initialization has succeeded.
             let [< __ $field _guard >] = unsafe {
-                $crate::__internal::DropGuard::new(::core::ptr::addr_of_mut!((*$slot).$field))
+                $crate::__internal::DropGuard::new_field(::core::ptr::addr_of_mut!((*$slot).$field))
             };
+            // After `skip_rest!()` the field has to stay initialized.
+            $(let [< __ $field _guard >] =
+                $crate::__internal::SkipRestGuard::new([< __ $field _guard >], &$skipped);)?
+            $crate::__init_internal!(field_ref:
+                @slot($slot),
+                @field($field),
+            );
 
             $crate::__init_internal!(init_slot($use_data):
+                @default(),
+                @zeroed($($init_zeroed, $skipped)?),
                 @data($data),
                 @slot($slot),
                 @guards([< __ $field _guard >], $($guards,)*),
@@ -1230,6 +2383,8 @@ macro_rules! __init_internal {
         }
     };
     (init_slot(): // No `use_data`, so we use `Init::__init` directly.
+        @default(),
+        @zeroed($($init_zeroed:tt, $skipped:ident)?),
         @data($data:ident),
         @slot($slot:ident),
         @guards($($guards:ident,)*),
@@ -1249,10 +2404,19 @@ macro_rules! __init_internal {
         $crate::macros::paste! {
             // SAFETY: We forget the guard later when initialization has succeeded.
             let [< __ $field _guard >] = unsafe {
-                $crate::__internal::DropGuard::new(::core::ptr::addr_of_mut!((*$slot).$field))
+                $crate::__internal::DropGuard::new_field(::core::ptr::addr_of_mut!((*$slot).$field))
             };
+            // After `skip_rest!()` the field has to stay initialized.
+            $(let [< __ $field _guard >] =
+                $crate::__internal::SkipRestGuard::new([< __ $field _guard >], &$skipped);)?
+            $crate::__init_internal!(field_ref:
+                @slot($slot),
+                @field($field),
+            );
 
             $crate::__init_internal!(init_slot():
+                @default(),
+                @zeroed($($init_zeroed, $skipped)?),
                 @data($data),
                 @slot($slot),
                 @guards([< __ $field _guard >], $($guards,)*),
@@ -1261,6 +2425,8 @@ macro_rules! __init_internal {
         }
     };
     (init_slot($($use_data:ident)?):
+        @default(),
+        @zeroed($($init_zeroed:tt, $skipped:ident)?),
         @data($data:ident),
         @slot($slot:ident),
         @guards($($guards:ident,)*),
@@ -1281,10 +2447,19 @@ macro_rules! __init_internal {
         $crate::macros::paste! {
             // SAFETY: We forget the guard later when initialization has succeeded.
             let [< __ $field _guard >] = unsafe {
-                $crate::__internal::DropGuard::new(::core::ptr::addr_of_mut!((*$slot).$field))
+                $crate::__internal::DropGuard::new_field(::core::ptr::addr_of_mut!((*$slot).$field))
             };
+            // After `skip_rest!()` the field has to stay initialized.
+            $(let [< __ $field _guard >] =
+                $crate::__internal::SkipRestGuard::new([< __ $field _guard >], &$skipped);)?
+            $crate::__init_internal!(field_ref:
+                @slot($slot),
+                @field($field),
+            );
 
             $crate::__init_internal!(init_slot($($use_data)?):
+                @default(),
+                @zeroed($($init_zeroed, $skipped)?),
                 @data($data),
                 @slot($slot),
                 @guards([< __ $field _guard >], $($guards,)*),
@@ -1292,12 +2467,178 @@ macro_rules! __init_internal {
             );
         }
     };
+    (init_slot($($use_data:ident)?):
+        @default($init_default:tt, $default_guard:ident),
+        @zeroed(),
+        @data($data:ident),
+        @slot($slot:ident),
+        @guards(),
+        @munch_fields(..Default::default() $(,)?),
+    ) => {
+        // Endpoint of munching with `..Default::default()`, no fields are left. If execution
+        // reaches this point, all fields have been initialized. Therefore we can now dismiss the
+        // guard of the slot by forgetting it.
+        ::core::mem::forget($default_guard);
+    };
+    (init_slot($use_data:ident): // `use_data` is present, so we use the `data` to init fields.
+        @default($init_default:tt, $default_guard:ident),
+        @zeroed(),
+        @data($data:ident),
+        @slot($slot:ident),
+        @guards(),
+        // In-place initialization syntax.
+        @munch_fields($field:ident <- $val:expr, $($rest:tt)*),
+    ) => {
+        let init = $val;
+        // Move the default value of the field out of the slot. If the initializer fails, it is
+        // written back and the whole slot is dropped by `$default_guard`.
+        //
+        // SAFETY: The field is initialized with its default value and has not been pinned yet.
+        let old = unsafe {
+            $crate::__internal::DefaultFieldGuard::new(::core::ptr::addr_of_mut!((*$slot).$field))
+        };
+        // Call the initializer.
+        //
+        // SAFETY: `slot` is valid, because we are inside of an initializer closure and the field
+        // is uninitialized, since its default value has been moved out above.
+        // We also use the `data` to require the correct trait (`Init` or `PinInit`) for `$field`.
+        unsafe { $data.$field(::core::ptr::addr_of_mut!((*$slot).$field), init)? };
+        old.dismiss();
+        $crate::__init_internal!(field_ref:
+            @slot($slot),
+            @field($field),
+        );
+        $crate::__init_internal!(init_slot($use_data):
+            @default($init_default, $default_guard),
+            @zeroed(),
+            @data($data),
+            @slot($slot),
+            @guards(),
+            @munch_fields($($rest)*),
+        );
+    };
+    (init_slot(): // No `use_data`, so we use `Init::__init` directly.
+        @default($init_default:tt, $default_guard:ident),
+        @zeroed(),
+        @data($data:ident),
+        @slot($slot:ident),
+        @guards(),
+        // In-place initialization syntax.
+        @munch_fields($field:ident <- $val:expr, $($rest:tt)*),
+    ) => {
+        let init = $val;
+        // Move the default value of the field out of the slot. If the initializer fails, it is
+        // written back and the whole slot is dropped by `$default_guard`.
+        //
+        // SAFETY: The field is initialized with its default value.
+        let old = unsafe {
+            $crate::__internal::DefaultFieldGuard::new(::core::ptr::addr_of_mut!((*$slot).$field))
+        };
+        // Call the initializer.
+        //
+        // SAFETY: `slot` is valid, because we are inside of an initializer closure and the field
+        // is uninitialized, since its default value has been moved out above.
+        unsafe { $crate::Init::__init(init, ::core::ptr::addr_of_mut!((*$slot).$field))? };
+        old.dismiss();
+        $crate::__init_internal!(field_ref:
+            @slot($slot),
+            @field($field),
+        );
+        $crate::__init_internal!(init_slot():
+            @default($init_default, $default_guard),
+            @zeroed(),
+            @data($data),
+            @slot($slot),
+            @guards(),
+            @munch_fields($($rest)*),
+        );
+    };
+    (init_slot($($use_data:ident)?):
+        @default($init_default:tt, $default_guard:ident),
+        @zeroed(),
+        @data($data:ident),
+        @slot($slot:ident),
+        @guards(),
+        // Init by-value.
+        @munch_fields($field:ident $(: $val:expr)?, $($rest:tt)*),
+    ) => {
+        {
+            $(let $field = $val;)?
+            // Replace the default value of the field and drop it.
+            //
+            // SAFETY: The field is initialized with its default value.
+            ::core::mem::drop(unsafe {
+                ::core::ptr::replace(::core::ptr::addr_of_mut!((*$slot).$field), $field)
+            });
+        }
+        $crate::__init_internal!(field_ref:
+            @slot($slot),
+            @field($field),
+        );
+        $crate::__init_internal!(init_slot($($use_data)?):
+            @default($init_default, $default_guard),
+            @zeroed(),
+            @data($data),
+            @slot($slot),
+            @guards(),
+            @munch_fields($($rest)*),
+        );
+    };
+    (field_ref:
+        @slot($slot:ident),
+        @field($field:ident),
+    ) => {
+        // Makes `$field` available to `field_ref!` in the expressions of the following fields.
+        $crate::macros::paste! {
+            #[allow(unused_macros)]
+            macro_rules! [< __ $field _ref >] {
+                () => {
+                    // SAFETY: `$field` has been initialized and the initializer does not modify it
+                    // afterwards. The reference cannot outlive the initializer closure.
+                    unsafe {
+                        $crate::__internal::field_ref(&$slot, ::core::ptr::addr_of!((*$slot).$field))
+                    }
+                };
+            }
+        }
+    };
+    (make_initializer:
+        @slot($slot:ident),
+        @type_name($t:path),
+        @munch_fields(..Default::default() $(,)?),
+        @acc($($acc:tt)*),
+        @names($($names:ident)*),
+    ) => {
+        $crate::__init_internal!(check_duplicates: @names($($names)*));
+        // Endpoint, nothing more to munch, create the initializer. Since the users specified
+        // `..Default::default()`, the slot will already contain the default value and all fields
+        // that have not been overwritten are thus initialized. We still check that all fields are
+        // actually accessible by using the struct update syntax ourselves.
+        // We are inside of a closure that is never executed and thus we can abuse `slot` to
+        // get the correct type inference here:
+        //
+        // SAFETY: This code is never executed.
+        unsafe {
+            // Here we abuse `paste!` to retokenize `$t`. Declarative macros have some internal
+            // information that is associated to already parsed fragments, so a path fragment
+            // cannot be used in this position. Doing the retokenization results in valid rust
+            // code.
+            $crate::macros::paste!(
+                ::core::ptr::write($slot, $t {
+                    $($acc)*
+                    ..::core::default::Default::default()
+                });
+            );
+        }
+    };
     (make_initializer:
         @slot($slot:ident),
         @type_name($t:path),
         @munch_fields(..Zeroable::zeroed() $(,)?),
         @acc($($acc:tt)*),
+        @names($($names:ident)*),
     ) => {
+        $crate::__init_internal!(check_duplicates: @names($($names)*));
         // Endpoint, nothing more to munch, create the initializer. Since the users specified
         // `..Zeroable::zeroed()`, the slot will already have been zeroed and all field that have
         // not been overwritten are thus zero and initialized. We still check that all fields are
@@ -1328,7 +2669,9 @@ macro_rules! __init_internal {
         @type_name($t:path),
         @munch_fields($(,)?),
         @acc($($acc:tt)*),
+        @names($($names:ident)*),
     ) => {
+        $crate::__init_internal!(check_duplicates: @names($($names)*));
         // Endpoint, nothing more to munch, create the initializer.
         // Since we are in the closure that is never called, this will never get executed.
         // We abuse `slot` to get the correct type inference here:
@@ -1351,12 +2694,14 @@ macro_rules! __init_internal {
         @type_name($t:path),
         @munch_fields($field:ident <- $val:expr, $($rest:tt)*),
         @acc($($acc:tt)*),
+        @names($($names:ident)*),
     ) => {
         $crate::__init_internal!(make_initializer:
             @slot($slot),
             @type_name($t),
             @munch_fields($($rest)*),
             @acc($($acc)* $field: ::core::panic!(),),
+            @names($($names)* $field),
         );
     };
     (make_initializer:
@@ -1364,25 +2709,30 @@ macro_rules! __init_internal {
         @type_name($t:path),
         @munch_fields($field:ident $(: $val:expr)?, $($rest:tt)*),
         @acc($($acc:tt)*),
+        @names($($names:ident)*),
     ) => {
         $crate::__init_internal!(make_initializer:
             @slot($slot),
             @type_name($t),
             @munch_fields($($rest)*),
             @acc($($acc)* $field: ::core::panic!(),),
+            @names($($names)* $field),
         );
     };
+    (check_duplicates: @names($($names:ident)*)) => {
+        // The struct initializer above already rejects duplicate fields, but it cannot point at
+        // the first occurrence. Generic parameters also have to be unique and the error points at
+        // both of them.
+        #[allow(non_camel_case_types, dead_code, clippy::extra_unused_type_parameters)]
+        fn __check_duplicate_fields<$($names),*>() {}
+    };
 }
 
 #[doc(hidden)]
 #[macro_export]
 macro_rules! __derive_zeroable {
     (parse_input:
-        @sig(
-            $(#[$($struct_attr:tt)*])*
-            $vis:vis struct $name:ident
-            $(where $($whr:tt)*)?
-        ),
+        @sig($($sig:tt)*),
         @impl_generics($($impl_generics:tt)*),
         @ty_generics($($ty_generics:tt)*),
         @body({
@@ -1391,6 +2741,41 @@ macro_rules! __derive_zeroable {
                 $field:ident : $field_ty:ty
             ),* $(,)?
         }),
+    ) => {
+        $crate::__derive_zeroable!(make_impl:
+            @sig($($sig)*),
+            @impl_generics($($impl_generics)*),
+            @ty_generics($($ty_generics)*),
+            @field_tys($($field_ty),*),
+        );
+    };
+    (parse_input:
+        @sig($($sig:tt)*),
+        @impl_generics($($impl_generics:tt)*),
+        @ty_generics($($ty_generics:tt)*),
+        @body((
+            $(
+                $(#[$($field_attr:tt)*])*
+                $field_vis:vis $field_ty:ty
+            ),* $(,)?
+        )),
+    ) => {
+        $crate::__derive_zeroable!(make_impl:
+            @sig($($sig)*),
+            @impl_generics($($impl_generics)*),
+            @ty_generics($($ty_generics)*),
+            @field_tys($($field_ty),*),
+        );
+    };
+    (make_impl:
+        @sig(
+            $(#[$($struct_attr:tt)*])*
+            $vis:vis struct $name:ident
+            $(where $($whr:tt)*)?
+        ),
+        @impl_generics($($impl_generics:tt)*),
+        @ty_generics($($ty_generics:tt)*),
+        @field_tys($($field_ty:ty),*),
     ) => {
         // SAFETY: Every field type implements `Zeroable` and padding bytes may be zero.
         #[automatically_derived]
@@ -1408,3 +2793,207 @@ macro_rules! __derive_zeroable {
         };
     };
 }
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __derive_zeroable_default {
+    (parse_input:
+        @sig(
+            $(#[$($struct_attr:tt)*])*
+            $vis:vis struct $name:ident
+            $(where $($whr:tt)*)?
+        ),
+        @impl_generics($($impl_generics:tt)*),
+        @ty_generics($($ty_generics:tt)*),
+        @body($($body:tt)*),
+    ) => {
+        #[automatically_derived]
+        impl<$($impl_generics)*> $crate::ZeroableDefault for $name<$($ty_generics)*>
+        where
+            $name<$($ty_generics)*>: $crate::Zeroable + ::core::default::Default,
+            $($($whr)*)?
+        {}
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __derive_from_bytes {
+    (parse_input:
+        @sig(
+            $(#[$($struct_attr:tt)*])*
+            $vis:vis struct $name:ident
+            $(where $($whr:tt)*)?
+        ),
+        @impl_generics($($impl_generics:tt)*),
+        @ty_generics($($ty_generics:tt)*),
+        @body({
+            $(
+                $(#[$($field_attr:tt)*])*
+                $field:ident : $field_ty:ty
+            ),* $(,)?
+        }),
+    ) => {
+        // SAFETY: Every field type implements `FromBytes` and padding bytes may hold any value.
+        #[automatically_derived]
+        unsafe impl<$($impl_generics)*> $crate::FromBytes for $name<$($ty_generics)*>
+        where
+            $($($whr)*)?
+        {}
+        const _: () = {
+            fn assert_from_bytes<T: ?::core::marker::Sized + $crate::FromBytes>() {}
+            fn ensure_from_bytes<$($impl_generics)*>()
+                where $($($whr)*)?
+            {
+                $(assert_from_bytes::<$field_ty>();)*
+            }
+        };
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __derive_transparent_wrapper {
+    (parse_input:
+        @derive($derive:ident),
+        @sig(
+            $(#[$($struct_attr:tt)*])*
+            $vis:vis struct $name:ident
+            $(where $($whr:tt)*)?
+        ),
+        @impl_generics($($impl_generics:tt)*),
+        @ty_generics($($ty_generics:tt)*),
+        @body({
+            $(#[$($field_attr:tt)*])*
+            $fvis:vis $field:ident : $field_ty:ty $(,)?
+        }),
+    ) => {
+        $crate::__derive_transparent_wrapper!(make_impl:
+            @derive($derive),
+            @vis($vis),
+            @name($name),
+            @impl_generics($($impl_generics)*),
+            @ty_generics($($ty_generics)*),
+            @where($($($whr)*)?),
+            @inner($field_ty),
+        );
+    };
+    (parse_input:
+        @derive($derive:ident),
+        @sig(
+            $(#[$($struct_attr:tt)*])*
+            $vis:vis struct $name:ident
+            $(where $($whr:tt)*)?
+        ),
+        @impl_generics($($impl_generics:tt)*),
+        @ty_generics($($ty_generics:tt)*),
+        @body((
+            $(#[$($field_attr:tt)*])*
+            $fvis:vis $field_ty:ty $(,)?
+        )),
+    ) => {
+        $crate::__derive_transparent_wrapper!(make_impl:
+            @derive($derive),
+            @vis($vis),
+            @name($name),
+            @impl_generics($($impl_generics)*),
+            @ty_generics($($ty_generics)*),
+            @where($($($whr)*)?),
+            @inner($field_ty),
+        );
+    };
+    (parse_input:
+        @derive($derive:ident),
+        @sig($($sig:tt)*),
+        @impl_generics($($impl_generics:tt)*),
+        @ty_generics($($ty_generics:tt)*),
+        @body($($body:tt)*),
+    ) => {
+        ::core::compile_error!(::core::concat!(
+            "`#[derive(",
+            ::core::stringify!($derive),
+            ")]` can only be used on structs with exactly one field."
+        ));
+    };
+    (make_impl:
+        @derive($derive:ident),
+        @vis($vis:vis),
+        @name($name:ident),
+        @impl_generics($($impl_generics:tt)*),
+        @ty_generics($($ty_generics:tt)*),
+        @where($($whr:tt)*),
+        @inner($inner:ty),
+    ) => {
+        // SAFETY: The struct is `#[repr(transparent)]` and `$inner` is the type of its only field,
+        // so they have the same layout and every valid `$inner` is a valid struct. The `Unpin`
+        // impl and the drop prevention below ensure that the field is structurally pinned.
+        #[automatically_derived]
+        unsafe impl<$($impl_generics)*> $crate::TransparentWrapper for $name<$($ty_generics)*>
+        where $($whr)*
+        {
+            type Inner = $inner;
+        }
+
+        // A manual `Unpin` impl would conflict with this one. The `'__pin` lifetime ensures that
+        // the bound is not trivial, which would be an error for non-generic structs.
+        #[automatically_derived]
+        impl<'__pin, $($impl_generics)*> ::core::marker::Unpin for $name<$($ty_generics)*>
+        where
+            (::core::marker::PhantomData<&'__pin ()>, $inner): ::core::marker::Unpin,
+            $($whr)*
+        {}
+
+        const _: () = {
+            $crate::__pin_data!(drop_prevention:
+                @name($name),
+                @impl_generics($($impl_generics)*),
+                @ty_generics($($ty_generics)*),
+                @where($($whr)*),
+                @pinned_drop(),
+            );
+        };
+
+        $crate::__derive_transparent_wrapper!(forward:
+            @derive($derive),
+            @vis($vis),
+            @name($name),
+            @impl_generics($($impl_generics)*),
+            @ty_generics($($ty_generics)*),
+            @where($($whr)*),
+            @inner($inner),
+        );
+    };
+    (forward:
+        @derive(TransparentWrapper),
+        $($rest:tt)*
+    ) => {};
+    (forward:
+        @derive(InitForward),
+        @vis($vis:vis),
+        @name($name:ident),
+        @impl_generics($($impl_generics:tt)*),
+        @ty_generics($($ty_generics:tt)*),
+        @where($($whr:tt)*),
+        @inner($inner:ty),
+    ) => {
+        impl<$($impl_generics)*> $name<$($ty_generics)*>
+        where $($whr)*
+        {
+            /// Turns a pin-initializer for the only field into a pin-initializer for `Self`.
+            #[allow(dead_code)]
+            $vis fn from_inner_pin_init<__E>(
+                init: impl $crate::PinInit<$inner, __E>,
+            ) -> impl $crate::PinInit<Self, __E> {
+                $crate::PinInit::wrap(init)
+            }
+
+            /// Turns an initializer for the only field into an initializer for `Self`.
+            #[allow(dead_code)]
+            $vis fn from_inner_init<__E>(
+                init: impl $crate::Init<$inner, __E>,
+            ) -> impl $crate::Init<Self, __E> {
+                $crate::PinInit::wrap(init)
+            }
+        }
+    };
+}
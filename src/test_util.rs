@@ -0,0 +1,67 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Helpers for testing initializers without setting up their memory manually.
+
+use crate::Init;
+#[cfg(any(feature = "std", feature = "alloc"))]
+use crate::{InPlaceWrite, PinInit};
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::boxed::Box;
+use core::mem::MaybeUninit;
+#[cfg(any(feature = "std", feature = "alloc"))]
+use core::pin::Pin;
+
+/// Runs `init` on the stack and returns the initialized value.
+///
+/// When the `debug-poison` feature is enabled, the slot is poisoned before running `init`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use pinned_init::*;
+/// #[derive(Debug, PartialEq)]
+/// struct Point {
+///     x: i32,
+///     y: i32,
+/// }
+///
+/// fn diagonal(len: i32) -> impl Init<Point> {
+///     init!(Point { x: len, y: len })
+/// }
+///
+/// assert_eq!(init_to_value(diagonal(2)), Ok(Point { x: 2, y: 2 }));
+/// ```
+pub fn init_to_value<T, E>(init: impl Init<T, E>) -> Result<T, E> {
+    let mut slot = MaybeUninit::<T>::uninit();
+    let ptr = slot.as_mut_ptr();
+    // SAFETY: `ptr` is valid and uninitialized.
+    unsafe { crate::__internal::poison(ptr) };
+    // SAFETY: `ptr` is valid for writes and uninitialized. It is allowed to move after
+    // initialization, since `init` is an `Init`.
+    unsafe { init.__init(ptr)? };
+    // SAFETY: `slot` has been initialized above.
+    Ok(unsafe { slot.assume_init() })
+}
+
+/// Pin-initializes a `T` in a new [`Box`].
+///
+/// In contrast to [`InPlaceInit::try_pin_init`], the error type does not need to be convertible from
+/// [`AllocError`], allocation failures abort or panic like [`Box::new`].
+///
+/// # Examples
+///
+/// ```rust
+/// # #![feature(allocator_api)]
+/// # #[path = "../examples/mutex.rs"] mod mutex; use mutex::*;
+/// # use pinned_init::*;
+/// # use core::convert::Infallible;
+/// let mutex = pin_init_to_boxed::<_, Infallible>(CMutex::new(42)).unwrap();
+/// assert_eq!(*mutex.lock(), 42);
+/// ```
+///
+/// [`InPlaceInit::try_pin_init`]: crate::InPlaceInit::try_pin_init
+/// [`AllocError`]: core::alloc::AllocError
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub fn pin_init_to_boxed<T, E>(init: impl PinInit<T, E>) -> Result<Pin<Box<T>>, E> {
+    Box::<T>::new_uninit().write_pin_init(init)
+}
@@ -0,0 +1,91 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Initializers for tuples that are created from one initializer per element.
+
+use crate::{__internal::DropGuard, init_from_closure, pin_init_from_closure, Init, PinInit};
+use core::ptr::addr_of_mut;
+
+macro_rules! impl_tuple_init {
+    ($init_fn:ident, $pin_init_fn:ident, $n:literal: $($idx:tt => $t:ident, $e:ident, $init:ident),*) => {
+        #[doc = concat!("Initializes a tuple with ", $n, " elements by initializing each element via")]
+        /// the given initializer.
+        ///
+        /// The elements are initialized in order. If an initializer fails, the already initialized
+        /// elements are dropped and the error is converted into `E`. The same happens before
+        /// unwinding, if an initializer panics.
+        ///
+        #[doc = concat!("See [`", stringify!($pin_init_fn), "`] for the pinned version.")]
+        #[allow(clippy::too_many_arguments)]
+        pub fn $init_fn<$($t,)* $($e,)* E>(
+            $($init: impl Init<$t, $e>,)*
+        ) -> impl Init<($($t,)*), E>
+        where
+            E: $(From<$e> +)*,
+        {
+            let init = move |slot: *mut ($($t,)*)| {
+                $(
+                    // SAFETY: `slot` is valid, because we are inside of an initializer closure. If
+                    // the initializer fails or panics, the guards of the previous elements drop
+                    // them.
+                    unsafe { Init::__init($init, addr_of_mut!((*slot).$idx))? };
+                    // SAFETY: We forget the guard below when initialization has succeeded.
+                    let $init = unsafe { DropGuard::new(addr_of_mut!((*slot).$idx)) };
+                )*
+                $(::core::mem::forget($init);)*
+                Ok(())
+            };
+            // SAFETY: The closure above initializes every element of the tuple. On failure or panic
+            // it drops any initialized elements.
+            unsafe { init_from_closure(init) }
+        }
+
+        #[doc = concat!("Pin-initializes a tuple with ", $n, " elements by pin-initializing each")]
+        /// element via the given initializer.
+        ///
+        /// The elements are initialized in order. If an initializer fails, the already initialized
+        /// elements are dropped and the error is converted into `E`. The same happens before
+        /// unwinding, if an initializer panics.
+        ///
+        #[doc = concat!("See [`", stringify!($init_fn), "`] for the unpinned version.")]
+        #[allow(clippy::too_many_arguments)]
+        pub fn $pin_init_fn<$($t,)* $($e,)* E>(
+            $($init: impl PinInit<$t, $e>,)*
+        ) -> impl PinInit<($($t,)*), E>
+        where
+            E: $(From<$e> +)*,
+        {
+            let init = move |slot: *mut ($($t,)*)| {
+                $(
+                    // SAFETY: `slot` is valid and pinned, because we are inside of an initializer
+                    // closure. If the initializer fails or panics, the guards of the previous
+                    // elements drop them.
+                    unsafe { PinInit::__pinned_init($init, addr_of_mut!((*slot).$idx))? };
+                    // SAFETY: We forget the guard below when initialization has succeeded.
+                    let $init = unsafe { DropGuard::new(addr_of_mut!((*slot).$idx)) };
+                )*
+                $(::core::mem::forget($init);)*
+                Ok(())
+            };
+            // SAFETY: The closure above initializes every element of the tuple. On failure or panic
+            // it drops any initialized elements.
+            unsafe { pin_init_from_closure(init) }
+        }
+    };
+}
+
+impl_tuple_init!(init_tuple2, pin_init_tuple2, 2:
+    0 => A, EA, a, 1 => B, EB, b);
+impl_tuple_init!(init_tuple3, pin_init_tuple3, 3:
+    0 => A, EA, a, 1 => B, EB, b, 2 => C, EC, c);
+impl_tuple_init!(init_tuple4, pin_init_tuple4, 4:
+    0 => A, EA, a, 1 => B, EB, b, 2 => C, EC, c, 3 => D, ED, d);
+impl_tuple_init!(init_tuple5, pin_init_tuple5, 5:
+    0 => A, EA, a, 1 => B, EB, b, 2 => C, EC, c, 3 => D, ED, d, 4 => F, EF, f);
+impl_tuple_init!(init_tuple6, pin_init_tuple6, 6:
+    0 => A, EA, a, 1 => B, EB, b, 2 => C, EC, c, 3 => D, ED, d, 4 => F, EF, f, 5 => G, EG, g);
+impl_tuple_init!(init_tuple7, pin_init_tuple7, 7:
+    0 => A, EA, a, 1 => B, EB, b, 2 => C, EC, c, 3 => D, ED, d, 4 => F, EF, f, 5 => G, EG, g,
+    6 => H, EH, h);
+impl_tuple_init!(init_tuple8, pin_init_tuple8, 8:
+    0 => A, EA, a, 1 => B, EB, b, 2 => C, EC, c, 3 => D, ED, d, 4 => F, EF, f, 5 => G, EG, g,
+    6 => H, EH, h, 7 => I, EI, i);
@@ -25,3 +25,62 @@ fn too_big_in_place() {
         Err(AllocError)
     ));
 }
+
+#[cfg(feature = "alloc")]
+mod failing {
+    use core::{
+        alloc::{GlobalAlloc, Layout},
+        cell::Cell,
+        ptr,
+    };
+    use std::alloc::System;
+
+    thread_local! {
+        static FAIL: Cell<bool> = const { Cell::new(false) };
+    }
+
+    /// Fails every allocation on the current thread while [`fail_allocations`] is running.
+    struct Failing;
+
+    // SAFETY: Forwards to `System` or returns null, which signals an allocation failure.
+    unsafe impl GlobalAlloc for Failing {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            if FAIL.try_with(Cell::get).unwrap_or(false) {
+                return ptr::null_mut();
+            }
+            // SAFETY: The caller upholds the requirements of `alloc`.
+            unsafe { System.alloc(layout) }
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            // SAFETY: `ptr` has been allocated by `System`.
+            unsafe { System.dealloc(ptr, layout) }
+        }
+    }
+
+    #[global_allocator]
+    static GLOBAL: Failing = Failing;
+
+    pub(crate) fn fail_allocations<R>(f: impl FnOnce() -> R) -> R {
+        FAIL.with(|fail| fail.set(true));
+        let res = f();
+        FAIL.with(|fail| fail.set(false));
+        res
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn erase_alloc_fail() {
+    use core::alloc::AllocError;
+    use pinned_init::*;
+
+    // The initializers capture `value`, so boxing them allocates.
+    let value = 42u64;
+    let init = failing::fail_allocations(|| pin_init_from_fn(move || Ok(value)).erase());
+    assert!(matches!(Box::<u64>::try_pin_init(init), Err(AllocError)));
+    let init = failing::fail_allocations(|| init_from_fn(move || Ok(value)).erase_init());
+    assert!(matches!(Box::<u64>::try_init(init), Err(AllocError)));
+    let init = pin_init_from_fn(move || Ok::<_, AllocError>(value)).erase();
+    assert_eq!(*Box::<u64>::try_pin_init(init).unwrap(), 42);
+}
@@ -0,0 +1,65 @@
+#![cfg_attr(feature = "alloc", feature(allocator_api))]
+
+use core::{marker::PhantomPinned, pin::Pin, ptr};
+use pinned_init::*;
+use std::sync::Arc;
+
+#[expect(unused_attributes)]
+#[path = "../examples/error.rs"]
+mod error;
+use error::Error;
+
+#[pin_data]
+struct Node {
+    value: u32,
+    // Remembers its own address to check that it has not been moved.
+    this: *const Node,
+    #[pin]
+    _pin: PhantomPinned,
+}
+
+impl Node {
+    fn new(value: u32) -> impl PinInit<Self> {
+        pin_init!(&this in Self {
+            value,
+            this: this.as_ptr(),
+            _pin: PhantomPinned,
+        })
+    }
+
+    fn check(self: Pin<&Self>) {
+        assert!(ptr::eq(self.this, &*self));
+    }
+}
+
+struct Graph {
+    shared: Pin<Arc<Node>>,
+    owned: Pin<Box<Node>>,
+}
+
+#[test]
+fn shared_and_owned() {
+    let graph = Box::try_init(try_init!(Graph {
+        shared <- arc_pin_init::<_, _, Error>(Node::new(1)),
+        owned <- box_pin_init::<_, _, Error>(Node::new(2)),
+    }? Error))
+    .unwrap();
+    let other = graph.shared.clone();
+    // The graph is movable, since the nodes live in their own allocations.
+    let graph = *graph;
+    assert_eq!((graph.shared.value, graph.owned.value), (1, 2));
+    graph.shared.as_ref().check();
+    graph.owned.as_ref().check();
+    drop(graph);
+    assert_eq!(other.value, 1);
+    other.as_ref().check();
+}
+
+#[test]
+fn error() {
+    let res = Box::try_init(try_init!(Graph {
+        shared <- arc_pin_init::<_, _, Error>(Node::new(1)),
+        owned <- box_pin_init::<_, _, Error>(pin_init_from_fn::<Node, _>(|| Err(Error))),
+    }? Error));
+    assert!(res.is_err());
+}
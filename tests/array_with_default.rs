@@ -0,0 +1,74 @@
+#![cfg_attr(feature = "alloc", feature(allocator_api))]
+
+use pinned_init::*;
+
+mod common;
+use common::{count_drop, dropped, reset_dropped};
+
+#[expect(unused_attributes)]
+#[path = "../examples/error.rs"]
+mod error;
+use error::Error;
+
+#[derive(Debug, PartialEq)]
+struct Slot(u32);
+
+impl Drop for Slot {
+    fn drop(&mut self) {
+        count_drop();
+    }
+}
+
+fn slot(value: u32) -> impl Init<Slot, Error> {
+    init_from_fn(move || {
+        if value == 0 {
+            Err(Error)
+        } else {
+            Ok(Slot(value))
+        }
+    })
+}
+
+#[test]
+fn prefix_and_default() {
+    stack_try_pin_init!(let array: [Slot; 5] = array_from_fn_with_default(
+        2,
+        |i| slot(10 + i as u32),
+        || slot(1),
+    ));
+    let array = array.unwrap();
+    assert_eq!(
+        array.iter().map(|s| s.0).collect::<Vec<_>>(),
+        [10, 11, 1, 1, 1]
+    );
+}
+
+#[test]
+fn prefix_longer_than_array() {
+    stack_try_pin_init!(let array: [Slot; 3] = array_from_fn_with_default(
+        8,
+        |i| slot(i as u32 + 1),
+        || -> _ { unreachable!() },
+    ));
+    assert_eq!(
+        array.unwrap().iter().map(|s| s.0).collect::<Vec<_>>(),
+        [1, 2, 3]
+    );
+}
+
+#[test]
+fn default_fails() {
+    reset_dropped();
+    let mut defaults = 0;
+    stack_try_pin_init!(let array: [Slot; 6] = array_from_fn_with_default(
+        2,
+        |_| slot(5),
+        || {
+            defaults += 1;
+            slot(if defaults == 3 { 0 } else { 7 })
+        },
+    ));
+    assert_eq!(array.err(), Some(Error));
+    // The two prefix elements and the two defaults before the failing one have been dropped.
+    assert_eq!(dropped(), 4);
+}
@@ -0,0 +1,37 @@
+use core::{
+    ptr,
+    sync::atomic::{AtomicBool, AtomicI8, AtomicPtr, AtomicU64, AtomicUsize, Ordering},
+};
+use pinned_init::*;
+
+#[test]
+fn big_array() {
+    let array: Box<[AtomicU64; 1000]> =
+        Box::init(init_array_from_fn(|i| atomic_init(i as u64 * 3))).unwrap();
+    for (i, a) in array.iter().enumerate() {
+        assert_eq!(a.load(Ordering::Relaxed), i as u64 * 3);
+    }
+}
+
+#[pin_data]
+struct Counters {
+    hits: AtomicUsize,
+    enabled: AtomicBool,
+    delta: AtomicI8,
+    last: AtomicPtr<u8>,
+}
+
+#[test]
+fn fields() {
+    let counters = Box::init(init!(Counters {
+        hits <- atomic_init(7),
+        enabled <- atomic_init(true),
+        delta <- atomic_init(-1),
+        last <- atomic_init(ptr::null_mut()),
+    }))
+    .unwrap();
+    assert_eq!(counters.hits.load(Ordering::Relaxed), 7);
+    assert!(counters.enabled.load(Ordering::Relaxed));
+    assert_eq!(counters.delta.load(Ordering::Relaxed), -1);
+    assert!(counters.last.load(Ordering::Relaxed).is_null());
+}
@@ -0,0 +1,78 @@
+#![cfg_attr(feature = "alloc", feature(allocator_api))]
+
+use core::{marker::PhantomPinned, pin::Pin, ptr};
+use pinned_init::*;
+
+#[expect(unused_attributes)]
+#[path = "../examples/error.rs"]
+mod error;
+use error::Error;
+
+#[pin_data]
+struct Leaf {
+    value: u32,
+    // Remembers its own address to check that it has not been moved.
+    this: *const Leaf,
+    #[pin]
+    _pin: PhantomPinned,
+}
+
+impl Leaf {
+    fn new(value: u32) -> impl PinInit<Self> {
+        pin_init!(&this in Self {
+            value,
+            this: this.as_ptr(),
+            _pin: PhantomPinned,
+        })
+    }
+
+    fn check(self: Pin<&Self>) {
+        assert!(ptr::eq(self.this, &*self));
+    }
+}
+
+#[pin_data]
+struct Branch {
+    left: Pin<Box<Leaf>>,
+    right: Pin<Box<Leaf>>,
+    #[pin]
+    _pin: PhantomPinned,
+}
+
+struct Tree {
+    root: Pin<Box<Branch>>,
+    size: usize,
+}
+
+#[test]
+fn nested() {
+    let tree = Box::try_init(try_init!(Tree {
+        root <- box_pin_init::<_, _, Error>(try_pin_init!(Branch {
+            left <- box_pin_init::<_, _, Error>(Leaf::new(1)),
+            right <- box_pin_init::<_, _, Error>(Leaf::new(2)),
+            _pin: PhantomPinned,
+        }? Error)),
+        size: 2,
+    }? Error))
+    .unwrap();
+    // The tree is movable, since every pinned value lives in its own box.
+    let tree = *tree;
+    assert_eq!(tree.size, 2);
+    assert_eq!((tree.root.left.value, tree.root.right.value), (1, 2));
+    tree.root.left.as_ref().check();
+    tree.root.right.as_ref().check();
+}
+
+#[test]
+fn error() {
+    let fail = pin_init_from_fn::<Leaf, _>(|| Err(Error));
+    let res = Box::try_init(try_init!(Tree {
+        root <- box_pin_init::<_, _, Error>(try_pin_init!(Branch {
+            left <- box_pin_init::<_, _, Error>(Leaf::new(1)),
+            right <- box_pin_init::<_, _, Error>(fail),
+            _pin: PhantomPinned,
+        }? Error)),
+        size: 2,
+    }? Error));
+    assert!(res.is_err());
+}
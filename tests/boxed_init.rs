@@ -0,0 +1,74 @@
+#![cfg_attr(feature = "alloc", feature(allocator_api))]
+
+use core::convert::Infallible;
+use pinned_init::*;
+
+#[expect(unused_attributes)]
+#[path = "../examples/error.rs"]
+mod error;
+use error::Error;
+
+mod common;
+use common::{dropped, reset_dropped, Counted};
+
+const BIG: usize = 1024 * 1024;
+
+#[pin_data]
+struct Inner {
+    header: u64,
+    buf: [u8; BIG],
+}
+
+#[pin_data]
+struct Middle {
+    inner: Box<Inner>,
+    table: Box<[u64; BIG / 8]>,
+}
+
+#[pin_data]
+struct Outer {
+    middle: Box<Middle>,
+    id: usize,
+}
+
+fn inner(header: u64) -> impl Init<Inner, Error> {
+    try_init!(Inner {
+        header,
+        buf <- zeroed(),
+    }? Error)
+}
+
+#[test]
+fn nested_big_structs() {
+    let outer = Box::try_init(try_init!(Outer {
+        middle <- boxed_init::<_, _, Error>(try_init!(Middle {
+            inner <- boxed_init::<_, _, Error>(inner(7)),
+            table <- boxed_init::<_, _, Error>(zeroed()),
+        }? Error)),
+        id: 1,
+    }? Error))
+    .unwrap();
+    assert_eq!(outer.id, 1);
+    assert_eq!(outer.middle.inner.header, 7);
+    assert!(outer.middle.inner.buf.iter().all(|b| *b == 0));
+    assert!(outer.middle.table.iter().all(|x| *x == 0));
+}
+
+#[pin_data]
+struct Pair {
+    first: Box<Counted>,
+    second: Box<Counted>,
+}
+
+#[test]
+fn error_frees_earlier_boxes() {
+    reset_dropped();
+    // SAFETY: The closure does not touch `slot` and returns an error.
+    let fail = unsafe { init_from_closure(|_: *mut Counted| Err::<(), _>(Error)) };
+    let res = Box::try_init(try_init!(Pair {
+        first <- boxed_init::<_, Infallible, Error>(Counted(0)),
+        second <- boxed_init::<_, _, Error>(fail),
+    }? Error));
+    assert!(res.is_err());
+    assert_eq!(dropped(), 1);
+}
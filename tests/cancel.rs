@@ -0,0 +1,86 @@
+#![cfg_attr(feature = "alloc", feature(allocator_api))]
+
+use core::{cell::Cell, convert::Infallible};
+use pinned_init::*;
+
+mod common;
+use common::{dropped, Counted};
+
+#[derive(Debug, PartialEq, Eq)]
+enum Error {
+    Cancelled,
+    Failed,
+}
+
+impl From<Cancelled> for Error {
+    fn from(_: Cancelled) -> Self {
+        Self::Cancelled
+    }
+}
+
+impl From<Infallible> for Error {
+    fn from(e: Infallible) -> Self {
+        match e {}
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<core::alloc::AllocError> for Error {
+    fn from(_: core::alloc::AllocError) -> Self {
+        Self::Failed
+    }
+}
+
+#[pin_data]
+struct Pair {
+    a: Counted,
+    b: Counted,
+}
+
+fn counted(ran: &Cell<bool>) -> impl Init<Counted, Error> + '_ {
+    // SAFETY: The closure always initializes `slot`.
+    unsafe {
+        init_from_closure(move |slot: *mut Counted| {
+            ran.set(true);
+            slot.write(Counted(0));
+            Ok(())
+        })
+    }
+}
+
+#[test]
+fn not_cancelled() {
+    let token = CancelToken::new();
+    let ran = Cell::new(false);
+    stack_try_pin_init!(let val: Counted = counted(&ran).with_cancel::<Error>(&token));
+    assert!(val.is_ok());
+    assert!(ran.get());
+}
+
+#[test]
+fn cancelled_before_start() {
+    let token = CancelToken::new();
+    token.cancel();
+    assert!(token.is_cancelled());
+    let ran = Cell::new(false);
+    stack_try_pin_init!(let val: Counted = counted(&ran).with_cancel::<Error>(&token));
+    assert_eq!(val.err(), Some(Error::Cancelled));
+    assert!(!ran.get());
+}
+
+#[test]
+fn cancelled_between_fields() {
+    let token = CancelToken::new();
+    let ran = Cell::new(false);
+    let before = dropped();
+    stack_try_pin_init!(let val: Pair = try_pin_init!(Pair {
+        a <- counted(&ran).chain(|_| {
+            token.cancel();
+            Ok(())
+        }).with_cancel::<Error>(&token),
+        b <- counted(&ran).with_cancel::<Error>(&token),
+    }? Error));
+    assert_eq!(val.err(), Some(Error::Cancelled));
+    // `a` has been initialized and must have been dropped again.
+    assert_eq!(dropped(), before + 1);
+}
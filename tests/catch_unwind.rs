@@ -0,0 +1,53 @@
+#![cfg(feature = "std")]
+
+use core::convert::Infallible;
+use pinned_init::*;
+
+mod common;
+use common::{dropped, reset_dropped, Counted};
+
+#[pin_data]
+struct Foo {
+    a: Counted,
+    b: Counted,
+    c: usize,
+}
+
+fn panicking() -> impl Init<usize> {
+    // SAFETY: The closure panics before touching `slot`.
+    unsafe { init_from_closure(|_| panic!("initializer panicked")) }
+}
+
+#[test]
+fn panic_in_field() {
+    reset_dropped();
+    stack_try_pin_init!(let res: Foo = pin_init!(Foo {
+        a: Counted(0),
+        b: Counted(1),
+        c <- panicking(),
+    })
+    .catch_unwind());
+    let Err(PanicOrError::Panic(payload)) = res else {
+        panic!("expected a panic");
+    };
+    assert_eq!(
+        payload.downcast_ref::<&str>(),
+        Some(&"initializer panicked")
+    );
+    // `a` and `b` have been initialized and must have been dropped again.
+    assert_eq!(dropped(), 2);
+}
+
+#[test]
+fn error_is_forwarded() {
+    // SAFETY: The closure does not touch `slot` and returns an error.
+    let init = unsafe { init_from_closure::<usize, _>(|_| Err(42u8)) };
+    stack_try_pin_init!(let res: usize = init.catch_unwind());
+    assert!(matches!(res, Err(PanicOrError::Error(42))));
+}
+
+#[test]
+fn no_panic() {
+    stack_try_pin_init!(let res: usize = PinInit::<usize, Infallible>::catch_unwind(42));
+    assert_eq!(*res.unwrap(), 42);
+}
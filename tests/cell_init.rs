@@ -0,0 +1,93 @@
+#![cfg_attr(feature = "alloc", feature(allocator_api))]
+
+use core::{
+    cell::{OnceCell, RefCell},
+    convert::Infallible,
+    mem::MaybeUninit,
+};
+use pinned_init::*;
+
+mod common;
+use common::{count_drop, dropped, reset_dropped};
+
+#[expect(unused_attributes)]
+#[path = "../examples/error.rs"]
+mod error;
+use error::Error;
+
+struct Entry;
+
+impl Drop for Entry {
+    fn drop(&mut self) {
+        count_drop();
+    }
+}
+
+#[pin_data]
+struct Table {
+    entries: RefCell<[u32; 8]>,
+    name: &'static str,
+}
+
+#[test]
+fn unborrowed() {
+    let table: Box<Table> = Box::init(init!(Table {
+        entries <- ref_cell_init::<_, Infallible>(init_array_from_fn(|i| i as u32 * 2)),
+        name: "table",
+    }))
+    .unwrap();
+    assert!(table.entries.try_borrow_mut().is_ok());
+    assert_eq!(table.entries.borrow()[3], 6);
+    {
+        let _first = table.entries.borrow();
+        let _second = table.entries.borrow();
+        assert!(table.entries.try_borrow_mut().is_err());
+    }
+    table.entries.borrow_mut()[0] = 1;
+    assert_eq!(table.entries.borrow()[0], 1);
+    assert_eq!(table.name, "table");
+}
+
+#[test]
+fn ref_cell_error() {
+    reset_dropped();
+    let mut calls = 0;
+    stack_try_pin_init!(let cell: RefCell<[Entry; 4]> = ref_cell_init(init_array_from_fn(|_| {
+        calls += 1;
+        let value = calls;
+        init_from_fn(move || if value == 3 { Err(Error) } else { Ok(Entry) })
+    })));
+    assert!(matches!(cell, Err(Error)));
+    assert_eq!(dropped(), 2);
+}
+
+#[test]
+fn once_cell_error() {
+    let mut cell = MaybeUninit::new(OnceCell::<u32>::new());
+    // SAFETY: `cell` is valid for writes.
+    let res = unsafe { once_cell_init(init_from_fn(|| Err(Error))).__init(cell.as_mut_ptr()) };
+    assert_eq!(res, Err(Error));
+    // SAFETY: `cell` still contains the empty cell, since the initializer failed.
+    let cell = unsafe { cell.assume_init() };
+    assert_eq!(cell.get(), None);
+}
+
+#[test]
+fn once_cell_initialized_once() {
+    let mut calls = 0;
+    let cell: Box<OnceCell<u32>> = Box::init(once_cell_init(init_from_fn(|| {
+        calls += 1;
+        Ok::<_, Infallible>(42)
+    })))
+    .unwrap();
+    assert_eq!(calls, 1);
+    assert_eq!(
+        *cell.get_or_init(|| {
+            calls += 1;
+            0
+        }),
+        42
+    );
+    assert_eq!(cell.set(0), Err(0));
+    assert_eq!(calls, 1);
+}
@@ -0,0 +1,79 @@
+use core::{marker::PhantomPinned, pin::Pin};
+use pinned_init::*;
+
+#[pin_data]
+struct Waiter {
+    id: usize,
+    #[pin]
+    _pin: PhantomPinned,
+}
+
+impl Waiter {
+    fn new(id: usize) -> impl PinInit<Self> {
+        pin_init!(Self {
+            id,
+            _pin: PhantomPinned,
+        })
+    }
+}
+
+#[pin_data]
+struct Queue {
+    #[cfg_attr(feature = "std", pin)]
+    head: Waiter,
+    #[cfg_attr(any(), pin)]
+    #[allow(dead_code)]
+    tail: usize,
+    #[cfg_attr(all(), pin, allow(dead_code))]
+    _marker: (),
+}
+
+#[cfg(feature = "std")]
+assert_not_unpin!(Queue);
+
+#[cfg(feature = "std")]
+#[test]
+fn pinned_with_feature() {
+    stack_pin_init!(let queue = pin_init!(Queue {
+        head <- Waiter::new(7),
+        tail: 0,
+        _marker: (),
+    }));
+    let queue: Pin<&mut Queue> = queue;
+    assert_eq!(queue.head.id, 7);
+}
+
+#[pin_data]
+struct Repeated {
+    #[cfg_attr(feature = "std", pin)]
+    a: Waiter,
+    #[cfg_attr(feature = "std", pin)]
+    b: Waiter,
+    #[cfg_attr(feature = "std", pin)]
+    c: Waiter,
+    #[cfg_attr(feature = "std", pin)]
+    d: Waiter,
+    #[cfg_attr(feature = "std", pin)]
+    e: Waiter,
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn repeated_predicate() {
+    stack_pin_init!(let repeated = pin_init!(Repeated {
+        a <- Waiter::new(1),
+        b <- Waiter::new(2),
+        c <- Waiter::new(3),
+        d <- Waiter::new(4),
+        e <- Waiter::new(5),
+    }));
+    let ids = [
+        &repeated.a,
+        &repeated.b,
+        &repeated.c,
+        &repeated.d,
+        &repeated.e,
+    ]
+    .map(|w| w.id);
+    assert_eq!(ids, [1, 2, 3, 4, 5]);
+}
@@ -0,0 +1,96 @@
+use core::{cell::Cell, marker::PhantomPinned, mem::MaybeUninit, pin::Pin};
+use pinned_init::*;
+
+/// Borrowed input that has to outlive the timer.
+struct Opaque {
+    fired: Cell<u32>,
+}
+
+struct Timer<'a> {
+    opaque: &'a Opaque,
+    this: *const Timer<'a>,
+    _pin: PhantomPinned,
+}
+
+impl<'a> Timer<'a> {
+    fn new(opaque: &'a Opaque) -> impl PinInit<Self> + 'a {
+        // SAFETY: The slot is fully initialized and the reference is only used to get the address.
+        unsafe {
+            pin_init_from_closure_ref(move |slot: &'a mut MaybeUninit<Self>| {
+                let this = slot.as_ptr();
+                slot.write(Timer {
+                    opaque,
+                    this,
+                    _pin: PhantomPinned,
+                });
+                Ok(())
+            })
+        }
+    }
+
+    fn fire(self: Pin<&Self>) {
+        assert_eq!(self.this, &*self as *const Self);
+        self.opaque.fired.set(self.opaque.fired.get() + 1);
+    }
+}
+
+#[test]
+fn borrowed_input() {
+    let opaque = Opaque {
+        fired: Cell::new(0),
+    };
+    let timer = Box::pin_init(Timer::new(&opaque)).unwrap();
+    timer.as_ref().fire();
+    stack_pin_init!(let timer2 = Timer::new(&opaque));
+    timer2.as_ref().fire();
+    drop(timer);
+    assert_eq!(opaque.fired.get(), 2);
+}
+
+/// Self-referential object that borrows an `&'opaque mut u64` that has to outlive it.
+struct Counter<'opaque> {
+    total: &'opaque mut u64,
+    own: u64,
+    ptr_to_own: *const u64,
+    _pin: PhantomPinned,
+}
+
+impl<'opaque> Counter<'opaque> {
+    fn new(total: &'opaque mut u64) -> impl PinInit<Self> + 'opaque {
+        // SAFETY: The slot is fully initialized and the pointer is only used while the value is
+        // alive.
+        unsafe {
+            pin_init_from_closure_to_init(move |slot: ToInit<'opaque, Self>| {
+                let ptr = slot.as_ptr();
+                ptr.write(Counter {
+                    total,
+                    own: 0,
+                    ptr_to_own: core::ptr::addr_of!((*ptr).own),
+                    _pin: PhantomPinned,
+                });
+                Ok(())
+            })
+        }
+    }
+
+    fn add(self: Pin<&mut Self>, val: u64) {
+        // SAFETY: We do not move out of `this`.
+        let this = unsafe { self.get_unchecked_mut() };
+        this.own += val;
+        *this.total += val;
+        // SAFETY: `ptr_to_own` points into `this`, which is pinned.
+        assert_eq!(unsafe { *this.ptr_to_own }, this.own);
+    }
+}
+
+#[test]
+fn to_init_self_referential() {
+    let mut total = 0;
+    {
+        stack_pin_init!(let counter = Counter::new(&mut total));
+        counter.as_mut().add(3);
+        counter.as_mut().add(4);
+        assert_eq!(counter.own, 7);
+    }
+    assert_eq!(total, 7);
+}
@@ -0,0 +1,136 @@
+#![cfg(feature = "std")]
+
+use core::{cell::Cell, convert::Infallible};
+use pinned_init::*;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+mod common;
+use common::{dropped, reset_dropped, Counted};
+
+thread_local! {
+    static CLONED: Cell<usize> = const { Cell::new(0) };
+}
+
+impl Clone for Counted {
+    fn clone(&self) -> Self {
+        if CLONED.with(|c| c.replace(c.get() + 1)) == 2 {
+            panic!("clone panicked");
+        }
+        Self(self.0)
+    }
+}
+
+/// Runs `f`, expecting it to panic, and returns the number of `Counted` values dropped by it.
+fn dropped_by_panic(f: impl FnOnce()) -> usize {
+    reset_dropped();
+    CLONED.with(|c| c.set(0));
+    assert!(catch_unwind(AssertUnwindSafe(f)).is_err());
+    dropped()
+}
+
+#[test]
+fn init_array_from_fn_panic() {
+    let dropped = dropped_by_panic(|| {
+        let _ = Box::init(init_array_from_fn::<_, 8, _, Infallible>(|i| {
+            let init = move |slot: *mut Counted| {
+                assert_ne!(i, 5, "initializer panicked");
+                // SAFETY: `slot` is valid for writes.
+                unsafe { slot.write(Counted(i)) };
+                Ok(())
+            };
+            // SAFETY: The closure initializes `slot` when it returns.
+            unsafe { init_from_closure(init) }
+        }));
+    });
+    assert_eq!(dropped, 5);
+}
+
+#[test]
+fn init_array_from_fn_make_init_panic() {
+    let dropped = dropped_by_panic(|| {
+        let _ = Box::init(init_array_from_fn::<_, 8, _, Infallible>(|i| {
+            assert_ne!(i, 3, "make_init panicked");
+            Counted(i)
+        }));
+    });
+    assert_eq!(dropped, 3);
+}
+
+#[test]
+fn pin_init_array_from_fn_panic() {
+    let dropped = dropped_by_panic(|| {
+        let _ = Box::pin_init(pin_init_array_from_fn::<_, 8, _, Infallible>(|i| {
+            assert_ne!(i, 6, "make_init panicked");
+            Counted(i)
+        }));
+    });
+    assert_eq!(dropped, 6);
+}
+
+#[test]
+fn repeat_init_panic() {
+    // The third clone panics, the two earlier clones and `elem` itself are dropped.
+    let dropped = dropped_by_panic(|| {
+        let _ = Box::init(repeat_init::<_, 8>(Counted(0)));
+    });
+    assert_eq!(dropped, 3);
+}
+
+#[test]
+fn array_from_fn_with_default_panic() {
+    let dropped = dropped_by_panic(|| {
+        let mut defaults = 0;
+        let _ = Box::init(array_from_fn_with_default::<_, 8, _, Infallible>(
+            2,
+            Counted,
+            || {
+                defaults += 1;
+                assert_ne!(defaults, 3, "default panicked");
+                Counted(0)
+            },
+        ));
+    });
+    // The two prefix elements and the first two defaults are dropped.
+    assert_eq!(dropped, 4);
+}
+
+#[test]
+fn chain_panic() {
+    let dropped = dropped_by_panic(|| {
+        let _ = Box::<Counted>::init(<Counted as Init<_, Infallible>>::chain(Counted(0), |_| {
+            panic!("chain panicked")
+        }));
+    });
+    assert_eq!(dropped, 1);
+}
+
+#[test]
+fn pin_chain_panic() {
+    let dropped = dropped_by_panic(|| {
+        let _ = Box::<Counted>::pin_init(<Counted as PinInit<_, Infallible>>::pin_chain(
+            Counted(0),
+            |_| panic!("pin_chain panicked"),
+        ));
+    });
+    assert_eq!(dropped, 1);
+}
+
+#[test]
+fn init_tuple2_panic() {
+    let dropped = dropped_by_panic(|| {
+        let first = init_from_fn::<Counted, Infallible>(|| Ok(Counted(0)));
+        let second = init_from_fn::<Counted, Infallible>(|| panic!("initializer panicked"));
+        let _ = Box::init(init_tuple2::<_, _, _, _, Infallible>(first, second));
+    });
+    assert_eq!(dropped, 1);
+}
+
+#[test]
+fn pin_init_tuple2_panic() {
+    let dropped = dropped_by_panic(|| {
+        let first = pin_init_from_fn::<Counted, Infallible>(|| Ok(Counted(0)));
+        let second = pin_init_from_fn::<Counted, Infallible>(|| panic!("initializer panicked"));
+        let _ = Box::pin_init(pin_init_tuple2::<_, _, _, _, Infallible>(first, second));
+    });
+    assert_eq!(dropped, 1);
+}
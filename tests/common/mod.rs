@@ -0,0 +1,38 @@
+//! Helpers that are shared between the integration tests.
+//!
+//! Not every test uses every helper, so dead code is allowed in this module.
+#![allow(dead_code)]
+
+use core::cell::Cell;
+use pinned_init::Zeroable;
+
+thread_local! {
+    static DROPPED: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Records every drop in the counter of the current thread, see [`dropped`].
+///
+/// The counter is thread-local, so tests running in parallel do not observe each other's drops.
+#[derive(Debug, Zeroable)]
+pub struct Counted(pub usize);
+
+impl Drop for Counted {
+    fn drop(&mut self) {
+        count_drop();
+    }
+}
+
+/// Increments the drop counter of the current thread, for types that also want to be counted.
+pub fn count_drop() {
+    DROPPED.with(|d| d.set(d.get() + 1));
+}
+
+/// Resets the drop counter of the current thread.
+pub fn reset_dropped() {
+    DROPPED.with(|d| d.set(0));
+}
+
+/// Returns the number of drops on the current thread since the last call to [`reset_dropped`].
+pub fn dropped() -> usize {
+    DROPPED.with(Cell::get)
+}
@@ -0,0 +1,46 @@
+use pinned_init::*;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Entry {
+    key: u32,
+    flags: u8,
+}
+
+#[pin_data]
+struct Table {
+    id: usize,
+    entries: [Entry; 64],
+}
+
+impl Table {
+    fn new<'a>(id: usize, template: &'a [Entry; 64]) -> impl Init<Self> + 'a {
+        init!(Self {
+            id,
+            entries <- copy_init(template),
+        })
+    }
+}
+
+#[test]
+fn from_borrowed_template() {
+    let mut template = [Entry { key: 0, flags: 1 }; 64];
+    for (i, entry) in template.iter_mut().enumerate() {
+        entry.key = i as u32;
+    }
+    let a: Box<Table> = Box::init(Table::new(1, &template)).unwrap();
+    let b: Box<Table> = Box::init(Table::new(2, &template)).unwrap();
+    // The template is still usable and the tables are independent copies.
+    template[0].flags = 0;
+    assert_eq!((a.id, b.id), (1, 2));
+    assert_eq!(a.entries[10], Entry { key: 10, flags: 1 });
+    assert_eq!(a.entries, b.entries);
+    assert_eq!(a.entries[1..], template[1..]);
+    assert_ne!(a.entries[0], template[0]);
+}
+
+#[test]
+fn on_stack() {
+    let value = 0x1234_5678u64;
+    stack_pin_init!(let copy = copy_init(&value));
+    assert_eq!(*copy, value);
+}
@@ -0,0 +1,64 @@
+#![cfg(feature = "cyclic")]
+
+use core::{cell::RefCell, convert::Infallible, marker::PhantomPinned, pin::Pin, ptr};
+use pinned_init::*;
+use std::sync::{Arc, Weak};
+
+#[pin_data]
+struct Node {
+    me: Weak<Node>,
+    value: usize,
+    #[pin]
+    pin: PhantomPinned,
+}
+
+#[test]
+fn self_reference() {
+    let node: Pin<Arc<Node>> = Arc::pin_init_cyclic(|me: &Weak<Node>| {
+        let me = me.clone();
+        pin_init!(Node {
+            me,
+            value: 42,
+            pin: PhantomPinned,
+        })
+    })
+    .unwrap_or_else(|e: Infallible| match e {});
+    let me = node.me.upgrade().unwrap();
+    assert!(ptr::eq(&*me, &*node));
+    assert_eq!(me.value, 42);
+    assert_eq!(Arc::strong_count(&me), 2);
+    assert_eq!(Arc::weak_count(&me), 1);
+}
+
+#[test]
+fn no_upgrade_during_init() {
+    let node: Pin<Arc<Node>> = Arc::pin_init_cyclic(|me: &Weak<Node>| {
+        let me = me.clone();
+        pin_init!(Node {
+            value: usize::from(me.upgrade().is_none()),
+            me,
+            pin: PhantomPinned,
+        })
+    })
+    .unwrap_or_else(|e: Infallible| match e {});
+    assert_eq!(node.value, 1);
+}
+
+thread_local! {
+    static ESCAPED: RefCell<Weak<Node>> = const { RefCell::new(Weak::new()) };
+}
+
+#[test]
+fn error() {
+    let res = Arc::pin_init_cyclic(|me: &Weak<Node>| {
+        ESCAPED.with(|e| *e.borrow_mut() = me.clone());
+        let me = me.clone();
+        try_pin_init!(Node {
+            me,
+            value: Err(())?,
+            pin: PhantomPinned,
+        }?())
+    });
+    assert!(res.is_err());
+    assert!(ESCAPED.with(|e| e.borrow().upgrade()).is_none());
+}
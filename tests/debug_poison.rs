@@ -0,0 +1,45 @@
+#![cfg(feature = "debug-poison")]
+
+use core::{convert::Infallible, mem::MaybeUninit};
+use pinned_init::*;
+use std::sync::Arc;
+
+#[pin_data]
+struct Packet {
+    a: u64,
+    b: [u8; 7],
+    c: MaybeUninit<[u8; 16]>,
+}
+
+fn packet() -> impl Init<Packet> {
+    init!(Packet {
+        a: 42,
+        b: [1; 7],
+        c <- uninit::<_, Infallible>(),
+    })
+}
+
+fn check(packet: &Packet) {
+    assert_eq!(packet.a, 42);
+    assert_eq!(packet.b, [1; 7]);
+    // SAFETY: The slot has been poisoned, so these bytes have been written.
+    assert_eq!(unsafe { packet.c.assume_init() }, [0xAA; 16]);
+}
+
+#[test]
+fn boxed() {
+    check(&Box::init(packet()).unwrap());
+    check(&Box::pin_init(packet()).unwrap());
+}
+
+#[test]
+fn arc() {
+    check(&Arc::init(packet()).unwrap());
+    check(&Arc::pin_init(packet()).unwrap());
+}
+
+#[test]
+fn stack() {
+    stack_pin_init!(let value = packet());
+    check(&value);
+}
@@ -0,0 +1,125 @@
+#![cfg_attr(feature = "alloc", feature(allocator_api))]
+
+use core::{cell::Cell, marker::PhantomPinned, pin::Pin};
+use pinned_init::*;
+use std::rc::Rc;
+
+#[expect(unused_attributes)]
+#[path = "../examples/error.rs"]
+mod error;
+use error::Error;
+
+#[derive(Default)]
+struct Config {
+    name: String,
+    retries: u32,
+    verbose: bool,
+    tags: Vec<String>,
+}
+
+#[test]
+fn default_fields() {
+    let config = Box::init(init!(Config {
+        retries: 3,
+        ..Default::default()
+    }))
+    .unwrap();
+    assert_eq!(config.name, "");
+    assert_eq!(config.retries, 3);
+    assert!(!config.verbose);
+    assert!(config.tags.is_empty());
+}
+
+#[test]
+fn overwrite_string() {
+    let config = Box::init(init!(Config {
+        name: "foo".to_owned(),
+        tags <- init_from_fn::<_, core::convert::Infallible>(|| Ok(vec!["bar".to_owned()])),
+        ..Default::default()
+    }))
+    .unwrap();
+    assert_eq!(config.name, "foo");
+    assert_eq!(config.tags, ["bar"]);
+    assert_eq!(config.retries, 0);
+}
+
+/// Counts how many values are alive.
+#[derive(Clone)]
+struct Tracked(Rc<Cell<isize>>);
+
+thread_local! {
+    static ALIVE: Rc<Cell<isize>> = Rc::new(Cell::new(0));
+}
+
+impl Default for Tracked {
+    fn default() -> Self {
+        ALIVE.with(Tracked::new)
+    }
+}
+
+impl Tracked {
+    fn new(alive: &Rc<Cell<isize>>) -> Self {
+        alive.set(alive.get() + 1);
+        Self(alive.clone())
+    }
+}
+
+impl Drop for Tracked {
+    fn drop(&mut self) {
+        self.0.set(self.0.get() - 1);
+    }
+}
+
+#[pin_data]
+#[derive(Default)]
+struct Pinned {
+    a: Tracked,
+    b: Tracked,
+    c: Tracked,
+    #[pin]
+    pin: PhantomPinned,
+}
+
+fn failing() -> impl Init<Tracked, Error> {
+    // SAFETY: The initializer fails without touching the slot.
+    unsafe { init_from_closure(|_| Err(Error)) }
+}
+
+#[test]
+fn failure_drops_everything() {
+    let alive = ALIVE.with(Rc::clone);
+    {
+        let a = Tracked::new(&alive);
+        let res: Result<Pin<Box<Pinned>>, Error> = Box::try_pin_init(try_pin_init!(Pinned {
+            a,
+            b <- failing(),
+            ..Default::default()
+        }? Error));
+        assert!(res.is_err());
+    }
+    assert_eq!(alive.get(), 0);
+    {
+        let b = Tracked::new(&alive);
+        let val = Box::pin_init(pin_init!(Pinned {
+            b,
+            ..Default::default()
+        }))
+        .unwrap();
+        assert_eq!(alive.get(), 3);
+        drop(val);
+    }
+    assert_eq!(alive.get(), 0);
+}
+
+#[test]
+fn early_return_drops_everything() {
+    let alive = ALIVE.with(Rc::clone);
+    let a = Tracked::new(&alive);
+    let res: Result<Box<Pinned>, Error> = Box::try_init(try_init!(Pinned {
+        a,
+        b: Err(Error)?,
+        ..Default::default()
+    }? Error));
+    assert!(res.is_err());
+    assert_eq!(alive.get(), 0);
+}
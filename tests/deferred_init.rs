@@ -0,0 +1,79 @@
+#![cfg_attr(feature = "alloc", feature(allocator_api))]
+#![cfg(any(feature = "std", feature = "alloc"))]
+
+use core::{cell::Cell, pin::pin};
+use pinned_init::*;
+
+#[expect(unused_attributes)]
+#[path = "../examples/mutex.rs"]
+mod mutex;
+use mutex::*;
+
+mod common;
+use common::{dropped, reset_dropped, Counted};
+
+thread_local! {
+    static INITS: Cell<usize> = const { Cell::new(0) };
+}
+
+fn counted(val: usize) -> impl PinInit<Counted> {
+    INITS.with(|c| c.set(0));
+    reset_dropped();
+    // SAFETY: The slot is always initialized.
+    unsafe {
+        pin_init_from_closure(move |slot: *mut Counted| {
+            INITS.with(|c| c.set(c.get() + 1));
+            slot.write(Counted(val));
+            Ok(())
+        })
+    }
+}
+
+#[test]
+fn runs_once() {
+    {
+        let deferred = pin!(DeferredInit::new(counted(7)));
+        assert_eq!(INITS.with(Cell::get), 0);
+        assert!(deferred.get().is_none());
+        assert_eq!(deferred.as_ref().get_or_init().0, 7);
+        assert_eq!(deferred.as_ref().get_or_init().0, 7);
+        assert_eq!(deferred.get().map(|c| c.0), Some(7));
+        assert_eq!(INITS.with(Cell::get), 1);
+    }
+    assert_eq!(dropped(), 1);
+}
+
+#[test]
+fn never_accessed() {
+    drop(DeferredInit::new(counted(7)));
+    assert_eq!(INITS.with(Cell::get), 0);
+    assert_eq!(dropped(), 0);
+}
+
+#[test]
+fn movable_before_init() {
+    let deferred = DeferredInit::new(CMutex::new(0usize));
+    let deferred = Box::pin(deferred);
+    *deferred.as_ref().get_or_init().lock() += 1;
+    assert_eq!(*deferred.as_ref().get_or_init().lock(), 1);
+}
+
+#[pin_data]
+struct Device {
+    id: u32,
+    #[pin]
+    state: DeferredInit<CMutex<u32>>,
+}
+
+#[test]
+fn in_struct() {
+    let dev = Box::pin_init(pin_init!(Device {
+        id: 3,
+        state: DeferredInit::new(CMutex::new(5)),
+    }))
+    .unwrap();
+    // SAFETY: `state` is structurally pinned.
+    let state = unsafe { dev.as_ref().map_unchecked(|d| &d.state) };
+    assert_eq!(*state.get_or_init().lock(), 5);
+    assert_eq!(dev.id, 3);
+}
@@ -0,0 +1,80 @@
+#![cfg_attr(feature = "alloc", feature(allocator_api))]
+
+use core::{cell::RefCell, pin::Pin};
+use pinned_init::*;
+
+#[expect(unused_attributes)]
+#[path = "../examples/error.rs"]
+mod error;
+use error::Error;
+
+thread_local! {
+    static DROPPED: RefCell<Vec<&'static str>> = const { RefCell::new(Vec::new()) };
+}
+
+struct Tracked(&'static str);
+
+impl Drop for Tracked {
+    fn drop(&mut self) {
+        DROPPED.with(|d| d.borrow_mut().push(self.0));
+    }
+}
+
+fn dropped() -> Vec<&'static str> {
+    DROPPED.with(|d| d.take())
+}
+
+fn fail() -> impl Init<Tracked, Error> {
+    // SAFETY: The slot is never initialized and we return an error.
+    unsafe { init_from_closure(|_| Err(Error)) }
+}
+
+#[pin_data]
+struct Triple {
+    a: Tracked,
+    #[pin]
+    b: Tracked,
+    c: Tracked,
+}
+
+#[test]
+fn init_failure() {
+    dropped();
+    stack_try_pin_init!(let triple = try_init!(Triple {
+        a: Tracked("a"),
+        b: Tracked("b"),
+        c <- fail(),
+    }? Error));
+    assert_eq!(triple.err(), Some(Error));
+    // The already initialized fields are dropped in reverse order, each exactly once.
+    assert_eq!(dropped(), ["b", "a"]);
+}
+
+#[test]
+fn pin_init_failure() {
+    dropped();
+    stack_try_pin_init!(let triple = try_pin_init!(Triple {
+        a: Tracked("a"),
+        b <- fail(),
+        c: Tracked("c"),
+    }? Error));
+    assert_eq!(triple.err(), Some(Error));
+    assert_eq!(dropped(), ["a"]);
+}
+
+#[test]
+fn success() {
+    dropped();
+    {
+        stack_try_pin_init!(let triple: Triple = try_pin_init!(Triple {
+            a: Tracked("a"),
+            b: Tracked("b"),
+            c: Tracked("c"),
+        }? Error));
+        let triple: Pin<&mut Triple> = triple.unwrap();
+        assert_eq!(triple.c.0, "c");
+        // The guards have been dismissed, so nothing is dropped before the struct itself is.
+        assert!(dropped().is_empty());
+    }
+    assert_eq!(dropped(), ["a", "b", "c"]);
+}
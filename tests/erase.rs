@@ -0,0 +1,130 @@
+#![cfg_attr(feature = "alloc", feature(allocator_api))]
+
+use core::{marker::PhantomPinned, pin::Pin};
+use pinned_init::*;
+
+#[expect(unused_attributes)]
+#[path = "../examples/mutex.rs"]
+mod mutex;
+use mutex::*;
+
+#[expect(unused_attributes)]
+#[path = "../examples/error.rs"]
+mod error;
+use error::Error;
+
+mod common;
+use common::{dropped, reset_dropped, Counted};
+
+fn alloc_mutex(init: ErasedPinInit<'_, CMutex<usize>>) -> Pin<Box<CMutex<usize>>> {
+    Box::try_pin_init(init).unwrap()
+}
+
+#[test]
+fn same_type_for_different_initializers() {
+    let inits = [
+        pin_infallible(CMutex::new(1)).pin_chain(|_| Ok(())).erase(),
+        pin_infallible(CMutex::new(2)).erase(),
+        pin_infallible(CMutex::new(3)).erase(),
+    ];
+    let sum: usize = inits
+        .into_iter()
+        .map(|init| *alloc_mutex(init).lock())
+        .sum();
+    assert_eq!(sum, 6);
+}
+
+#[test]
+fn pinned_error_is_forwarded() {
+    // SAFETY: The initializer fails without touching the slot.
+    let init = unsafe { pin_init_from_closure::<CMutex<usize>, _>(|_| Err(Error)) }.erase();
+    assert!(matches!(
+        Box::<CMutex<usize>>::try_pin_init(init),
+        Err(Error)
+    ));
+}
+
+#[test]
+fn borrowed() {
+    let value = 42;
+    let val = &value;
+    let init = infallible(init_from_closure_ok(move || *val)).erase_init();
+    let b = Box::<usize>::try_init::<Error>(init).unwrap();
+    assert_eq!(*b, 42);
+}
+
+fn init_from_closure_ok(f: impl FnOnce() -> usize) -> impl Init<usize> {
+    // SAFETY: The closure always initializes `slot`.
+    unsafe {
+        init_from_closure(move |slot: *mut usize| {
+            slot.write(f());
+            Ok(())
+        })
+    }
+}
+
+#[test]
+fn erased_init_is_init() {
+    reset_dropped();
+    let init: ErasedInit<'_, Counted> = Counted(7).erase_init();
+    let mut slot = core::mem::MaybeUninit::<Counted>::uninit();
+    // SAFETY: `slot` is valid for writes and not pinned.
+    unsafe { init.__init(slot.as_mut_ptr()).unwrap() };
+    // SAFETY: `slot` was initialized above.
+    let value = unsafe { slot.assume_init() };
+    assert_eq!(value.0, 7);
+    drop(value);
+    assert_eq!(dropped(), 1);
+}
+
+#[test]
+fn error_is_forwarded() {
+    reset_dropped();
+    let init = Init::<Counted, Error>::chain(Counted(0), |_| Err(Error)).erase_init();
+    assert!(matches!(Box::<Counted>::try_init(init), Err(Error)));
+    assert_eq!(dropped(), 1);
+}
+
+#[pin_data]
+struct Device {
+    id: usize,
+    name: &'static str,
+    #[pin]
+    _pin: PhantomPinned,
+}
+
+fn probe(id: usize) -> impl PinInit<Device, Error> {
+    try_pin_init!(Device {
+        id,
+        name: "probed",
+        _pin: PhantomPinned,
+    }? Error)
+}
+
+fn fail() -> impl PinInit<Device, Error> {
+    // SAFETY: The initializer fails without touching the slot.
+    unsafe { pin_init_from_closure(|_| Err(Error)) }
+}
+
+#[test]
+fn registry() {
+    let registry: Vec<Box<dyn PinInitDyn<Device, Error>>> = vec![
+        boxed_pin_init(probe(0)),
+        boxed_pin_init(try_pin_init!(Device {
+            id: 1,
+            name: "static",
+            _pin: PhantomPinned,
+        }? Error)),
+        boxed_pin_init(fail()),
+    ];
+    let devices: Vec<Result<Pin<Box<Device>>, Error>> = registry
+        .into_iter()
+        .map(|init| Box::try_pin_init(init.into_pin_init()))
+        .collect();
+    assert_eq!(devices.len(), 3);
+    let dev = devices[0].as_ref().unwrap();
+    assert_eq!((dev.id, dev.name), (0, "probed"));
+    let dev = devices[1].as_ref().unwrap();
+    assert_eq!((dev.id, dev.name), (1, "static"));
+    assert!(devices[2].is_err());
+}
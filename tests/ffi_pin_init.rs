@@ -0,0 +1,124 @@
+use core::{cell::Cell, pin::Pin};
+use pinned_init::*;
+
+/// A mock of a C API, that records every call.
+#[allow(
+    non_camel_case_types,
+    clippy::missing_safety_doc,
+    clippy::undocumented_unsafe_blocks
+)]
+mod bindings {
+    use core::cell::Cell;
+
+    thread_local! {
+        pub static CALLS: Cell<[usize; 3]> = const { Cell::new([0; 3]) };
+    }
+
+    fn record(idx: usize) {
+        CALLS.with(|c| {
+            let mut calls = c.get();
+            calls[idx] += 1;
+            c.set(calls);
+        });
+    }
+
+    pub struct device {
+        pub this: *const device,
+        pub flags: u32,
+    }
+
+    pub unsafe fn device_init(ptr: *mut device) {
+        record(0);
+        unsafe {
+            ptr.write(device {
+                this: ptr,
+                flags: 0,
+            })
+        };
+    }
+
+    pub unsafe fn device_destroy(ptr: *mut device) {
+        record(1);
+        // The device must not have been moved.
+        assert_eq!(unsafe { (*ptr).this }, ptr.cast_const());
+    }
+
+    pub unsafe fn device_enable(ptr: *mut device, flags: u32, strict: bool) -> i32 {
+        record(2);
+        if strict && flags == 0 {
+            return -22;
+        }
+        unsafe { (*ptr).flags = flags };
+        0
+    }
+
+    pub struct timer {
+        pub ticks: u64,
+    }
+
+    pub unsafe fn timer_init(ptr: *mut timer) {
+        unsafe { ptr.write(timer { ticks: 0 }) };
+    }
+
+    pub unsafe fn timer_destroy(_ptr: *mut timer) {
+        record(1);
+    }
+}
+
+use bindings::CALLS;
+
+ffi_pin_init! {
+    struct Device(bindings::device);
+    // SAFETY: The mock functions initialize and clean up the device in place.
+    unsafe {
+        init = bindings::device_init;
+        destroy = bindings::device_destroy;
+        enable(flags: u32, strict: bool) = bindings::device_enable;
+    }
+}
+
+ffi_pin_init! {
+    struct Timer(bindings::timer);
+    // SAFETY: The mock functions initialize and clean up the timer in place.
+    unsafe {
+        init = bindings::timer_init;
+        destroy = bindings::timer_destroy;
+    }
+}
+
+fn calls() -> [usize; 3] {
+    CALLS.with(Cell::take)
+}
+
+#[test]
+fn enabled() {
+    calls();
+    {
+        stack_try_pin_init!(let dev = Device::new(3, true));
+        let dev: Pin<&mut Device> = dev.unwrap();
+        // SAFETY: The device is initialized.
+        assert_eq!(unsafe { (*dev.as_raw()).flags }, 3);
+        assert_eq!(calls(), [1, 0, 1]);
+    }
+    assert_eq!(calls(), [0, 1, 0]);
+}
+
+#[test]
+fn enable_fails() {
+    calls();
+    stack_try_pin_init!(let dev = Device::new(0, true));
+    assert_eq!(dev.err(), Some(-22));
+    // The device is destroyed after enabling failed.
+    assert_eq!(calls(), [1, 1, 1]);
+}
+
+#[test]
+fn without_enable() {
+    calls();
+    {
+        stack_pin_init!(let timer = Timer::new());
+        // SAFETY: The timer is initialized.
+        assert_eq!(unsafe { (*timer.as_raw()).ticks }, 0);
+    }
+    assert_eq!(calls(), [0, 1, 0]);
+}
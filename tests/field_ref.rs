@@ -0,0 +1,74 @@
+use pinned_init::*;
+
+#[pin_data]
+struct Buffer {
+    len: usize,
+    data: Vec<u8>,
+    #[pin]
+    cap: usize,
+}
+
+#[test]
+fn earlier_fields() {
+    let buf = Box::pin_init(pin_init!(Buffer {
+        len: 16,
+        data: vec![0; *field_ref!(len)],
+        cap: field_ref!(data).capacity(),
+    }))
+    .unwrap();
+    assert_eq!(buf.data.len(), 16);
+    assert!(buf.cap >= 16);
+}
+
+#[pin_data]
+struct Outer {
+    id: u32,
+    #[pin]
+    inner: Inner,
+}
+
+#[pin_data]
+struct Inner {
+    parent: u32,
+}
+
+#[test]
+fn nested() {
+    let outer = Box::pin_init(pin_init!(Outer {
+        id: 7,
+        inner <- pin_init!(Inner {
+            parent: *field_ref!(id),
+        }),
+    }))
+    .unwrap();
+    assert_eq!(outer.inner.parent, 7);
+}
+
+#[derive(Zeroable, Default)]
+struct Pair {
+    a: u64,
+    b: u64,
+    c: u64,
+}
+
+#[test]
+fn zeroed() {
+    let pair = Box::init(init!(Pair {
+        a: 3,
+        b: *field_ref!(a) * 2,
+        ..Zeroable::zeroed()
+    }))
+    .unwrap();
+    assert_eq!((pair.a, pair.b, pair.c), (3, 6, 0));
+}
+
+#[test]
+fn default() {
+    let pair = Box::init(init!(Pair {
+        b: 5,
+        a: *field_ref!(b) + 1,
+        ..Default::default()
+    }))
+    .unwrap();
+    assert_eq!((pair.a, pair.b, pair.c), (6, 5, 0));
+}
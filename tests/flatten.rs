@@ -0,0 +1,66 @@
+use core::{cell::Cell, marker::PhantomPinned};
+use pinned_init::*;
+
+#[derive(Debug, PartialEq)]
+enum Error {
+    Outer,
+    Inner,
+}
+
+thread_local! {
+    static BUILT: Cell<usize> = const { Cell::new(0) };
+}
+
+#[pin_data]
+struct Node {
+    value: usize,
+    #[pin]
+    pin: PhantomPinned,
+}
+
+struct Builder {
+    value: usize,
+}
+
+impl Builder {
+    /// Produces the initializer of the `Node`, this fails for odd values.
+    fn build(self) -> impl PinInit<Node, Error> {
+        try_pin_init!(Node {
+            value: if self.value.is_multiple_of(2) {
+                self.value
+            } else {
+                return Err(Error::Inner);
+            },
+            pin: PhantomPinned,
+        }? Error)
+    }
+}
+
+/// Initializes a `Node` with the initializer created in place by the builder, which fails for
+/// `None`.
+fn init_node(value: Option<usize>) -> Result<usize, Error> {
+    let outer = init_from_fn(move || {
+        BUILT.with(|b| b.set(b.get() + 1));
+        let value = value.ok_or(Error::Outer)?;
+        Ok(Builder { value }.build())
+    });
+    stack_try_pin_init!(let node = flatten_pin_init(outer));
+    node.map(|node| node.value)
+}
+
+#[test]
+fn success() {
+    assert_eq!(init_node(Some(42)), Ok(42));
+}
+
+#[test]
+fn outer_error() {
+    BUILT.with(|b| b.set(0));
+    assert_eq!(init_node(None), Err(Error::Outer));
+    assert_eq!(BUILT.with(Cell::get), 1);
+}
+
+#[test]
+fn inner_error() {
+    assert_eq!(init_node(Some(7)), Err(Error::Inner));
+}
@@ -0,0 +1,54 @@
+#![cfg_attr(feature = "alloc", feature(allocator_api))]
+
+use core::fmt;
+use pinned_init::*;
+
+#[expect(unused_attributes)]
+#[path = "../examples/error.rs"]
+mod error;
+use error::Error;
+
+struct Device {
+    id: u32,
+    name: String,
+    path: String,
+}
+
+#[test]
+fn formatted_fields() {
+    let id = 3;
+    let dev = Box::init(init!(Device {
+        id,
+        name <- fmt_init(format_args!("dev{id}")),
+        path <- fmt_init(format_args!(
+            "/sys/devices/{}/{:02x}",
+            field_ref!(name),
+            id + 10
+        )),
+    }))
+    .unwrap();
+    assert_eq!(dev.id, 3);
+    assert_eq!(dev.name, "dev3");
+    assert_eq!(dev.path, "/sys/devices/dev3/0d");
+}
+
+#[test]
+fn reserved_capacity() {
+    let s = Box::init(string_init(64, |s| s.push_str("hello"))).unwrap();
+    assert_eq!(*s, "hello");
+    assert!(s.capacity() >= 64);
+}
+
+struct Failing;
+
+impl fmt::Display for Failing {
+    fn fmt(&self, _: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Err(fmt::Error)
+    }
+}
+
+#[test]
+#[should_panic = "formatting trait implementation returned an error"]
+fn display_error_panics() {
+    let _ = Box::<String>::try_init::<Error>(infallible(fmt_init(format_args!("{}", Failing))));
+}
@@ -0,0 +1,72 @@
+use pinned_init::*;
+
+#[derive(Zeroable, FromBytes, Debug, PartialEq)]
+#[repr(C)]
+struct Header {
+    magic: [u8; 4],
+    len: u16,
+    flags: u16,
+}
+
+#[derive(Zeroable, FromBytes)]
+struct Packet<T> {
+    header: Header,
+    payload: T,
+}
+
+fn wire(len: u16, flags: u16) -> Vec<u8> {
+    [&b"PINI"[..], &len.to_ne_bytes(), &flags.to_ne_bytes()].concat()
+}
+
+#[test]
+fn exact_size() {
+    let bytes = wire(12, 3);
+    stack_try_pin_init!(let header: Header = from_bytes_init(&bytes));
+    let header = header.unwrap();
+    assert_eq!(
+        *header,
+        Header {
+            magic: *b"PINI",
+            len: 12,
+            flags: 3,
+        }
+    );
+}
+
+#[test]
+fn wrong_size() {
+    let bytes = wire(12, 3);
+    stack_try_pin_init!(let header: Header = from_bytes_init(&bytes[..7]));
+    assert_eq!(
+        header.err(),
+        Some(SizeError {
+            expected: 8,
+            found: 7,
+        })
+    );
+    let mut long = bytes.clone();
+    long.push(0);
+    stack_try_pin_init!(let header: Header = from_bytes_init(&long));
+    assert_eq!(header.err().map(|e| e.found), Some(9));
+}
+
+#[test]
+fn unaligned() {
+    let mut bytes = vec![0];
+    bytes.extend_from_slice(&0x1234_5678u32.to_ne_bytes());
+    stack_try_pin_init!(let val: u32 = from_bytes_init(&bytes[1..]));
+    assert_eq!(*val.unwrap(), 0x1234_5678);
+}
+
+#[test]
+fn generic_field() {
+    let mut bytes = wire(4, 0);
+    bytes.extend_from_slice(&[1, 2, 3, 4]);
+    stack_try_pin_init!(let packet = try_init!(Packet {
+        header <- from_bytes_init(&bytes[..8]),
+        payload <- from_bytes_init::<[u8; 4]>(&bytes[8..]),
+    }? SizeError));
+    let packet = packet.unwrap();
+    assert_eq!(packet.header.len, 4);
+    assert_eq!(packet.payload, [1, 2, 3, 4]);
+}
@@ -0,0 +1,51 @@
+use core::cell::Cell;
+use pinned_init::*;
+
+#[derive(Zeroable, Clone, Copy, Debug, PartialEq)]
+struct Entry {
+    key: u64,
+    value: u32,
+}
+
+struct Header {
+    len: usize,
+    flags: u32,
+}
+
+#[test]
+fn header_and_tail() {
+    let (header, tail) =
+        alloc_header_with_zeroed_tail::<Header, Entry>(init!(Header { len: 100, flags: 7 }), 100)
+            .unwrap();
+    assert_eq!((header.len, header.flags), (100, 7));
+    assert_eq!(tail.len(), 100);
+    assert!(tail.iter().all(|e| *e == Entry { key: 0, value: 0 }));
+}
+
+#[test]
+fn empty_tail() {
+    let (header, tail) =
+        alloc_header_with_zeroed_tail::<Header, u8>(init!(Header { len: 0, flags: 0 }), 0).unwrap();
+    assert_eq!(header.len, 0);
+    assert!(tail.is_empty());
+}
+
+thread_local! {
+    static INITS: Cell<usize> = const { Cell::new(0) };
+}
+
+#[test]
+fn header_initialized_once() {
+    INITS.with(|i| i.set(0));
+    // SAFETY: The closure initializes `slot`.
+    let header = unsafe {
+        init_from_closure(|slot: *mut Header| {
+            INITS.with(|i| i.set(i.get() + 1));
+            slot.write(Header { len: 3, flags: 0 });
+            Ok(())
+        })
+    };
+    let (header, tail) = alloc_header_with_zeroed_tail::<Header, u16>(header, 3).unwrap();
+    assert_eq!(INITS.with(Cell::get), 1);
+    assert_eq!(header.len, tail.len());
+}
@@ -0,0 +1,138 @@
+#![cfg_attr(feature = "alloc", feature(allocator_api))]
+
+use core::{cell::Cell, marker::PhantomPinned, mem::MaybeUninit, pin::Pin, ptr};
+use pinned_init::*;
+use std::sync::Arc;
+
+#[expect(unused_attributes)]
+#[path = "../examples/error.rs"]
+mod error;
+use error::Error;
+
+mod common;
+use common::{count_drop, dropped, reset_dropped};
+
+#[pin_data(PinnedDrop)]
+struct SelfRef {
+    value: usize,
+    this: *const SelfRef,
+    #[pin]
+    pin: PhantomPinned,
+}
+
+#[pinned_drop]
+impl PinnedDrop for SelfRef {
+    fn drop(self: Pin<&mut Self>) {
+        count_drop();
+    }
+}
+
+impl SelfRef {
+    fn new(value: usize) -> impl PinInit<Self, Error> {
+        try_pin_init!(&this in Self {
+            value,
+            this: this.as_ptr(),
+            pin: PhantomPinned,
+        }? Error)
+    }
+
+    fn fail() -> impl PinInit<Self, Error> {
+        SelfRef::new(0).pin_chain(|_| Err(Error))
+    }
+}
+
+fn check(value: Pin<&SelfRef>, expected: usize) {
+    assert_eq!(value.value, expected);
+    // The value has not been moved after its initialization.
+    assert!(ptr::eq(value.this, &*value));
+}
+
+#[test]
+fn boxed() {
+    reset_dropped();
+    let value = Box::try_pin_init(SelfRef::new(1)).unwrap();
+    check(value.as_ref(), 1);
+    drop(value);
+    assert_eq!(dropped(), 1);
+    let value: Box<[usize; 64]> = Box::init(init_array_from_fn(|i| i)).unwrap();
+    assert_eq!(value[63], 63);
+}
+
+#[test]
+fn arc() {
+    reset_dropped();
+    let value = Arc::try_pin_init(SelfRef::new(2)).unwrap();
+    check(value.as_ref(), 2);
+    drop(value);
+    assert_eq!(dropped(), 1);
+    let value: Arc<[usize; 64]> = Arc::init(init_array_from_fn(|i| i)).unwrap();
+    assert_eq!(value[63], 63);
+}
+
+#[test]
+fn errors() {
+    reset_dropped();
+    assert!(Box::try_pin_init(SelfRef::fail()).is_err());
+    assert!(Arc::try_pin_init(SelfRef::fail()).is_err());
+    // The values have been initialized before the error, so they were dropped.
+    assert_eq!(dropped(), 2);
+}
+
+fn counter(start: usize) -> impl Init<Cell<usize>, Error> {
+    init_from_fn(move || Ok(Cell::new(start)))
+}
+
+#[test]
+fn pinned_from_init() {
+    let value: Pin<Arc<Cell<usize>>> = Arc::try_pin_init(counter(3)).unwrap();
+    value.set(value.get() + 1);
+    assert_eq!(value.get(), 4);
+    let value: Pin<Arc<[usize; 64]>> = Arc::pin_init(init_array_from_fn(|i| i)).unwrap();
+    assert_eq!(value[63], 63);
+    let value: Pin<Box<[usize; 64]>> = Box::pin_init(init_array_from_fn(|i| i * 2)).unwrap();
+    assert_eq!(value[63], 126);
+}
+
+#[derive(Debug, PartialEq)]
+struct Busy;
+
+#[pin_data(PinnedDrop)]
+struct Buffer {
+    data: [u8; 256],
+}
+
+#[pinned_drop]
+impl PinnedDrop for Buffer {
+    fn drop(self: Pin<&mut Self>) {
+        count_drop();
+    }
+}
+
+fn buffer(fail: bool) -> impl PinInit<Buffer, Busy> {
+    try_pin_init!(Buffer { data: [7; 256] }? Busy).pin_chain(
+        move |_| {
+            if fail {
+                Err(Busy)
+            } else {
+                Ok(())
+            }
+        },
+    )
+}
+
+#[test]
+fn reuse_after_failure() {
+    reset_dropped();
+    let slot: Box<MaybeUninit<Buffer>> = Box::new_uninit();
+    let ptr = slot.as_ptr();
+    let (slot, err) = slot.write_pin_init_keep_alloc(buffer(true)).err().unwrap();
+    assert_eq!(err, Busy);
+    assert_eq!(slot.as_ptr(), ptr);
+    // The value initialized before the failure has been dropped.
+    assert_eq!(dropped(), 1);
+    let buf = slot.write_pin_init_keep_alloc(buffer(false)).ok().unwrap();
+    assert!(core::ptr::eq(&*buf, ptr));
+    assert_eq!(buf.data, [7; 256]);
+    drop(buf);
+    assert_eq!(dropped(), 2);
+}
@@ -0,0 +1,60 @@
+use core::{marker::PhantomPinned, pin::Pin};
+use pinned_init::*;
+
+/// Does not implement `From<Infallible>`.
+#[derive(Debug, PartialEq)]
+struct Error;
+
+#[pin_data]
+struct Counter {
+    count: u64,
+    #[pin]
+    pin: PhantomPinned,
+}
+
+fn counter() -> impl PinInit<Counter> {
+    pin_init!(Counter {
+        count: 0,
+        pin: PhantomPinned,
+    })
+}
+
+fn at_most(max: u64, init: impl PinInit<Counter, Error>) -> impl PinInit<Counter, Error> {
+    init.pin_chain(move |counter| {
+        if counter.count <= max {
+            Ok(())
+        } else {
+            Err(Error)
+        }
+    })
+}
+
+#[test]
+fn pinned() {
+    stack_try_pin_init!(let counter = at_most(0, pin_infallible(counter())));
+    assert_eq!(counter.unwrap().count, 0);
+}
+
+#[test]
+fn unpinned() {
+    stack_try_pin_init!(let value: u64 = infallible(zeroed()));
+    let value: Result<Pin<&mut u64>, Error> = value;
+    assert_eq!(*value.unwrap(), 0);
+}
+
+#[pin_data]
+struct Pair {
+    a: u64,
+    #[pin]
+    counter: Counter,
+}
+
+#[test]
+fn in_struct() {
+    stack_try_pin_init!(let pair = try_pin_init!(Pair {
+        a <- infallible::<_, Error>(zeroed()),
+        counter <- pin_infallible::<_, Error>(counter()),
+    }? Error));
+    let pair = pair.unwrap();
+    assert_eq!((pair.a, pair.counter.count), (0, 0));
+}
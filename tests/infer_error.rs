@@ -0,0 +1,64 @@
+#![cfg_attr(feature = "alloc", feature(allocator_api))]
+
+use pinned_init::*;
+
+#[expect(unused_attributes)]
+#[path = "../examples/error.rs"]
+mod error;
+use error::Error;
+
+fn check(val: u32) -> Result<u32, Error> {
+    if val == 0 {
+        Err(Error)
+    } else {
+        Ok(val)
+    }
+}
+
+#[pin_data]
+struct Foo {
+    a: u32,
+    b: u32,
+}
+
+impl Foo {
+    fn pinned(a: u32) -> impl PinInit<Self, Error> {
+        try_pin_init!(Self { a: check(a)?, b: 1 })
+    }
+
+    fn unpinned(a: u32) -> impl Init<Self, Error> {
+        try_init!(Self { a: check(a)?, b: 2 })
+    }
+
+    fn with_this(a: u32) -> impl PinInit<Self, Error> {
+        try_pin_init!(&this in Self {
+            a: check(a)?,
+            b: u32::from(this.as_ptr().is_aligned()),
+        })
+    }
+}
+
+#[test]
+fn pinned() {
+    stack_try_pin_init!(let foo = Foo::pinned(3));
+    let foo = foo.unwrap();
+    assert_eq!((foo.a, foo.b), (3, 1));
+    stack_try_pin_init!(let foo = Foo::pinned(0));
+    assert_eq!(foo.err(), Some(Error));
+}
+
+#[test]
+fn unpinned() {
+    stack_try_pin_init!(let foo = Foo::unpinned(3));
+    let foo = foo.unwrap();
+    assert_eq!((foo.a, foo.b), (3, 2));
+    stack_try_pin_init!(let foo = Foo::unpinned(0));
+    assert_eq!(foo.err(), Some(Error));
+}
+
+#[test]
+fn this() {
+    stack_try_pin_init!(let foo = Foo::with_this(5));
+    let foo = foo.unwrap();
+    assert_eq!((foo.a, foo.b), (5, 1));
+}
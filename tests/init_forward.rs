@@ -0,0 +1,71 @@
+#![cfg_attr(feature = "alloc", feature(allocator_api))]
+
+use pinned_init::*;
+
+#[expect(unused_attributes)]
+#[path = "../examples/mutex.rs"]
+mod mutex;
+use mutex::*;
+
+#[expect(unused_attributes)]
+#[path = "../examples/error.rs"]
+mod error;
+use error::Error;
+
+mod counter {
+    use super::mutex::CMutex;
+    use pinned_init::*;
+
+    #[derive(InitForward)]
+    #[repr(transparent)]
+    pub struct Counter(CMutex<usize>);
+
+    impl Counter {
+        pub fn get(&self) -> usize {
+            *self.0.lock()
+        }
+    }
+}
+
+#[derive(InitForward)]
+#[repr(transparent)]
+struct Named<T> {
+    inner: T,
+}
+
+#[test]
+fn forward_pinned() {
+    stack_pin_init!(let counter = counter::Counter::from_inner_pin_init(CMutex::new(3)));
+    assert_eq!(counter.get(), 3);
+}
+
+#[test]
+fn forward_generic() {
+    stack_pin_init!(let named = Named::from_inner_pin_init(zeroed::<[u8; 8]>()));
+    assert_eq!(named.inner, [0; 8]);
+}
+
+#[test]
+fn forward_error() {
+    // SAFETY: The slot is never touched and we return an error.
+    let fail = unsafe { pin_init_from_closure::<u32, _>(|_| Err(Error)) };
+    stack_try_pin_init!(let named = Named::from_inner_pin_init(fail));
+    assert_eq!(named.err(), Some(Error));
+}
+
+#[test]
+fn forward_init() {
+    let named: Box<Named<u64>> = Box::init(Named::from_inner_init(7)).unwrap();
+    assert_eq!(named.inner, 7);
+    // SAFETY: The slot is never touched and we return an error.
+    let fail = unsafe { init_from_closure::<u32, _>(|_| Err(Error)) };
+    let res = Box::<Named<u32>>::new_uninit().write_init(Named::from_inner_init(fail));
+    assert_eq!(res.err(), Some(Error));
+}
+
+#[test]
+fn is_transparent_wrapper() {
+    // `InitForward` also implements `TransparentWrapper`, so `wrap` works too.
+    stack_pin_init!(let named: Named<u64> = <u64 as PinInit<u64>>::wrap(42));
+    assert_eq!(named.inner, 42);
+}
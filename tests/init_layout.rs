@@ -0,0 +1,37 @@
+#![cfg_attr(feature = "alloc", feature(allocator_api))]
+
+use core::{alloc::Layout, convert::Infallible};
+use pinned_init::*;
+
+#[expect(unused_attributes)]
+#[path = "../examples/error.rs"]
+mod error;
+use error::Error;
+
+#[pin_data]
+#[repr(C)]
+struct Header {
+    len: u16,
+    flags: u64,
+}
+
+fn header() -> impl PinInit<Header, Error> {
+    try_pin_init!(Header { len: 0, flags: 0 }? Error)
+}
+
+#[test]
+fn matches_type() {
+    assert_eq!(header().layout(), Layout::new::<Header>());
+    assert_eq!(zeroed::<[u8; 3]>().layout(), Layout::new::<[u8; 3]>());
+    let init = init_array_from_fn::<_, 8, u64, Infallible>(|i| i as u64);
+    assert_eq!(init.layout(), Layout::new::<[u64; 8]>());
+}
+
+fn layout_of<T, E>(init: &dyn InitLayout<T, E>) -> Layout {
+    init.layout()
+}
+
+#[test]
+fn dyn_layout() {
+    assert_eq!(layout_of(&header()), Layout::new::<Header>());
+}
@@ -0,0 +1,92 @@
+#![cfg_attr(feature = "alloc", feature(allocator_api))]
+
+use pinned_init::*;
+
+#[expect(unused_attributes)]
+#[path = "../examples/error.rs"]
+mod error;
+use error::Error;
+
+mod common;
+use common::{dropped, reset_dropped, Counted};
+
+#[pin_data]
+#[derive(Debug, PartialEq)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[pin_data]
+#[derive(Debug, PartialEq)]
+struct Line {
+    #[pin]
+    from: Point,
+    #[pin]
+    to: Point,
+}
+
+impl Line {
+    fn new(len: i32) -> impl PinInit<Self> {
+        pin_init!(Self {
+            from <- pin_init!(Point { x: 0, y: 0 }),
+            to <- pin_init!(Point { x: len, y: 0 }),
+        })
+    }
+}
+
+#[test]
+fn unpin_into_box() {
+    let line: Box<Line> = Box::init(Line::new(3).into_init()).unwrap();
+    let line = *line;
+    assert_eq!(line.to, Point { x: 3, y: 0 });
+}
+
+#[test]
+fn unpin_as_field() {
+    struct Shape {
+        line: Line,
+        points: usize,
+    }
+
+    let shape = Box::init(init!(Shape {
+        line <- Line::new(1).into_init(),
+        points: 2,
+    }))
+    .unwrap();
+    assert_eq!(shape.line.from, Point { x: 0, y: 0 });
+    assert_eq!(shape.points, 2);
+}
+
+#[pin_data]
+struct Fallible {
+    a: Counted,
+    b: usize,
+}
+
+#[test]
+fn error() {
+    reset_dropped();
+    let init = try_pin_init!(Fallible {
+        a: Counted(0),
+        b <- init_from_closure_err(),
+    }? Error)
+    .into_init();
+    assert!(matches!(Box::<Fallible>::try_init(init), Err(Error)));
+    assert_eq!(dropped(), 1);
+}
+
+fn init_from_closure_err() -> impl Init<usize, Error> {
+    // SAFETY: The closure fails without touching the slot.
+    unsafe { init_from_closure(|_| Err(Error)) }
+}
+
+#[cfg(feature = "test-util")]
+#[test]
+fn infallible_stack() {
+    let init = pin_init!(Point { x: 4, y: 5 }).into_init();
+    assert_eq!(
+        init_to_value(init),
+        Ok::<_, core::convert::Infallible>(Point { x: 4, y: 5 })
+    );
+}
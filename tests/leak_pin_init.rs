@@ -0,0 +1,35 @@
+#![cfg_attr(feature = "alloc", feature(allocator_api))]
+#![cfg(any(feature = "std", feature = "alloc"))]
+
+use core::pin::Pin;
+use pinned_init::*;
+
+#[expect(unused_attributes)]
+#[path = "../examples/mutex.rs"]
+mod mutex;
+use mutex::*;
+
+#[expect(unused_attributes)]
+#[path = "../examples/error.rs"]
+mod error;
+use error::Error;
+
+#[test]
+fn leak_mutex() {
+    let counter: Pin<&'static mut CMutex<usize>> =
+        leak_pin_init::<_, Error>(pin_infallible(CMutex::new(0))).unwrap();
+    let counter: Pin<&'static CMutex<usize>> = counter.into_ref();
+    let handles = (0..4)
+        .map(|_| std::thread::spawn(move || *counter.lock() += 1))
+        .collect::<Vec<_>>();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+    assert_eq!(*counter.lock(), 4);
+}
+
+#[test]
+fn error() {
+    let res = leak_pin_init(pin_init_from_fn::<u32, _>(|| Err(Error)));
+    assert!(matches!(res, Err(Error)));
+}
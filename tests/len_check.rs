@@ -0,0 +1,102 @@
+use pinned_init::*;
+
+#[derive(Debug, PartialEq)]
+#[repr(C)]
+struct Regs {
+    ctrl: u32,
+    status: u32,
+    data: [u16; 4],
+}
+
+fn complete() -> impl Init<Regs> {
+    init!(Regs {
+        ctrl: 1,
+        status: 2,
+        data: [3; 4],
+    })
+}
+
+/// Forgets to initialize `status`.
+#[cfg(all(feature = "debug-poison", debug_assertions))]
+fn incomplete() -> impl Init<Regs> {
+    let init = |slot: *mut Regs| {
+        // SAFETY: `slot` is valid for writes.
+        unsafe {
+            (&raw mut (*slot).ctrl).write(1);
+            (&raw mut (*slot).data).write([3; 4]);
+        }
+        Ok::<_, core::convert::Infallible>(())
+    };
+    // SAFETY: This is deliberately wrong, `status` is not initialized.
+    unsafe { init_from_closure(init) }
+}
+
+#[test]
+fn complete_passes() {
+    // SAFETY: `Regs` has no padding bytes.
+    let regs: Box<Regs> = Box::init(unsafe { complete().with_len_check() }).unwrap();
+    assert_eq!(
+        *regs,
+        Regs {
+            ctrl: 1,
+            status: 2,
+            data: [3; 4],
+        }
+    );
+}
+
+#[cfg(all(feature = "debug-poison", debug_assertions))]
+#[test]
+#[should_panic(expected = "byte 4 of `len_check::Regs` was not written by the initializer")]
+fn incomplete_panics() {
+    // SAFETY: `Regs` has no padding bytes.
+    let init = unsafe { incomplete().with_len_check() };
+    let _: Result<Box<Regs>, _> = Box::init(init);
+}
+
+#[cfg(all(feature = "debug-poison", debug_assertions))]
+mod leak {
+    use super::*;
+    use core::cell::Cell;
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    thread_local! {
+        static DROPPED: Cell<bool> = const { Cell::new(false) };
+    }
+
+    struct Tracked(#[allow(dead_code)] u32);
+
+    impl Drop for Tracked {
+        fn drop(&mut self) {
+            DROPPED.with(|d| d.set(true));
+        }
+    }
+
+    #[repr(C)]
+    struct Pair {
+        first: Tracked,
+        second: u32,
+    }
+
+    /// Forgets to initialize `second`.
+    fn incomplete() -> impl Init<Pair> {
+        let init = |slot: *mut Pair| {
+            // SAFETY: `slot` is valid for writes.
+            unsafe { (&raw mut (*slot).first).write(Tracked(1)) };
+            Ok::<_, core::convert::Infallible>(())
+        };
+        // SAFETY: This is deliberately wrong, `second` is not initialized.
+        unsafe { init_from_closure(init) }
+    }
+
+    #[test]
+    fn incomplete_is_not_dropped() {
+        // SAFETY: `Pair` has no padding bytes.
+        let init = unsafe { incomplete().with_len_check() };
+        let res = catch_unwind(AssertUnwindSafe(|| {
+            let _: Result<Box<Pair>, _> = Box::init(init);
+        }));
+        assert!(res.is_err());
+        assert!(!DROPPED.with(Cell::get));
+    }
+}
@@ -0,0 +1,83 @@
+#![cfg_attr(feature = "alloc", feature(allocator_api))]
+
+use core::{
+    cell::{Cell, UnsafeCell},
+    ptr::addr_of_mut,
+};
+use pinned_init::*;
+use std::{sync::Arc, thread};
+
+#[expect(unused_attributes)]
+#[path = "../examples/error.rs"]
+mod error;
+use error::Error;
+
+#[expect(unused_attributes)]
+#[path = "../examples/mutex.rs"]
+mod mutex;
+use mutex::*;
+
+#[test]
+fn cmutex() {
+    let mtx: std::pin::Pin<Arc<CMutex<[u64; 64]>>> =
+        Arc::pin_init(lock_init(init_array_from_fn(|i| i as u64))).unwrap();
+    let handles: Vec<_> = (0..4)
+        .map(|_| {
+            let mtx = mtx.clone();
+            thread::spawn(move || {
+                for _ in 0..1000 {
+                    mtx.lock()[0] += 1;
+                }
+            })
+        })
+        .collect();
+    for h in handles {
+        h.join().unwrap();
+    }
+    let data = mtx.lock();
+    assert_eq!(data[0], 4000);
+    assert_eq!(data[63], 63);
+}
+
+#[test]
+fn cmutex_data_fails() {
+    // SAFETY: The initializer fails without touching the slot.
+    let fail = unsafe { pin_init_from_closure(|_: *mut u64| Err(Error)) };
+    let res: Result<_, Error> = Box::try_pin_init(lock_init::<CMutex<u64>, _, Error>(fail));
+    assert!(res.is_err());
+}
+
+struct DropCounter<'a>(&'a Cell<usize>);
+
+impl Drop for DropCounter<'_> {
+    fn drop(&mut self) {
+        self.0.set(self.0.get() + 1);
+    }
+}
+
+struct FailingLock<T> {
+    data: UnsafeCell<T>,
+}
+
+// SAFETY: `data_ptr` only projects to `data` and `init_lock` always fails.
+unsafe impl<T> LockInit<T> for FailingLock<T> {
+    type Error = Error;
+
+    unsafe fn data_ptr(slot: *mut Self) -> *mut T {
+        // SAFETY: `slot` is valid and `UnsafeCell<T>` has the same layout as `T`.
+        unsafe { addr_of_mut!((*slot).data) }.cast()
+    }
+
+    unsafe fn init_lock(_slot: *mut Self) -> Result<(), Error> {
+        Err(Error)
+    }
+}
+
+#[test]
+fn lock_fails_drops_data() {
+    let drops = Cell::new(0);
+    stack_try_pin_init!(let res: FailingLock<DropCounter<'_>> =
+        lock_init::<_, _, Error>(DropCounter(&drops)));
+    assert!(res.is_err());
+    assert_eq!(drops.get(), 1);
+}
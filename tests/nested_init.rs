@@ -0,0 +1,71 @@
+#![cfg_attr(feature = "alloc", feature(allocator_api))]
+
+use core::pin::Pin;
+use pinned_init::*;
+use std::{sync::Arc, thread};
+
+#[expect(unused_attributes)]
+#[path = "../examples/error.rs"]
+mod error;
+use error::Error;
+
+#[expect(unused_attributes)]
+#[path = "../examples/mutex.rs"]
+mod mutex;
+use mutex::*;
+
+/// Much bigger than the stack of the threads that run the initializers below. If any of the
+/// initializers created a temporary of this size on the stack, the thread would overflow its stack.
+const BIG: usize = 16 * 1024 * 1024;
+const STACK: usize = 256 * 1024;
+
+fn run_with_small_stack<F: FnOnce() + Send + 'static>(f: F) {
+    thread::Builder::new()
+        .stack_size(STACK)
+        .spawn(f)
+        .unwrap()
+        .join()
+        .unwrap();
+}
+
+#[pin_data]
+struct Buffers {
+    #[pin]
+    inner: CMutex<Box<[u8; BIG]>>,
+    big: Box<[u8; BIG]>,
+}
+
+impl Buffers {
+    fn new() -> impl PinInit<Self, Error> {
+        try_pin_init!(Self {
+            inner <- CMutex::new(Box::init(zeroed())?),
+            big: Box::init(zeroed())?,
+        }? Error)
+    }
+}
+
+#[test]
+fn arc_mutex_array() {
+    run_with_small_stack(|| {
+        let mtx: Pin<Arc<CMutex<[u8; BIG]>>> = Arc::pin_init(CMutex::new(zeroed())).unwrap();
+        assert!(mtx.lock().iter().all(|b| *b == 0));
+    });
+}
+
+#[test]
+fn arc_mutex_box_array() {
+    run_with_small_stack(|| {
+        let mtx: Pin<Arc<CMutex<Box<[u8; BIG]>>>> =
+            Arc::pin_init(CMutex::new(Box::init(zeroed()).unwrap())).unwrap();
+        assert!(mtx.lock().iter().all(|b| *b == 0));
+    });
+}
+
+#[test]
+fn nested_try_pin_init() {
+    run_with_small_stack(|| {
+        let bufs = Arc::try_pin_init(Buffers::new()).unwrap();
+        assert!(bufs.inner.lock().iter().all(|b| *b == 0));
+        assert!(bufs.big.iter().all(|b| *b == 0));
+    });
+}
@@ -0,0 +1,46 @@
+#![cfg_attr(feature = "alloc", feature(allocator_api))]
+
+use pinned_init::*;
+
+mod common;
+use common::{dropped, reset_dropped, Counted};
+
+#[expect(unused_attributes)]
+#[path = "../examples/error.rs"]
+mod error;
+use error::Error;
+
+#[pin_data]
+#[derive(Zeroable)]
+struct Stats {
+    first: Counted,
+    second: Counted,
+}
+
+fn failing() -> impl Init<Counted, Error> {
+    // SAFETY: The closure does not touch `slot` and returns an error.
+    unsafe { init_from_closure(|_| Err(Error)) }
+}
+
+#[test]
+fn falls_back_to_zeroes() {
+    reset_dropped();
+    let init = try_init!(Stats {
+        first: Counted(1),
+        second <- failing(),
+    }? Error);
+    stack_pin_init!(let stats: Stats = init.or_zeroed());
+    // `first` has been dropped by the failing initializer before the slot was zeroed.
+    assert_eq!(dropped(), 1);
+    assert_eq!((stats.first.0, stats.second.0), (0, 0));
+}
+
+#[test]
+fn success_is_kept() {
+    let init = try_init!(Stats {
+        first: Counted(1),
+        second: Counted(2),
+    }? Error);
+    let stats: Box<Stats> = Box::init(init.or_zeroed()).unwrap();
+    assert_eq!((stats.first.0, stats.second.0), (1, 2));
+}
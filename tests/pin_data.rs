@@ -0,0 +1,94 @@
+use core::{cell::Cell, marker::PhantomPinned, pin::Pin};
+use pinned_init::*;
+
+#[test]
+fn local_struct() {
+    #[pin_data]
+    struct Local {
+        #[pin]
+        _pin: PhantomPinned,
+        value: usize,
+    }
+
+    let local: Pin<Box<Local>> = Box::pin_init(pin_init!(Local {
+        _pin: PhantomPinned,
+        value: 42,
+    }))
+    .unwrap();
+    assert_eq!(local.value, 42);
+}
+
+#[test]
+fn local_generic_with_drop() {
+    thread_local! {
+        static DROPPED: Cell<bool> = const { Cell::new(false) };
+    }
+
+    #[pin_data(PinnedDrop)]
+    struct Wrapper<T> {
+        #[pin]
+        inner: T,
+    }
+
+    #[pinned_drop]
+    impl<T> PinnedDrop for Wrapper<T> {
+        fn drop(self: Pin<&mut Self>) {
+            DROPPED.with(|d| d.set(true));
+        }
+    }
+
+    impl<T> Wrapper<T> {
+        fn new(inner: impl PinInit<T>) -> impl PinInit<Self> {
+            pin_init!(Self { inner <- inner })
+        }
+    }
+
+    {
+        stack_pin_init!(let wrapper = Wrapper::new(7u32));
+        assert_eq!(wrapper.inner, 7);
+    }
+    assert!(DROPPED.with(Cell::get));
+}
+
+#[test]
+fn local_constructor() {
+    #[pin_data(Zeroable, constructor)]
+    struct Pair {
+        #[pin]
+        first: u32,
+        second: u64,
+    }
+
+    let pair: Pin<Box<Pair>> = Box::pin_init(Pair::new_pin_init(1, 2)).unwrap();
+    assert_eq!((pair.first, pair.second), (1, 2));
+    let pair: Box<Pair> = Box::init(zeroed()).unwrap();
+    assert_eq!((pair.first, pair.second), (0, 0));
+}
+
+#[pin_data]
+struct Waiters {
+    count: usize,
+    #[pin]
+    waiters: [PhantomPinned],
+}
+
+assert_not_unpin!(Waiters);
+
+#[pin_data]
+struct Packet<T: ?Sized> {
+    len: u32,
+    #[pin]
+    data: T,
+}
+
+#[test]
+fn unsize_generic() {
+    let packet: Pin<Box<Packet<[u8; 4]>>> = Box::pin_init(pin_init!(Packet {
+        len: 4,
+        data: [1, 2, 3, 4],
+    }))
+    .unwrap();
+    let packet: Pin<Box<Packet<[u8]>>> = packet;
+    assert_eq!(packet.len as usize, packet.data.len());
+    assert_eq!(packet.data, [1, 2, 3, 4]);
+}
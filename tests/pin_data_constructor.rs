@@ -0,0 +1,82 @@
+#![cfg_attr(feature = "alloc", feature(allocator_api))]
+
+use core::pin::Pin;
+use pinned_init::*;
+
+#[expect(unused_attributes)]
+#[path = "../examples/mutex.rs"]
+mod mutex;
+use mutex::*;
+
+mod driver {
+    use super::*;
+
+    #[pin_data(constructor)]
+    pub struct Driver {
+        #[pin]
+        queue: CMutex<Vec<u32>>,
+        id: usize,
+        buf: [u8; 64],
+    }
+
+    impl Driver {
+        pub fn id(&self) -> usize {
+            self.id
+        }
+
+        pub fn push(self: Pin<&Self>, value: u32) {
+            self.queue.lock().push(value);
+        }
+
+        pub fn len(self: Pin<&Self>) -> usize {
+            self.queue.lock().len()
+        }
+
+        pub fn buf(&self) -> &[u8; 64] {
+            &self.buf
+        }
+    }
+
+    #[pin_data(constructor)]
+    pub(crate) struct Pair<T> {
+        first: T,
+        second: T,
+    }
+
+    impl<T: Copy> Pair<T> {
+        pub(crate) fn get(&self) -> (T, T) {
+            (self.first, self.second)
+        }
+    }
+}
+
+use driver::{Driver, Pair};
+
+#[expect(unused_attributes)]
+#[path = "../examples/error.rs"]
+mod error;
+use error::Error;
+
+#[test]
+fn private_fields() {
+    let driver: Pin<Box<Driver>> =
+        Box::pin_init(Driver::new_pin_init(CMutex::new(Vec::new()), 7, zeroed())).unwrap();
+    driver.as_ref().push(1);
+    driver.as_ref().push(2);
+    assert_eq!(driver.id(), 7);
+    assert_eq!(driver.as_ref().len(), 2);
+    assert_eq!(driver.buf(), &[0; 64]);
+}
+
+fn failing() -> impl Init<u64, Error> {
+    // SAFETY: The closure does not touch `slot` and returns an error.
+    unsafe { init_from_closure(|_| Err(Error)) }
+}
+
+#[test]
+fn generic() {
+    stack_pin_init!(let pair: Pair<u64> = Pair::new_pin_init(1, 2));
+    assert_eq!(pair.get(), (1, 2));
+    stack_try_pin_init!(let pair: Pair<u64> = Pair::new_pin_init(1, failing()));
+    assert_eq!(pair.err(), Some(Error));
+}
@@ -0,0 +1,42 @@
+use core::{marker::PhantomPinned, num::NonZeroU64, pin::Pin};
+use pinned_init::*;
+
+#[pin_data(Zeroable, Default)]
+#[derive(Debug, PartialEq)]
+struct Config {
+    flags: u32,
+    timeout: Option<NonZeroU64>,
+    name: [u8; 16],
+}
+
+#[pin_data(PinnedDrop, Zeroable, Default)]
+struct Pinned<T> {
+    #[pin]
+    pin: PhantomPinned,
+    value: T,
+}
+
+#[pinned_drop]
+impl<T> PinnedDrop for Pinned<T> {
+    fn drop(self: Pin<&mut Self>) {}
+}
+
+#[test]
+fn default_is_zeroed() {
+    assert_eq!(
+        Config::default(),
+        Config {
+            flags: 0,
+            timeout: None,
+            name: [0; 16],
+        }
+    );
+}
+
+#[test]
+fn generic_with_pinned_drop() {
+    let p = Pinned::<usize>::default();
+    assert_eq!(p.value, 0);
+    let z: Pinned<u8> = zeroed_value();
+    assert_eq!(z.value, 0);
+}
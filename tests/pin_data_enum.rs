@@ -0,0 +1,59 @@
+use core::{cell::Cell, marker::PhantomPinned, pin::Pin};
+use pinned_init::*;
+
+#[pin_data]
+#[repr(u8)]
+#[allow(dead_code)]
+enum Unpinned<T> {
+    A { value: T },
+    B(u32, T),
+    C,
+    D = 10,
+}
+
+#[pin_data]
+#[allow(dead_code)]
+enum Pinned {
+    Tuple(#[pin] PhantomPinned),
+    Named {
+        #[pin]
+        pin: PhantomPinned,
+        value: usize,
+    },
+    Unit,
+}
+
+thread_local! {
+    static DROPPED: Cell<usize> = const { Cell::new(0) };
+}
+
+#[pin_data(PinnedDrop)]
+enum WithDrop {
+    Count(usize),
+}
+
+#[pinned_drop]
+impl PinnedDrop for WithDrop {
+    fn drop(self: Pin<&mut Self>) {
+        let WithDrop::Count(count) = &*self;
+        DROPPED.with(|d| d.set(d.get() + count));
+    }
+}
+
+assert_not_unpin!(Pinned);
+
+fn assert_unpin<T: Unpin>() {}
+
+#[test]
+fn unpin() {
+    assert_unpin::<Unpinned<usize>>();
+    // Only pinned fields are relevant for `Unpin`.
+    assert_unpin::<Unpinned<PhantomPinned>>();
+}
+
+#[test]
+fn pinned_drop() {
+    DROPPED.with(|d| d.set(0));
+    drop(WithDrop::Count(3));
+    assert_eq!(DROPPED.with(Cell::get), 3);
+}
@@ -0,0 +1,60 @@
+#![cfg_attr(feature = "alloc", feature(allocator_api))]
+
+use core::{
+    marker::PhantomPinned,
+    mem::MaybeUninit,
+    pin::Pin,
+    ptr::{self, addr_of_mut},
+};
+use pinned_init::*;
+
+#[expect(unused_attributes)]
+#[path = "../examples/error.rs"]
+mod error;
+use error::Error;
+
+#[pin_data]
+struct Registers {
+    ctrl: u32,
+    status: u32,
+    this: *const Registers,
+    #[pin]
+    _pin: PhantomPinned,
+}
+
+impl Registers {
+    fn new(ctrl: u32) -> impl PinInit<Self> {
+        pin_init!(&this in Self {
+            ctrl,
+            status: 0,
+            this: this.as_ptr(),
+            _pin: PhantomPinned,
+        })
+    }
+}
+
+#[test]
+fn static_buffer() {
+    static mut REGS: MaybeUninit<Registers> = MaybeUninit::uninit();
+    let addr: *mut Registers = addr_of_mut!(REGS).cast();
+    // SAFETY: `REGS` is only accessed through the returned reference.
+    let regs: Pin<&'static mut Registers> =
+        unsafe { pin_init_at(addr, Registers::new(0x11)) }.unwrap();
+    assert_eq!(regs.ctrl, 0x11);
+    assert_eq!(regs.status, 0);
+    assert!(ptr::eq(regs.this, addr));
+    assert!(ptr::eq(&*regs, addr));
+}
+
+#[test]
+fn retry_after_error() {
+    static mut BUF: MaybeUninit<[u64; 4]> = MaybeUninit::uninit();
+    let addr: *mut [u64; 4] = addr_of_mut!(BUF).cast();
+    let failing = pin_init_array_from_fn(|i| i as u64).pin_chain(|_| Err(Error));
+    // SAFETY: `BUF` is only accessed through the returned reference.
+    assert!(matches!(unsafe { pin_init_at(addr, failing) }, Err(Error)));
+    // SAFETY: The first attempt failed, so `BUF` is uninitialized and not accessed otherwise.
+    let buf =
+        unsafe { pin_init_at::<_, Error>(addr, pin_init_array_from_fn(|i| i as u64 * 2)) }.unwrap();
+    assert_eq!(*buf, [0, 2, 4, 6]);
+}
@@ -0,0 +1,59 @@
+use core::{
+    convert::Infallible,
+    marker::PhantomPinned,
+    mem::MaybeUninit,
+    pin::{pin, Pin},
+    ptr,
+};
+use pinned_init::*;
+
+mod common;
+use common::{count_drop, dropped, reset_dropped};
+
+#[pin_data(PinnedDrop)]
+struct SelfRef {
+    value: u32,
+    ptr: *const u32,
+    #[pin]
+    _pin: PhantomPinned,
+}
+
+impl SelfRef {
+    fn new(value: u32) -> impl PinInit<Self> {
+        pin_init!(&this in Self {
+            value,
+            // SAFETY: `this` points to the slot that is being initialized.
+            ptr: unsafe { ptr::addr_of!((*this.as_ptr()).value) },
+            _pin: PhantomPinned,
+        })
+    }
+}
+
+#[pinned_drop]
+impl PinnedDrop for SelfRef {
+    fn drop(self: Pin<&mut Self>) {
+        assert!(ptr::eq(self.ptr, &self.value));
+        count_drop();
+    }
+}
+
+#[test]
+fn init_and_drop() {
+    reset_dropped();
+    let slot = pin!(MaybeUninit::uninit());
+    // SAFETY: The value is dropped below, before `slot` goes out of scope.
+    let value = unsafe { pin_init_pinned_slot::<_, Infallible>(slot, SelfRef::new(7)) }.unwrap();
+    assert_eq!(value.value, 7);
+    // SAFETY: The value stays pinned and is not used afterwards.
+    unsafe { ptr::drop_in_place(Pin::get_unchecked_mut(value)) };
+    assert_eq!(dropped(), 1);
+}
+
+#[test]
+fn error() {
+    let slot = pin!(MaybeUninit::<u64>::uninit());
+    // SAFETY: The initializer fails without touching the slot.
+    let fail = unsafe { pin_init_from_closure(|_| Err(())) };
+    // SAFETY: The slot is not initialized on error, so there is nothing to drop.
+    assert!(unsafe { pin_init_pinned_slot(slot, fail) }.is_err());
+}
@@ -0,0 +1,59 @@
+use core::{convert::Infallible, pin::Pin};
+use pinned_init::*;
+
+#[pin_data]
+struct Buffer {
+    len: usize,
+    data: [u8; 1024],
+}
+
+fn pinned(init: impl PinPlaceInit<Buffer, Infallible>) -> Pin<Box<Buffer>> {
+    Box::pin_init(init).unwrap()
+}
+
+fn unpinned(init: impl PlaceInit<Buffer, Infallible>) -> Box<Buffer> {
+    Box::init(init).unwrap()
+}
+
+#[test]
+fn macros() {
+    let buf = pinned(pin_init!(Buffer { len: 0, data <- zeroed() }));
+    assert_eq!(buf.len, 0);
+    let buf = unpinned(init!(Buffer { len: 1, data <- zeroed() }));
+    assert_eq!(buf.len, 1);
+}
+
+#[test]
+fn closures() {
+    // SAFETY: The closure initializes every field of `slot`.
+    let init = unsafe {
+        init_from_closure(|slot: *mut Buffer| {
+            slot.write_bytes(0, 1);
+            (*slot).len = 2;
+            Ok(())
+        })
+    };
+    assert_eq!(unpinned(init).len, 2);
+}
+
+#[test]
+fn adapters() {
+    let buf = pinned(
+        pin_init!(Buffer { len: 3, data <- zeroed() })
+            .pin_chain(|buf: Pin<&mut Buffer>| {
+                // SAFETY: `data` is not structurally pinned.
+                unsafe { buf.get_unchecked_mut().data[0] = 1 };
+                Ok(())
+            })
+            .validate(|buf: Pin<&Buffer>| {
+                assert_eq!(buf.data[0], 1);
+                Ok(())
+            }),
+    );
+    assert_eq!((buf.len, buf.data[0]), (3, 1));
+    let buf = unpinned(init!(Buffer { len: 4, data <- zeroed() }).chain(|buf| {
+        buf.data[1] = 2;
+        Ok(())
+    }));
+    assert_eq!((buf.len, buf.data[1]), (4, 2));
+}
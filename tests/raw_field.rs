@@ -0,0 +1,101 @@
+use core::{cell::UnsafeCell, marker::PhantomPinned, pin::Pin};
+use pinned_init::*;
+
+/// A mock of a C API, that records every destroyed device.
+#[allow(
+    non_camel_case_types,
+    clippy::missing_safety_doc,
+    clippy::undocumented_unsafe_blocks
+)]
+mod bindings {
+    use core::cell::RefCell;
+
+    thread_local! {
+        pub static DESTROYED: RefCell<Vec<u32>> = const { RefCell::new(Vec::new()) };
+    }
+
+    pub struct device {
+        pub this: *const device,
+        pub id: u32,
+    }
+
+    pub unsafe fn device_init(ptr: *mut device, id: u32) -> i32 {
+        if id == 0 {
+            return -22;
+        }
+        unsafe { ptr.write(device { this: ptr, id }) };
+        0
+    }
+
+    pub unsafe fn device_destroy(ptr: *mut device) {
+        // The device must not have been moved.
+        assert_eq!(unsafe { (*ptr).this }, ptr.cast_const());
+        DESTROYED.with(|d| d.borrow_mut().push(unsafe { (*ptr).id }));
+    }
+}
+
+#[pin_data(PinnedDrop)]
+struct Device {
+    name: &'static str,
+    #[pin]
+    raw: UnsafeCell<bindings::device>,
+    #[pin]
+    _pin: PhantomPinned,
+}
+
+impl Device {
+    fn new(name: &'static str, id: u32) -> impl PinInit<Self, i32> {
+        try_pin_init!(Self {
+            name,
+            // SAFETY: `device_init` initializes the device in place when it returns `0` and does
+            // not touch it otherwise.
+            raw <- unsafe_cell_init(unsafe {
+                pin_init_from_closure(move |slot| match bindings::device_init(slot, id) {
+                    0 => Ok(()),
+                    err => Err(err),
+                })
+            }),
+            _pin: PhantomPinned,
+        }? i32)
+    }
+
+    fn id(&self) -> u32 {
+        // SAFETY: `raw` is initialized.
+        unsafe { (*raw_field!(self, raw)).id }
+    }
+}
+
+#[pinned_drop]
+impl PinnedDrop for Device {
+    fn drop(self: Pin<&mut Self>) {
+        // SAFETY: `raw` is initialized and not used afterwards.
+        unsafe { bindings::device_destroy(raw_field!(self, raw)) };
+    }
+}
+
+fn destroyed() -> Vec<u32> {
+    bindings::DESTROYED.with(|d| d.take())
+}
+
+#[test]
+fn destroy_on_drop() {
+    destroyed();
+    {
+        stack_try_pin_init!(let dev = Device::new("eth0", 3));
+        let dev = dev.unwrap();
+        assert_eq!(dev.name, "eth0");
+        assert_eq!(dev.id(), 3);
+        let ptr: *mut bindings::device = raw_field!(dev, raw);
+        assert_eq!(ptr, dev.raw.get());
+        assert!(destroyed().is_empty());
+    }
+    assert_eq!(destroyed(), [3]);
+}
+
+#[test]
+fn init_fails() {
+    destroyed();
+    stack_try_pin_init!(let dev = Device::new("eth1", 0));
+    assert_eq!(dev.err(), Some(-22));
+    assert!(destroyed().is_empty());
+}
@@ -0,0 +1,76 @@
+#![cfg_attr(feature = "alloc", feature(allocator_api))]
+
+use core::{marker::PhantomPinned, pin::Pin};
+use pinned_init::*;
+
+#[expect(unused_attributes)]
+#[path = "../examples/mutex.rs"]
+mod mutex;
+use mutex::*;
+
+#[pin_data(PinnedDrop)]
+struct Token {
+    r#type: u8,
+    #[pin]
+    r#ref: CMutex<usize>,
+    r#match: usize,
+    #[pin]
+    _pin: PhantomPinned,
+}
+
+#[pinned_drop]
+impl PinnedDrop for Token {
+    fn drop(self: Pin<&mut Self>) {
+        *self.r#ref.lock() = 0;
+    }
+}
+
+#[test]
+fn pin_init() {
+    let token = Box::pin_init(pin_init!(Token {
+        r#type: 1,
+        r#ref <- CMutex::new(2),
+        r#match: *field_ref!(r#type) as usize + 1,
+        _pin: PhantomPinned,
+    }))
+    .unwrap();
+    assert_eq!(token.r#type, 1);
+    assert_eq!(*token.r#ref.lock(), 2);
+    assert_eq!(token.r#match, 2);
+}
+
+#[derive(Zeroable)]
+struct Keywords {
+    r#type: u8,
+    r#fn: u32,
+    r#loop: [u8; 4],
+}
+
+#[test]
+fn init() {
+    let kw = Box::init(init!(Keywords {
+        r#fn <- init_from_fn(|| Ok::<_, core::convert::Infallible>(7)),
+        ..Zeroable::zeroed()
+    }))
+    .unwrap();
+    assert_eq!(kw.r#type, 0);
+    assert_eq!(kw.r#fn, 7);
+    assert_eq!(kw.r#loop, [0; 4]);
+}
+
+#[pin_data(constructor)]
+struct Raw {
+    #[pin]
+    r#struct: PhantomPinned,
+    r#enum: u8,
+}
+
+#[test]
+fn constructor() {
+    let raw = Box::pin_init(Raw::new_pin_init::<core::convert::Infallible>(
+        PhantomPinned,
+        3,
+    ))
+    .unwrap();
+    assert_eq!(raw.r#enum, 3);
+}
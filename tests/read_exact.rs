@@ -0,0 +1,68 @@
+#![cfg(feature = "std")]
+#![cfg_attr(feature = "alloc", feature(allocator_api))]
+
+use pinned_init::*;
+use std::io::{self, Cursor, ErrorKind};
+
+#[derive(Debug)]
+enum Error {
+    Io(io::Error),
+    #[cfg_attr(not(feature = "alloc"), expect(dead_code))]
+    Alloc,
+}
+
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<core::alloc::AllocError> for Error {
+    fn from(_: core::alloc::AllocError) -> Self {
+        Self::Alloc
+    }
+}
+
+#[cfg(not(feature = "alloc"))]
+impl From<core::convert::Infallible> for Error {
+    fn from(err: core::convert::Infallible) -> Self {
+        match err {}
+    }
+}
+
+#[pin_data]
+struct Page {
+    index: usize,
+    data: [u8; 1024],
+}
+
+fn page(index: usize, reader: impl io::Read) -> impl Init<Page, Error> {
+    try_init!(Page {
+        index,
+        data <- read_exact_init(reader),
+    }? Error)
+}
+
+#[test]
+fn boxed() {
+    let data = (0..2048).map(|i| i as u8).collect::<Vec<_>>();
+    let mut cursor = Cursor::new(data);
+    let page = Box::try_init(page(0, &mut cursor)).unwrap();
+    assert_eq!(page.index, 0);
+    assert!(page.data.iter().enumerate().all(|(i, b)| *b == i as u8));
+    // Only the requested bytes have been consumed.
+    assert_eq!(cursor.position(), 1024);
+}
+
+#[test]
+fn short_read() {
+    let res = Box::try_init(page(1, Cursor::new([1; 16])));
+    assert!(matches!(res, Err(Error::Io(err)) if err.kind() == ErrorKind::UnexpectedEof));
+}
+
+#[test]
+fn stack() {
+    stack_try_pin_init!(let buf: [u8; 3] = read_exact_init(&b"abc"[..]));
+    assert_eq!(&*buf.unwrap(), b"abc");
+}
@@ -0,0 +1,58 @@
+#![cfg_attr(feature = "alloc", feature(allocator_api))]
+
+use core::marker::PhantomPinned;
+use pinned_init::*;
+
+#[expect(unused_attributes)]
+#[path = "../examples/error.rs"]
+mod error;
+use error::Error;
+
+#[pin_data]
+struct Packet<'a> {
+    len: usize,
+    payload: &'a [u8],
+    #[pin]
+    pin: PhantomPinned,
+}
+
+#[test]
+fn stack() {
+    let buf = [1, 2, 3, 4];
+    let payload = &buf[1..];
+    stack_pin_init!(let packet = pin_init!(Packet {
+        len: payload.len(),
+        payload,
+        pin: PhantomPinned,
+    }));
+    assert_eq!(packet.len, 3);
+    assert_eq!(packet.payload, &[2, 3, 4]);
+}
+
+#[test]
+fn boxed() {
+    let buf = vec![5; 16];
+    let payload = buf.as_slice();
+    let packet = Box::pin_init(pin_init!(Packet {
+        len: payload.len(),
+        payload: &payload[..8],
+        pin: PhantomPinned,
+    }))
+    .unwrap();
+    assert_eq!(packet.payload, &[5; 8]);
+}
+
+fn packet(payload: &[u8]) -> impl PinInit<Packet<'_>, Error> {
+    try_pin_init!(Packet {
+        len: payload.len(),
+        payload,
+        pin: PhantomPinned,
+    }? Error)
+}
+
+#[test]
+fn from_fn() {
+    stack_try_pin_init!(let packet = packet(b"pinned-init"));
+    let packet = packet.unwrap();
+    assert_eq!(packet.payload, b"pinned-init");
+}
@@ -0,0 +1,80 @@
+#![cfg_attr(feature = "alloc", feature(allocator_api))]
+
+use core::{
+    marker::PhantomPinned,
+    ptr::{self, NonNull},
+    sync::atomic::{AtomicPtr, Ordering},
+};
+use pinned_init::*;
+
+#[expect(unused_attributes)]
+#[path = "../examples/error.rs"]
+mod error;
+use error::Error;
+
+mod common;
+use common::{dropped, reset_dropped, Counted};
+
+/// Head of a global, intrusive, singly linked list of `Node`s.
+static HEAD: AtomicPtr<Node> = AtomicPtr::new(ptr::null_mut());
+
+#[pin_data]
+struct Node {
+    value: usize,
+    next: *mut Node,
+    #[pin]
+    pin: PhantomPinned,
+}
+
+impl Node {
+    fn new(value: usize) -> impl PinInit<Self, Error> {
+        try_pin_init!(Self {
+            value,
+            next: ptr::null_mut(),
+            pin: PhantomPinned,
+        }? Error)
+        .pin_init_register(|node: NonNull<Node>| {
+            let node = node.as_ptr();
+            let mut head = HEAD.load(Ordering::Acquire);
+            loop {
+                // SAFETY: `node` is initialized and nobody else has access to it yet.
+                unsafe { (*node).next = head };
+                match HEAD.compare_exchange(head, node, Ordering::AcqRel, Ordering::Acquire) {
+                    Ok(_) => return Ok(()),
+                    Err(cur) => head = cur,
+                }
+            }
+        })
+    }
+}
+
+fn values() -> Vec<usize> {
+    let mut values = vec![];
+    let mut cur = HEAD.load(Ordering::Acquire);
+    while !cur.is_null() {
+        // SAFETY: All nodes in the list are leaked and thus still alive.
+        let node = unsafe { &*cur };
+        values.push(node.value);
+        cur = node.next;
+    }
+    values
+}
+
+#[test]
+fn register_into_global_list() {
+    for i in 0..3 {
+        let node = Box::try_pin_init(Node::new(i)).unwrap();
+        assert!(ptr::eq(HEAD.load(Ordering::Acquire), &*node as *const Node));
+        // The list points to the node, so it must stay alive.
+        core::mem::forget(node);
+    }
+    assert_eq!(values(), [2, 1, 0]);
+}
+
+#[test]
+fn register_error_drops_value() {
+    reset_dropped();
+    let init = PinInit::<Counted, Error>::pin_init_register(Counted(0), |_| Err(Error));
+    assert!(Box::<Counted>::try_pin_init(init).is_err());
+    assert_eq!(dropped(), 1);
+}
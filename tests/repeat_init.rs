@@ -0,0 +1,40 @@
+use core::cell::Cell;
+use pinned_init::*;
+
+mod common;
+use common::Counted;
+
+#[test]
+fn strings() {
+    let template = "pinned-init".to_owned();
+    let array: Box<[String; 1000]> = Box::init(repeat_init(template)).unwrap();
+    assert!(array.iter().all(|s| s == "pinned-init"));
+    // Every element owns its own allocation.
+    assert!(array.windows(2).all(|w| w[0].as_ptr() != w[1].as_ptr()));
+}
+
+thread_local! {
+    static CLONES: Cell<usize> = const { Cell::new(0) };
+}
+
+impl Clone for Counted {
+    fn clone(&self) -> Self {
+        CLONES.with(|c| c.set(c.get() + 1));
+        Self(self.0)
+    }
+}
+
+#[pin_data]
+struct Big {
+    elems: [Counted; 64],
+}
+
+#[test]
+fn in_struct() {
+    CLONES.with(|c| c.set(0));
+    let _: Box<Big> = Box::init(init!(Big {
+        elems <- repeat_init(Counted(0)),
+    }))
+    .unwrap();
+    assert_eq!(CLONES.with(Cell::get), 64);
+}
@@ -0,0 +1,64 @@
+use core::pin::Pin;
+use pinned_init::*;
+
+mod common;
+use common::{count_drop, dropped, reset_dropped};
+
+#[derive(Debug, PartialEq)]
+struct Busy(usize);
+
+#[pin_data(PinnedDrop)]
+struct Device {
+    id: usize,
+}
+
+#[pinned_drop]
+impl PinnedDrop for Device {
+    fn drop(self: Pin<&mut Self>) {
+        count_drop();
+    }
+}
+
+/// Creates an initializer that fails until the `fail_until`th attempt.
+fn probe(attempt: &mut usize, fail_until: usize) -> impl PinInit<Device, Busy> {
+    *attempt += 1;
+    let id = *attempt;
+    try_pin_init!(Device { id }? Busy).pin_chain(move |_| {
+        if id < fail_until {
+            Err(Busy(id))
+        } else {
+            Ok(())
+        }
+    })
+}
+
+#[test]
+fn third_attempt() {
+    reset_dropped();
+    let mut attempt = 0;
+    {
+        stack_try_pin_init!(let dev = retry_pin_init(5, || probe(&mut attempt, 3)));
+        assert_eq!(dev.unwrap().id, 3);
+        // The values of the two failed attempts have been dropped.
+        assert_eq!(dropped(), 2);
+    }
+    assert_eq!(attempt, 3);
+    assert_eq!(dropped(), 3);
+}
+
+#[test]
+fn gives_up() {
+    reset_dropped();
+    let mut attempt = 0;
+    stack_try_pin_init!(let dev = retry_pin_init(2, || probe(&mut attempt, 3)));
+    assert_eq!(dev.err(), Some(Busy(2)));
+    assert_eq!(attempt, 2);
+    assert_eq!(dropped(), 2);
+}
+
+#[test]
+fn zero_attempts() {
+    let mut attempt = 0;
+    stack_try_pin_init!(let dev = retry_pin_init(0, || probe(&mut attempt, 1)));
+    assert_eq!(dev.unwrap().id, 1);
+}
@@ -1,13 +1,7 @@
 #![allow(clippy::undocumented_unsafe_blocks)]
 #![cfg_attr(feature = "alloc", feature(allocator_api))]
 
-use core::{
-    convert::Infallible,
-    marker::PhantomPinned,
-    mem::MaybeUninit,
-    pin::Pin,
-    ptr::{self, addr_of_mut},
-};
+use core::{convert::Infallible, mem::MaybeUninit};
 use pinned_init::*;
 use std::sync::Arc;
 
@@ -16,106 +10,12 @@ use std::sync::Arc;
 mod error;
 use error::Error;
 
-#[pin_data(PinnedDrop)]
-pub struct RingBuffer<T, const SIZE: usize> {
-    buffer: [MaybeUninit<T>; SIZE],
-    head: *mut T,
-    tail: *mut T,
-    #[pin]
-    _pin: PhantomPinned,
-}
-
-#[pinned_drop]
-impl<T, const SIZE: usize> PinnedDrop for RingBuffer<T, SIZE> {
-    fn drop(self: Pin<&mut Self>) {
-        // SAFETY: We do not move `this`.
-        let this = unsafe { self.get_unchecked_mut() };
-        while !ptr::eq(this.tail, this.head) {
-            unsafe { this.tail.drop_in_place() };
-            this.tail = unsafe { this.advance(this.tail) };
-        }
-    }
-}
-
-impl<T, const SIZE: usize> RingBuffer<T, SIZE> {
-    pub fn new() -> impl PinInit<Self> {
-        assert!(SIZE > 0);
-        pin_init!(&this in Self {
-            // SAFETY: The elements of the array can be uninitialized.
-            buffer <- unsafe { init_from_closure(|_| Ok::<_, Infallible>(())) },
-            // SAFETY: `this` is a valid pointer.
-            head: unsafe { addr_of_mut!((*this.as_ptr()).buffer).cast::<T>() },
-            tail: unsafe { addr_of_mut!((*this.as_ptr()).buffer).cast::<T>() },
-            _pin: PhantomPinned,
-        })
-    }
-
-    pub fn push(self: Pin<&mut Self>, value: impl Init<T>) -> bool {
-        match self.try_push(value) {
-            Ok(res) => res,
-            Err(i) => match i {},
-        }
-    }
-    pub fn try_push<E>(self: Pin<&mut Self>, value: impl Init<T, E>) -> Result<bool, E> {
-        // SAFETY: We do not move `this`.
-        let this = unsafe { self.get_unchecked_mut() };
-        let next_head = unsafe { this.advance(this.head) };
-        // `head` and `tail` point into the same buffer.
-        if ptr::eq(next_head, this.tail) {
-            // We cannot advance `head`, since `next_head` would point to the same slot as `tail`,
-            // which is currently live.
-            return Ok(false);
-        }
-        // SAFETY: `head` always points to the next free slot.
-        unsafe { value.__init(this.head)? };
-        this.head = next_head;
-        Ok(true)
-    }
-
-    pub fn pop(self: Pin<&mut Self>) -> Option<T> {
-        // SAFETY: We do not move `this`.
-        let this = unsafe { self.get_unchecked_mut() };
-        if ptr::eq(this.head, this.tail) {
-            return None;
-        }
-        // SAFETY: `tail` always points to a valid element, or is the same as `head`.
-        let value = unsafe { this.tail.read() };
-        this.tail = unsafe { this.advance(this.tail) };
-        Some(value)
-    }
+#[path = "../examples/ring_buffer.rs"]
+mod ring_buffer;
+use ring_buffer::RingBuffer;
 
-    pub fn pop_no_stack(self: Pin<&mut Self>) -> Option<impl Init<T> + '_> {
-        // SAFETY: We do not move `this`.
-        let this = unsafe { self.get_unchecked_mut() };
-        if ptr::eq(this.head, this.tail) {
-            return None;
-        }
-        let remove_init = |slot| {
-            // SAFETY: `tail` always points to a valid element, or is the same as `head`.
-            unsafe { ptr::copy_nonoverlapping(this.tail, slot, 1) };
-            this.tail = unsafe { this.advance(this.tail) };
-            Ok(())
-        };
-        // SAFETY: the above initializer is correct.
-        Some(unsafe { init_from_closure(remove_init) })
-    }
-
-    /// # Safety
-    ///
-    /// TODO
-    unsafe fn advance(&mut self, ptr: *mut T) -> *mut T {
-        // SAFETY: ptr's offset from buffer is < SIZE
-        let ptr = unsafe { ptr.add(1) };
-        let origin: *mut _ = addr_of_mut!(self.buffer);
-        let origin = origin.cast::<T>();
-        let offset = unsafe { ptr.offset_from(origin) };
-        if offset >= SIZE as isize {
-            origin
-        } else {
-            ptr
-        }
-    }
-}
+mod common;
+use common::{dropped, reset_dropped, Counted};
 
 #[test]
 fn on_stack() -> Result<(), Infallible> {
@@ -151,6 +51,26 @@ fn on_stack() -> Result<(), Infallible> {
     Ok(())
 }
 
+#[test]
+fn drops_remaining() {
+    reset_dropped();
+    {
+        stack_pin_init!(let buf = RingBuffer::<Counted, 4>::new());
+        assert!(buf.is_empty());
+        // Wrap around once, so the live elements are not contiguous.
+        for i in 0..3 {
+            assert!(buf.as_mut().push(Counted(i)));
+        }
+        assert_eq!(buf.as_mut().pop().map(|c| c.0), Some(0));
+        assert_eq!(buf.as_mut().pop().map(|c| c.0), Some(1));
+        assert!(buf.as_mut().push(Counted(3)));
+        assert!(buf.as_mut().push(Counted(4)));
+        assert!(!buf.is_empty());
+        assert_eq!(dropped(), 2);
+    }
+    assert_eq!(dropped(), 5);
+}
+
 #[derive(PartialEq, Eq, Debug)]
 pub struct EvenU64 {
     info: String,
@@ -161,7 +81,7 @@ impl EvenU64 {
     pub fn new2(value: u64) -> impl Init<Self, Error> {
         try_init!(Self {
             info: "Hello world!".to_owned(),
-            data: if value % 2 == 0 {
+            data: if value.is_multiple_of(2) {
                 value
             } else {
                 return Err(Error);
@@ -171,7 +91,7 @@ impl EvenU64 {
     pub fn new(value: u64) -> impl Init<Self, ()> {
         try_init!(Self {
             info: "Hello world!".to_owned(),
-            data: if value % 2 == 0 {
+            data: if value.is_multiple_of(2) {
                 value
             } else {
                 return Err(());
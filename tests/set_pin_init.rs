@@ -0,0 +1,53 @@
+#![cfg_attr(feature = "alloc", feature(allocator_api))]
+
+use core::convert::Infallible;
+use pinned_init::*;
+
+#[expect(unused_attributes)]
+#[path = "../examples/error.rs"]
+mod error;
+use error::Error;
+
+#[expect(unused_attributes)]
+#[path = "../examples/mutex.rs"]
+mod mutex;
+use mutex::*;
+
+mod common;
+use common::{dropped, reset_dropped, Counted};
+
+#[test]
+fn recycle_cmutex() {
+    let mut mtx = Box::pin_init(CMutex::new([1u64; 128])).unwrap();
+    let addr = &*mtx as *const CMutex<[u64; 128]>;
+    mtx.lock()[0] = 42;
+    for round in 0..4 {
+        // SAFETY: The initializer cannot fail and does not panic.
+        unsafe {
+            set_pin_init_unchecked::<_, Infallible>(
+                mtx.as_mut(),
+                CMutex::new(init_array_from_fn(|i| (i + round) as u64)),
+            )
+        }
+        .unwrap();
+        assert_eq!(mtx.lock()[0], round as u64);
+        assert_eq!(mtx.lock()[127], 127 + round as u64);
+    }
+    assert_eq!(&*mtx as *const CMutex<[u64; 128]>, addr);
+}
+
+#[test]
+fn failure_zeroes() {
+    reset_dropped();
+    let mut val = Box::pin(Counted(7));
+    set_pin_init::<_, Error>(val.as_mut(), Counted(8)).unwrap();
+    assert_eq!(val.0, 8);
+    assert_eq!(dropped(), 1);
+    // SAFETY: The initializer fails without touching the slot.
+    let fail = unsafe { pin_init_from_closure(|_| Err(Error)) };
+    assert!(set_pin_init(val.as_mut(), fail).is_err());
+    assert_eq!(dropped(), 2);
+    assert_eq!(val.0, 0);
+    drop(val);
+    assert_eq!(dropped(), 3);
+}
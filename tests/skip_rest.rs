@@ -0,0 +1,114 @@
+#![cfg_attr(feature = "alloc", feature(allocator_api))]
+
+use core::marker::PhantomPinned;
+use pinned_init::*;
+
+mod common;
+use common::{dropped, reset_dropped, Counted};
+
+#[expect(unused_attributes)]
+#[path = "../examples/error.rs"]
+mod error;
+use error::Error;
+
+#[pin_data]
+#[derive(Zeroable)]
+struct Foo {
+    a: Counted,
+    b: u32,
+    c: Counted,
+    #[pin]
+    pin: PhantomPinned,
+}
+
+fn foo(skip: bool, error: bool) -> impl PinInit<Foo, Error> {
+    try_pin_init!(Foo {
+        a: Counted(1),
+        b <- {
+            if skip {
+                skip_rest!();
+            }
+            fail_if(error)
+        },
+        c: Counted(3),
+        ..Zeroable::zeroed()
+    }? Error)
+}
+
+fn fail_if(error: bool) -> impl Init<u32, Error> {
+    // SAFETY: The closure does not touch `slot` and returns an error, or initializes `slot`.
+    unsafe {
+        init_from_closure(move |slot: *mut u32| {
+            if error {
+                Err(Error)
+            } else {
+                slot.write(2);
+                Ok(())
+            }
+        })
+    }
+}
+
+#[test]
+fn skip() {
+    reset_dropped();
+    {
+        stack_try_pin_init!(let foo = foo(true, false));
+        let foo = foo.unwrap();
+        // The already initialized field must not have been dropped.
+        assert_eq!(dropped(), 0);
+        assert_eq!((foo.a.0, foo.b, foo.c.0), (1, 0, 0));
+    }
+    assert_eq!(dropped(), 2);
+}
+
+#[test]
+fn no_skip() {
+    reset_dropped();
+    {
+        stack_try_pin_init!(let foo = foo(false, false));
+        let foo = foo.unwrap();
+        assert_eq!((foo.a.0, foo.b, foo.c.0), (1, 2, 3));
+    }
+    assert_eq!(dropped(), 2);
+}
+
+#[test]
+fn error_drops_initialized_fields() {
+    reset_dropped();
+    stack_try_pin_init!(let foo = foo(false, true));
+    assert_eq!(foo.err(), Some(Error));
+    assert_eq!(dropped(), 1);
+}
+
+#[test]
+fn skip_first_field() {
+    let skip = true;
+    let init = init!(Foo {
+        a: if skip { skip_rest!() } else { Counted(1) },
+        b: 2,
+        ..Zeroable::zeroed()
+    });
+    stack_pin_init!(let foo = init);
+    assert_eq!((foo.a.0, foo.b, foo.c.0), (0, 0, 0));
+}
+
+#[test]
+fn skip_in_loop() {
+    let init = init!(Foo {
+        a: Counted(1),
+        b: {
+            let mut sum = 0;
+            for i in 0.. {
+                if i == 5 {
+                    skip_rest!();
+                }
+                sum += i;
+            }
+            sum
+        },
+        ..Zeroable::zeroed()
+    });
+    stack_pin_init!(let foo = init);
+    assert_eq!((foo.a.0, foo.b), (1, 0));
+}
@@ -0,0 +1,108 @@
+use pinned_init::*;
+
+#[derive(Debug, PartialEq)]
+enum Error {
+    Invalid,
+}
+
+impl From<core::convert::Infallible> for Error {
+    fn from(e: core::convert::Infallible) -> Self {
+        match e {}
+    }
+}
+
+#[pin_data]
+struct BigStruct {
+    id: u64,
+    buf: [u8; 4096],
+}
+
+impl BigStruct {
+    fn new(id: u64) -> impl Init<Self, Error> {
+        try_init!(Self {
+            id,
+            buf <- zeroed(),
+        }? Error)
+    }
+}
+
+#[test]
+fn some() {
+    stack_try_pin_init!(let big: Option<BigStruct> = some_init(BigStruct::new(7)));
+    let big = big.unwrap();
+    let big = big.as_ref().get_ref().as_ref().unwrap();
+    assert_eq!(big.id, 7);
+    assert!(big.buf.iter().all(|b| *b == 0));
+}
+
+#[test]
+fn error() {
+    // SAFETY: The slot is never touched and we return an error.
+    let fail = unsafe { init_from_closure::<BigStruct, _>(|_| Err(Error::Invalid)) };
+    stack_try_pin_init!(let res: Option<BigStruct> = some_init(fail));
+    assert_eq!(res.err(), Some(Error::Invalid));
+}
+
+struct Slots {
+    first: Option<BigStruct>,
+    second: Option<BigStruct>,
+}
+
+#[test]
+fn in_struct() {
+    stack_try_pin_init!(let slots = try_init!(Slots {
+        first <- some_init(BigStruct::new(1)),
+        second: None,
+    }? Error));
+    let slots = slots.unwrap();
+    assert_eq!(slots.first.as_ref().map(|b| b.id), Some(1));
+    assert!(slots.second.is_none());
+}
+
+#[test]
+fn option_some() {
+    stack_try_pin_init!(let big: Option<BigStruct> = option_init(true, BigStruct::new(3)));
+    let big = big.unwrap();
+    assert_eq!(big.as_ref().get_ref().as_ref().map(|b| b.id), Some(3));
+}
+
+#[test]
+fn option_none_does_not_run_init() {
+    // SAFETY: The closure panics before touching the slot.
+    let unreachable =
+        unsafe { init_from_closure::<BigStruct, Error>(|_| panic!("initializer should not run")) };
+    stack_try_pin_init!(let big: Option<BigStruct> = option_init(false, unreachable));
+    assert!(big.unwrap().is_none());
+}
+
+#[test]
+fn option_error() {
+    // SAFETY: The slot is never touched and we return an error.
+    let fail = unsafe { init_from_closure::<BigStruct, _>(|_| Err(Error::Invalid)) };
+    stack_try_pin_init!(let res: Option<BigStruct> = option_init(true, fail));
+    assert_eq!(res.err(), Some(Error::Invalid));
+}
+
+#[test]
+fn option_niche() {
+    use core::num::NonZeroU32;
+
+    struct Handles {
+        irq: Option<NonZeroU32>,
+        name: Option<&'static str>,
+    }
+
+    let init = |present: bool| {
+        try_init!(Handles {
+            irq <- option_init::<_, Error>(present, NonZeroU32::new(5).unwrap()),
+            name <- option_init::<_, Error>(present, "uart"),
+        }? Error)
+    };
+    stack_try_pin_init!(let some = init(true));
+    let some = some.unwrap();
+    assert_eq!(some.irq, NonZeroU32::new(5));
+    assert_eq!(some.name, Some("uart"));
+    stack_try_pin_init!(let none = init(false));
+    let none = none.unwrap();
+    assert!(none.irq.is_none() && none.name.is_none());
+}
@@ -0,0 +1,92 @@
+#![cfg_attr(feature = "alloc", feature(allocator_api))]
+
+use core::{convert::Infallible, pin::Pin};
+use pinned_init::*;
+
+#[expect(unused_attributes)]
+#[path = "../examples/mutex.rs"]
+mod mutex;
+use mutex::*;
+
+#[expect(unused_attributes)]
+#[path = "../examples/error.rs"]
+mod error;
+use error::Error;
+
+#[pin_data]
+struct Foo {
+    a: usize,
+    b: u32,
+}
+
+fn foo() -> impl PinInit<Foo, Error> {
+    try_pin_init!(Foo { a: 42, b: 24 }? Error)
+}
+
+fn failing() -> impl PinInit<Foo, Error> {
+    // SAFETY: The closure does not touch `slot` and returns an error.
+    unsafe { pin_init_from_closure(|_| Err(Error)) }
+}
+
+#[test]
+fn infallible_without_annotation() {
+    stack_pin_init!(let value = pin_init!(Foo { a: 1, b: 2 }));
+    let value: Pin<&mut Foo> = value;
+    assert_eq!((value.a, value.b), (1, 2));
+}
+
+#[test]
+fn infallible_with_annotation() {
+    // `zeroed` is generic over its output, so the annotation is needed for inference.
+    stack_pin_init!(let val: u64 = zeroed());
+    assert_eq!(*val, 0);
+}
+
+#[test]
+fn result_without_annotation() {
+    stack_try_pin_init!(let value = foo());
+    let value = value.unwrap();
+    assert_eq!((value.a, value.b), (42, 24));
+    stack_try_pin_init!(let value = failing());
+    assert_eq!(value.err(), Some(Error));
+}
+
+#[test]
+fn result_with_annotation() {
+    stack_try_pin_init!(let value: Foo = foo());
+    assert_eq!(value.unwrap().a, 42);
+    stack_try_pin_init!(let val: u64 = zeroed());
+    let val: Result<Pin<&mut u64>, Infallible> = val;
+    assert_eq!(*val.unwrap(), 0);
+}
+
+fn question_mark_without_annotation(init: impl PinInit<Foo, Error>) -> Result<usize, Error> {
+    stack_try_pin_init!(let value =? init);
+    let value: Pin<&mut Foo> = value;
+    Ok(value.a)
+}
+
+fn question_mark_with_annotation() -> Result<u64, Infallible> {
+    // `zeroed` is generic over its output, so the annotation is needed for inference.
+    stack_try_pin_init!(let val: u64 =? zeroed());
+    Ok(*val)
+}
+
+#[test]
+fn question_mark() {
+    assert_eq!(question_mark_without_annotation(foo()), Ok(42));
+    assert_eq!(question_mark_without_annotation(failing()), Err(Error));
+    assert_eq!(question_mark_with_annotation(), Ok(0));
+}
+
+#[test]
+fn multiple_bindings() {
+    stack_pin_init!(
+        let a = CMutex::new(1),
+        let b = CMutex::new(2),
+        let c: CMutex<usize> = CMutex::new(0),
+    );
+    *c.lock() = *a.lock() + *b.lock();
+    *a.lock() += 10;
+    assert_eq!((*a.lock(), *b.lock(), *c.lock()), (11, 2, 3));
+}
@@ -0,0 +1,67 @@
+#![cfg(feature = "std")]
+
+use core::marker::PhantomPinned;
+use pinned_init::*;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+mod common;
+use common::{dropped, reset_dropped, Counted};
+
+#[pin_data(PinnedDrop)]
+struct Foo {
+    a: Counted,
+    b: Counted,
+    c: usize,
+    #[pin]
+    _pin: PhantomPinned,
+}
+
+#[pinned_drop]
+impl PinnedDrop for Foo {
+    fn drop(self: core::pin::Pin<&mut Self>) {
+        panic!("a partially initialized `Foo` was dropped");
+    }
+}
+
+fn panicking() -> impl Init<usize> {
+    // SAFETY: The closure panics before touching `slot`.
+    unsafe { init_from_closure(|_| panic!("initializer panicked")) }
+}
+
+#[test]
+fn panic_drops_initialized_fields_once() {
+    reset_dropped();
+    let res = catch_unwind(AssertUnwindSafe(|| {
+        stack_pin_init!(let _foo = pin_init!(Foo {
+            a: Counted(0),
+            b: Counted(1),
+            c <- panicking(),
+            _pin: PhantomPinned,
+        }));
+    }));
+    let payload = res.err().unwrap();
+    assert_eq!(
+        payload.downcast_ref::<&str>(),
+        Some(&"initializer panicked")
+    );
+    // `a` and `b` were dropped by the initializer while unwinding, the slot itself was not.
+    assert_eq!(dropped(), 2);
+}
+
+#[test]
+fn earlier_bindings_are_dropped() {
+    reset_dropped();
+    let res = catch_unwind(AssertUnwindSafe(|| {
+        stack_pin_init!(
+            let _first = Counted(0),
+            let _second = pin_init!(Foo {
+                a: Counted(1),
+                b: Counted(2),
+                c <- panicking(),
+                _pin: PhantomPinned,
+            }),
+        );
+    }));
+    assert!(res.is_err());
+    assert_eq!(dropped(), 3);
+}
@@ -0,0 +1,55 @@
+#![cfg_attr(feature = "alloc", feature(allocator_api))]
+
+use core::marker::PhantomPinned;
+use pinned_init::*;
+use std::sync::Arc;
+
+#[expect(unused_attributes)]
+#[path = "../examples/mutex.rs"]
+mod mutex;
+use mutex::*;
+
+#[derive(TransparentWrapper)]
+#[repr(transparent)]
+struct Counter(CMutex<usize>);
+
+#[derive(TransparentWrapper)]
+#[repr(transparent)]
+struct Named<T> {
+    inner: T,
+}
+
+#[pin_data]
+struct Pair {
+    #[pin]
+    counter: Counter,
+    id: Named<u32>,
+}
+
+#[test]
+fn wrap_mutex() {
+    let counter: core::pin::Pin<Arc<Counter>> = Arc::pin_init(CMutex::new(0).wrap()).unwrap();
+    *counter.0.lock() += 1;
+    assert_eq!(*counter.0.lock(), 1);
+}
+
+#[test]
+fn wrap_fields() {
+    stack_pin_init!(let pair = pin_init!(Pair {
+        counter <- CMutex::new(7).wrap(),
+        id <- init!(Named { inner: 3 }),
+    }));
+    assert_eq!(*pair.counter.0.lock(), 7);
+    assert_eq!(pair.id.inner, 3);
+}
+
+#[test]
+fn wrap_init() {
+    let named: Box<Named<[u8; 16]>> = Box::init(zeroed().wrap()).unwrap();
+    assert_eq!(named.inner, [0; 16]);
+    let named: Box<Named<u64>> = Box::init(<u64 as PinInit<u64>>::wrap(42)).unwrap();
+    assert_eq!(named.inner, 42);
+}
+
+assert_not_unpin!(Counter);
+assert_not_unpin!(Named<PhantomPinned>);
@@ -0,0 +1,70 @@
+#![cfg_attr(feature = "alloc", feature(allocator_api))]
+
+use core::convert::Infallible;
+use pinned_init::*;
+
+mod common;
+use common::{dropped, Counted};
+
+#[expect(unused_attributes)]
+#[path = "../examples/error.rs"]
+mod error;
+use error::Error;
+
+fn counted(n: usize) -> impl Init<Counted> {
+    // SAFETY: The closure always initializes `slot`.
+    unsafe {
+        init_from_closure(move |slot: *mut Counted| {
+            slot.write(Counted(n));
+            Ok(())
+        })
+    }
+}
+
+fn fail<T>() -> impl Init<T, Error> {
+    // SAFETY: The closure does not touch `slot` and returns an error.
+    unsafe { init_from_closure(|_| Err(Error)) }
+}
+
+#[test]
+fn pair() {
+    let pair: Box<([u8; 1024], [u16; 1024])> =
+        Box::init(init_tuple2::<_, _, _, _, Infallible>(zeroed(), zeroed())).unwrap();
+    assert!(pair.0.iter().all(|x| *x == 0));
+    assert!(pair.1.iter().all(|x| *x == 0));
+}
+
+#[test]
+fn pin_triple() {
+    stack_pin_init!(let triple = pin_init_tuple3::<_, _, _, _, _, _, Infallible>(
+        counted(42),
+        zeroed::<u32>(),
+        init_array_from_fn::<_, 4, _, Infallible>(|i| i as u8),
+    ));
+    assert_eq!(triple.0 .0, 42);
+    assert_eq!(triple.1, 0);
+    assert_eq!(triple.2, [0, 1, 2, 3]);
+}
+
+#[test]
+fn error_in_second_element() {
+    let before = dropped();
+    let res: Result<Box<(Counted, Counted)>, Error> =
+        Box::try_init(init_tuple2(counted(0), fail::<Counted>()));
+    assert_eq!(res.err(), Some(Error));
+    // The first element has been initialized and must have been dropped again.
+    assert_eq!(dropped(), before + 1);
+}
+
+#[test]
+fn error_in_last_element() {
+    let before = dropped();
+    stack_try_pin_init!(let res: (Counted, Counted, Counted, u8) = pin_init_tuple4(
+        counted(0),
+        counted(1),
+        counted(2),
+        fail::<u8>(),
+    ));
+    assert_eq!(res.err(), Some(Error));
+    assert_eq!(dropped(), before + 3);
+}
@@ -0,0 +1,110 @@
+use core::{
+    convert::Infallible,
+    marker::{PhantomData, PhantomPinned},
+};
+use pinned_init::*;
+
+#[pin_data]
+struct Foo<T, const N: usize> {
+    value: T,
+    array: [u8; N],
+    #[pin]
+    pin: PhantomPinned,
+}
+
+impl<T: Default, const N: usize> Foo<T, N> {
+    fn new() -> impl PinInit<Self> {
+        pin_init!(Foo::<T, N> {
+            value: T::default(),
+            array: [0; N],
+            pin: PhantomPinned,
+        })
+    }
+}
+
+const LEN: usize = 8;
+
+#[test]
+fn pin_init() {
+    let foo = Box::pin_init(pin_init!(Foo::<u32, LEN> {
+        value: 42,
+        array <- zeroed(),
+        pin: PhantomPinned,
+    }))
+    .unwrap();
+    assert_eq!(foo.value, 42);
+    assert_eq!(foo.array, [0; LEN]);
+}
+
+#[test]
+fn generic_params() {
+    let foo = Box::pin_init(Foo::<String, 4>::new()).unwrap();
+    assert_eq!(foo.value, "");
+    assert_eq!(foo.array.len(), 4);
+}
+
+#[test]
+fn try_pin_init_nested_generics() {
+    let foo = Box::pin_init(try_pin_init!(&this in Foo::<Vec<Option<u8>>, LEN,> {
+        value: vec![None],
+        array: [1; LEN],
+        pin: PhantomPinned,
+    }? Infallible))
+    .unwrap();
+    assert_eq!(foo.value, [None]);
+}
+
+#[pin_data]
+struct Bar<T> {
+    value: T,
+}
+
+#[test]
+fn init() {
+    let bar = Box::init(init!(Bar::<u64> { value <- zeroed() })).unwrap();
+    assert_eq!(bar.value, 0);
+    let bar = Box::init(try_init!(Bar::<&str> { value: "bar" }? Infallible)).unwrap();
+    assert_eq!(bar.value, "bar");
+}
+
+#[test]
+fn inferred() {
+    let foo = Box::pin_init(pin_init!(Foo {
+        value: 42u8,
+        array: [1; LEN],
+        pin: PhantomPinned,
+    }))
+    .unwrap();
+    let _: &Foo<u8, LEN> = &foo;
+    let bar: Box<Bar<u16>> = Box::init(init!(Bar { value <- zeroed() })).unwrap();
+    assert_eq!(bar.value, 0);
+}
+
+#[pin_data]
+struct Tagged<T> {
+    id: u32,
+    tag: PhantomData<T>,
+}
+
+impl<T> Tagged<T> {
+    fn new(id: u32) -> impl Init<Self> {
+        // `T` is inferred from the return type.
+        init!(Tagged {
+            id,
+            tag: PhantomData,
+        })
+    }
+}
+
+#[test]
+fn phantom_generic() {
+    let tagged = Box::init(init!(Tagged::<String> {
+        id: 1,
+        tag: PhantomData,
+    }))
+    .unwrap();
+    let _: &Tagged<String> = &tagged;
+    assert_eq!(tagged.id, 1);
+    let tagged = Box::init(Tagged::<()>::new(2)).unwrap();
+    assert_eq!(tagged.id, 2);
+}
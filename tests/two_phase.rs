@@ -0,0 +1,101 @@
+#![cfg_attr(feature = "alloc", feature(allocator_api))]
+
+use core::{marker::PhantomPinned, pin::Pin, ptr};
+use pinned_init::*;
+
+#[expect(unused_attributes)]
+#[path = "../examples/error.rs"]
+mod error;
+use error::Error;
+
+mod common;
+use common::{dropped, reset_dropped, Counted};
+
+#[derive(Zeroable)]
+struct Endpoint {
+    id: u32,
+    peer: *const Endpoint,
+    link: *const Link,
+}
+
+impl Endpoint {
+    fn peer(&self) -> &Endpoint {
+        // SAFETY: `peer` points to the other endpoint of the same pinned `Link`.
+        unsafe { &*self.peer }
+    }
+}
+
+#[pin_data]
+#[derive(Zeroable)]
+struct Link {
+    a: Endpoint,
+    b: Endpoint,
+    #[pin]
+    _pin: PhantomPinned,
+}
+
+impl Link {
+    fn new(a: u32, b: u32) -> impl PinInit<Self, Error> {
+        two_phase_pin_init!(Link, move |link: Pin<&mut Link>| {
+            if a == b {
+                return Err(Error);
+            }
+            // SAFETY: No field is moved out of `link`.
+            let link = unsafe { link.get_unchecked_mut() };
+            let this: *const Link = link;
+            link.a = Endpoint {
+                id: a,
+                peer: &link.b,
+                link: this,
+            };
+            link.b = Endpoint {
+                id: b,
+                peer: &link.a,
+                link: this,
+            };
+            Ok(())
+        })
+    }
+}
+
+#[test]
+fn mutual_references() {
+    stack_try_pin_init!(let link: Link = Link::new(1, 2));
+    let link = link.unwrap();
+    assert_eq!(link.a.peer().id, 2);
+    assert_eq!(link.b.peer().id, 1);
+    assert!(ptr::eq(link.a.peer().peer(), &link.a));
+    assert!(ptr::eq(link.a.link, &*link));
+    assert!(ptr::eq(link.b.link, &*link));
+}
+
+#[test]
+fn boxed() {
+    let link = Box::<Link>::try_pin_init(Link::new(3, 4)).unwrap();
+    assert!(ptr::eq(link.a.peer, &link.b));
+    assert_eq!(link.b.peer().id, 3);
+}
+
+#[pin_data]
+#[derive(Zeroable)]
+struct Tracked {
+    first: Counted,
+    second: Counted,
+}
+
+#[test]
+fn error_drops_value() {
+    reset_dropped();
+    stack_try_pin_init!(let res: Tracked = two_phase_pin_init!(Tracked, |t: Pin<&mut Tracked>| {
+        assert_eq!((t.first.0, t.second.0), (0, 0));
+        Err(Error)
+    }));
+    assert!(matches!(res, Err(Error)));
+    assert_eq!(dropped(), 2);
+}
+
+#[test]
+fn link_error() {
+    stack_try_pin_init!(let res: Link = Link::new(5, 5));
+    assert!(matches!(res, Err(Error)));
+}
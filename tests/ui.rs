@@ -6,6 +6,7 @@ fn compile_fail() {
     test_cases.compile_fail("tests/ui/compile-fail/pin_data/*.rs");
     test_cases.compile_fail("tests/ui/compile-fail/init/*.rs");
     test_cases.compile_fail("tests/ui/compile-fail/zeroable/*.rs");
+    test_cases.compile_fail("tests/ui/compile-fail/transparent_wrapper/*.rs");
 }
 
 #[cfg(not(any(miri, NO_UI_TESTS)))]
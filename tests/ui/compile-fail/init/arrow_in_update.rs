@@ -0,0 +1,18 @@
+use pinned_init::*;
+
+#[derive(Zeroable)]
+struct Foo {
+    a: u32,
+    b: u64,
+}
+
+fn main() {
+    let _ = init!(Foo {
+        a: 1,
+        ..Zeroable::zeroed() <- zeroed()
+    });
+    let _ = init!(Foo {
+        a: 1,
+        ..b <- zeroed()
+    });
+}
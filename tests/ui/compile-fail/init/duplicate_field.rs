@@ -0,0 +1,20 @@
+use pinned_init::*;
+
+#[pin_data]
+struct Foo {
+    a: usize,
+    b: usize,
+}
+
+fn main() {
+    let _ = init!(Foo {
+        a: 0,
+        b: 1,
+        a: 2,
+    });
+    let _ = pin_init!(Foo {
+        a <- 0,
+        b: 1,
+        a: 2,
+    });
+}
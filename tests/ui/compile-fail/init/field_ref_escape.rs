@@ -0,0 +1,15 @@
+use pinned_init::*;
+
+struct Node<'a> {
+    value: u32,
+    parent: &'a u32,
+}
+
+fn node<'a>() -> impl Init<Node<'a>> {
+    init!(Node {
+        value: 42,
+        parent: field_ref!(value),
+    })
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+use pinned_init::*;
+
+#[pin_data]
+struct Range {
+    start: usize,
+    end: usize,
+}
+
+fn main() {
+    let _ = init!(Range {
+        start: *field_ref!(end) - 8,
+        end: 8,
+    });
+}
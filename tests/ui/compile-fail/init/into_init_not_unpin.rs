@@ -0,0 +1,19 @@
+use core::marker::PhantomPinned;
+use pinned_init::*;
+
+#[pin_data]
+struct Foo {
+    a: usize,
+    #[pin]
+    _pin: PhantomPinned,
+}
+
+fn main() {
+    let _ = Box::init(
+        pin_init!(Foo {
+            a: 0,
+            _pin: PhantomPinned,
+        })
+        .into_init(),
+    );
+}
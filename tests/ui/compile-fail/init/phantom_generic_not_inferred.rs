@@ -0,0 +1,17 @@
+#![cfg_attr(feature = "alloc", feature(allocator_api))]
+
+use core::marker::PhantomData;
+use pinned_init::*;
+
+#[pin_data]
+struct Tagged<T> {
+    id: u32,
+    tag: PhantomData<T>,
+}
+
+fn main() {
+    let _ = Box::init(init!(Tagged {
+        id: 1,
+        tag: PhantomData,
+    }));
+}
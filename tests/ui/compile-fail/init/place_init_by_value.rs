@@ -0,0 +1,12 @@
+use core::convert::Infallible;
+use pinned_init::*;
+
+struct Buffer {
+    data: [u8; 1024],
+}
+
+fn store(_init: impl PlaceInit<Buffer, Infallible>) {}
+
+fn main() {
+    store(Buffer { data: [0; 1024] });
+}
@@ -0,0 +1,16 @@
+#![cfg_attr(feature = "alloc", feature(allocator_api))]
+
+use pinned_init::*;
+
+#[pin_data]
+struct Packet<'a> {
+    payload: &'a [u8],
+}
+
+fn main() {
+    let _packet = {
+        let buf = vec![1, 2, 3];
+        let payload = &buf[..];
+        Box::pin_init(pin_init!(Packet { payload }))
+    };
+}
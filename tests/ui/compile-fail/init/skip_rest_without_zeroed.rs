@@ -0,0 +1,15 @@
+use pinned_init::*;
+
+#[derive(Zeroable)]
+struct Foo {
+    a: usize,
+    b: usize,
+}
+
+fn main() {
+    let skip = true;
+    let _ = init!(Foo {
+        a: if skip { skip_rest!() } else { 0 },
+        b: 0,
+    });
+}
@@ -0,0 +1,36 @@
+use pinned_init::*;
+
+#[derive(Zeroable)]
+struct Foo {
+    a: u32,
+    b: u64,
+}
+
+#[derive(Zeroable)]
+struct Bar {
+    a: u32,
+}
+
+mod other {
+    #[allow(unused_imports)]
+    pub use pinned_init::Zeroable;
+}
+
+fn main() {
+    let _ = init!(Foo {
+        a: 1,
+        ..<Bar as Zeroable>::zeroed()
+    });
+    let _ = init!(Foo {
+        a: 1,
+        ..<[u8; 4] as Zeroable>::zeroed()
+    });
+    let _ = init!(Foo {
+        a: 1,
+        ..<Foo as other::Zeroable>::zeroed()
+    });
+    let _ = init!(Foo {
+        a: 1,
+        ..other::Zeroable::zeroed()
+    });
+}
@@ -0,0 +1,11 @@
+use pinned_init::*;
+
+#[pin_data]
+struct Foo {
+    ptr: *const u8,
+    buf: [u8; 64],
+}
+
+assert_not_unpin!(Foo);
+
+fn main() {}
@@ -0,0 +1,14 @@
+use core::marker::PhantomPinned;
+use pinned_init::*;
+
+struct Waiter(PhantomPinned);
+
+#[pin_data]
+struct Foo {
+    #[cfg_attr(any(), pin)]
+    waiter: Waiter,
+}
+
+assert_not_unpin!(Foo);
+
+fn main() {}
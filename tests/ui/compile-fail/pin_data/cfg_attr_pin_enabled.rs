@@ -0,0 +1,14 @@
+use core::marker::PhantomPinned;
+use pinned_init::*;
+
+#[pin_data]
+struct Foo {
+    #[cfg_attr(all(), pin)]
+    _pin: PhantomPinned,
+}
+
+fn assert_unpin<T: Unpin>() {}
+
+fn main() {
+    assert_unpin::<Foo>();
+}
@@ -0,0 +1,17 @@
+use pinned_init::*;
+
+#[pin_data]
+struct Foo {
+    #[cfg_attr(feature = "a", pin)]
+    a: usize,
+    #[cfg_attr(feature = "b", pin)]
+    b: usize,
+    #[cfg_attr(feature = "c", pin)]
+    c: usize,
+    #[cfg_attr(feature = "d", pin)]
+    d: usize,
+    #[cfg_attr(feature = "e", pin)]
+    e: usize,
+}
+
+fn main() {}
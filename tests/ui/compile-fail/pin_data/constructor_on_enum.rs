@@ -0,0 +1,9 @@
+use pinned_init::*;
+
+#[pin_data(constructor)]
+enum Foo {
+    A(u8),
+    B,
+}
+
+fn main() {}
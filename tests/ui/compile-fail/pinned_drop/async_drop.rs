@@ -0,0 +1,15 @@
+use core::{marker::PhantomPinned, pin::Pin};
+use pinned_init::*;
+
+#[pin_data(PinnedDrop)]
+struct Foo {
+    #[pin]
+    _pin: PhantomPinned,
+}
+
+#[pinned_drop]
+impl PinnedDrop for Foo {
+    async fn drop(self: Pin<&mut Self>) {}
+}
+
+fn main() {}
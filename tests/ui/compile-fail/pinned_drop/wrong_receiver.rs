@@ -1,7 +1,11 @@
+use core::marker::PhantomPinned;
 use pinned_init::*;
 
 #[pin_data(PinnedDrop)]
-struct Foo {}
+struct Foo {
+    #[pin]
+    _pin: PhantomPinned,
+}
 
 #[pinned_drop]
 impl PinnedDrop for Foo {
@@ -0,0 +1,11 @@
+use pinned_init::*;
+
+#[derive(TransparentWrapper)]
+#[repr(transparent)]
+struct Wrapper(u32);
+
+impl Drop for Wrapper {
+    fn drop(&mut self) {}
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use pinned_init::*;
+
+#[derive(InitForward)]
+#[repr(transparent)]
+struct Wrapper {
+    inner: u32,
+    other: (),
+}
+
+fn main() {}
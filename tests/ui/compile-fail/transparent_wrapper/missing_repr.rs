@@ -0,0 +1,6 @@
+use pinned_init::*;
+
+#[derive(TransparentWrapper)]
+struct Wrapper(u32);
+
+fn main() {}
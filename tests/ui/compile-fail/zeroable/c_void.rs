@@ -0,0 +1,6 @@
+use core::ffi::c_void;
+use pinned_init::*;
+
+fn main() {
+    let _ = Box::init(zeroed::<c_void>());
+}
@@ -0,0 +1,9 @@
+use pinned_init::*;
+
+#[derive(Zeroable, FromBytes)]
+struct Flags {
+    valid: bool,
+    raw: u32,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use pinned_init::*;
+
+struct Foo {
+    a: usize,
+    b: &'static str,
+}
+
+fn main() {
+    let _ = Box::init(zeroed::<Foo>());
+}
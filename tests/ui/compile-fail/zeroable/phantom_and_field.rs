@@ -0,0 +1,13 @@
+use core::marker::PhantomData;
+use pinned_init::*;
+
+struct Token;
+
+#[derive(Zeroable)]
+struct Foo<T>(T, PhantomData<T>);
+
+fn assert_zeroable<T: Zeroable>() {}
+
+fn main() {
+    assert_zeroable::<Foo<Token>>();
+}
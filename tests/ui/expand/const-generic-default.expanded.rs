@@ -0,0 +1,59 @@
+use pinned_init::*;
+struct Array<const N: usize = 0> {
+    array: [u8; N],
+}
+const _: () = {
+    struct __ThePinData<const N: usize> {
+        __phantom: ::core::marker::PhantomData<fn(Array<N>) -> Array<N>>,
+    }
+    impl<const N: usize> ::core::clone::Clone for __ThePinData<N> {
+        fn clone(&self) -> Self {
+            *self
+        }
+    }
+    impl<const N: usize> ::core::marker::Copy for __ThePinData<N> {}
+    #[allow(dead_code)]
+    #[expect(clippy::missing_safety_doc)]
+    impl<const N: usize> __ThePinData<N> {
+        unsafe fn array<E>(
+            self,
+            slot: *mut [u8; N],
+            init: impl ::pinned_init::Init<[u8; N], E>,
+        ) -> ::core::result::Result<(), E> {
+            unsafe { ::pinned_init::Init::__init(init, slot) }
+        }
+    }
+    unsafe impl<const N: usize> ::pinned_init::__internal::HasPinData for Array<N> {
+        type PinData = __ThePinData<N>;
+        unsafe fn __pin_data() -> Self::PinData {
+            __ThePinData {
+                __phantom: ::core::marker::PhantomData,
+            }
+        }
+    }
+    unsafe impl<const N: usize> ::pinned_init::__internal::PinData for __ThePinData<N> {
+        type Datee = Array<N>;
+    }
+    #[allow(dead_code)]
+    struct __Unpin<'__pin, const N: usize> {
+        __phantom_pin: ::core::marker::PhantomData<fn(&'__pin ()) -> &'__pin ()>,
+        __phantom: ::core::marker::PhantomData<fn(Array<N>) -> Array<N>>,
+    }
+    #[doc(hidden)]
+    impl<'__pin, const N: usize> ::core::marker::Unpin for Array<N>
+    where
+        __Unpin<'__pin, N>: ::core::marker::Unpin,
+    {}
+    trait MustNotImplDrop {}
+    #[expect(drop_bounds)]
+    impl<T: ::core::ops::Drop> MustNotImplDrop for T {}
+    impl<const N: usize> MustNotImplDrop for Array<N> {}
+    #[expect(non_camel_case_types)]
+    trait UselessPinnedDropImpl_you_need_to_specify_PinnedDrop {}
+    impl<
+        T: ::pinned_init::PinnedDrop,
+    > UselessPinnedDropImpl_you_need_to_specify_PinnedDrop for T {}
+    impl<const N: usize> UselessPinnedDropImpl_you_need_to_specify_PinnedDrop
+    for Array<N> {}
+};
+fn main() {}
@@ -0,0 +1,8 @@
+use pinned_init::*;
+
+#[pin_data]
+struct Array<const N: usize = 0> {
+    array: [u8; N],
+}
+
+fn main() {}
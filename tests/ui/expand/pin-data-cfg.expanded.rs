@@ -0,0 +1,156 @@
+use core::marker::PhantomPinned;
+use pinned_init::*;
+struct Foo {
+    enabled: PhantomPinned,
+    value: usize,
+}
+const _: () = {
+    struct __ThePinData {
+        __phantom: ::core::marker::PhantomData<fn(Foo) -> Foo>,
+    }
+    impl ::core::clone::Clone for __ThePinData {
+        fn clone(&self) -> Self {
+            *self
+        }
+    }
+    impl ::core::marker::Copy for __ThePinData {}
+    #[allow(dead_code)]
+    #[expect(clippy::missing_safety_doc)]
+    impl __ThePinData {
+        unsafe fn enabled<E>(
+            self,
+            slot: *mut PhantomPinned,
+            init: impl ::pinned_init::PinInit<PhantomPinned, E>,
+        ) -> ::core::result::Result<(), E> {
+            unsafe { ::pinned_init::PinInit::__pinned_init(init, slot) }
+        }
+        unsafe fn value<E>(
+            self,
+            slot: *mut usize,
+            init: impl ::pinned_init::Init<usize, E>,
+        ) -> ::core::result::Result<(), E> {
+            unsafe { ::pinned_init::Init::__init(init, slot) }
+        }
+    }
+    unsafe impl ::pinned_init::__internal::HasPinData for Foo {
+        type PinData = __ThePinData;
+        unsafe fn __pin_data() -> Self::PinData {
+            __ThePinData {
+                __phantom: ::core::marker::PhantomData,
+            }
+        }
+    }
+    unsafe impl ::pinned_init::__internal::PinData for __ThePinData {
+        type Datee = Foo;
+    }
+    #[allow(dead_code)]
+    struct __Unpin<'__pin> {
+        __phantom_pin: ::core::marker::PhantomData<fn(&'__pin ()) -> &'__pin ()>,
+        __phantom: ::core::marker::PhantomData<fn(Foo) -> Foo>,
+        enabled: PhantomPinned,
+    }
+    #[doc(hidden)]
+    impl<'__pin> ::core::marker::Unpin for Foo
+    where
+        __Unpin<'__pin>: ::core::marker::Unpin,
+    {}
+    trait MustNotImplDrop {}
+    #[expect(drop_bounds)]
+    impl<T: ::core::ops::Drop> MustNotImplDrop for T {}
+    impl MustNotImplDrop for Foo {}
+    #[expect(non_camel_case_types)]
+    trait UselessPinnedDropImpl_you_need_to_specify_PinnedDrop {}
+    impl<
+        T: ::pinned_init::PinnedDrop,
+    > UselessPinnedDropImpl_you_need_to_specify_PinnedDrop for T {}
+    impl UselessPinnedDropImpl_you_need_to_specify_PinnedDrop for Foo {}
+};
+fn main() {
+    let _ = {
+        struct __InitOk;
+        let data = unsafe {
+            use ::pinned_init::__internal::GetPinData;
+            Foo::__get_pin_data()
+        };
+        let init = ::pinned_init::__internal::PinData::make_closure::<
+            _,
+            __InitOk,
+            ::core::convert::Infallible,
+        >(
+            data,
+            move |slot| {
+                {
+                    struct __InitOk;
+                    #[allow(unused_macros)]
+                    macro_rules! field_ref {
+                        ($name:ident) => {
+                            ::pinned_init::macros::paste! { [< __ $name _ref >] ! () }
+                        };
+                    }
+                    {
+                        let enabled = PhantomPinned;
+                        unsafe { ::core::ptr::write(&raw mut (*slot).enabled, enabled) };
+                    }
+                    let __enabled_guard = unsafe {
+                        ::pinned_init::__internal::DropGuard::new_field(
+                            &raw mut (*slot).enabled,
+                        )
+                    };
+                    #[allow(unused_macros)]
+                    macro_rules! __enabled_ref {
+                        () => {
+                            unsafe { ::pinned_init::__internal::field_ref(& slot,
+                            ::core::ptr::addr_of!((* slot).enabled)) }
+                        };
+                    }
+                    {
+                        let value = 0;
+                        unsafe { ::core::ptr::write(&raw mut (*slot).value, value) };
+                    }
+                    let __value_guard = unsafe {
+                        ::pinned_init::__internal::DropGuard::new_field(
+                            &raw mut (*slot).value,
+                        )
+                    };
+                    #[allow(unused_macros)]
+                    macro_rules! __value_ref {
+                        () => {
+                            unsafe { ::pinned_init::__internal::field_ref(& slot,
+                            ::core::ptr::addr_of!((* slot).value)) }
+                        };
+                    }
+                    ::core::mem::forget(__value_guard);
+                    ::core::mem::forget(__enabled_guard);
+                    #[allow(unreachable_code, clippy::diverging_sub_expression)]
+                    let _ = || {
+                        #[allow(
+                            non_camel_case_types,
+                            dead_code,
+                            clippy::extra_unused_type_parameters
+                        )]
+                        fn __check_duplicate_fields<enabled, value>() {}
+                        unsafe {
+                            ::core::ptr::write(
+                                slot,
+                                Foo {
+                                    enabled: ::core::panicking::panic("explicit panic"),
+                                    value: ::core::panicking::panic("explicit panic"),
+                                },
+                            );
+                        };
+                    };
+                }
+                Ok(__InitOk)
+            },
+        );
+        let init = move |
+            slot,
+        | -> ::core::result::Result<(), ::core::convert::Infallible> {
+            init(slot).map(|__InitOk| ())
+        };
+        let init = unsafe {
+            ::pinned_init::pin_init_from_closure::<_, ::core::convert::Infallible>(init)
+        };
+        init
+    };
+}
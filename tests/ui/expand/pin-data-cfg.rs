@@ -0,0 +1,27 @@
+use core::marker::PhantomPinned;
+use pinned_init::*;
+
+#[cfg(any())]
+struct Disabled;
+
+#[pin_data]
+struct Foo {
+    // `cfg(all())` is always enabled.
+    #[cfg(all())]
+    #[pin]
+    enabled: PhantomPinned,
+    // `cfg(any())` is always disabled, so `Disabled` must not appear anywhere.
+    #[cfg(any())]
+    #[pin]
+    disabled: Disabled,
+    #[cfg(any())]
+    also_disabled: Disabled,
+    value: usize,
+}
+
+fn main() {
+    let _ = pin_init!(Foo {
+        enabled: PhantomPinned,
+        value: 0,
+    });
+}
@@ -0,0 +1,158 @@
+use core::marker::PhantomPinned;
+use pinned_init::*;
+pub struct Foo<T> {
+    pin: PhantomPinned,
+    value: T,
+}
+const _: () = {
+    pub struct __ThePinData<T> {
+        __phantom: ::core::marker::PhantomData<fn(Foo<T>) -> Foo<T>>,
+    }
+    impl<T> ::core::clone::Clone for __ThePinData<T> {
+        fn clone(&self) -> Self {
+            *self
+        }
+    }
+    impl<T> ::core::marker::Copy for __ThePinData<T> {}
+    #[allow(dead_code)]
+    #[expect(clippy::missing_safety_doc)]
+    impl<T> __ThePinData<T> {
+        unsafe fn pin<E>(
+            self,
+            slot: *mut PhantomPinned,
+            init: impl ::pinned_init::PinInit<PhantomPinned, E>,
+        ) -> ::core::result::Result<(), E> {
+            unsafe { ::pinned_init::PinInit::__pinned_init(init, slot) }
+        }
+        unsafe fn value<E>(
+            self,
+            slot: *mut T,
+            init: impl ::pinned_init::Init<T, E>,
+        ) -> ::core::result::Result<(), E> {
+            unsafe { ::pinned_init::Init::__init(init, slot) }
+        }
+    }
+    unsafe impl<T> ::pinned_init::__internal::HasPinData for Foo<T> {
+        type PinData = __ThePinData<T>;
+        unsafe fn __pin_data() -> Self::PinData {
+            __ThePinData {
+                __phantom: ::core::marker::PhantomData,
+            }
+        }
+    }
+    unsafe impl<T> ::pinned_init::__internal::PinData for __ThePinData<T> {
+        type Datee = Foo<T>;
+    }
+    #[allow(dead_code)]
+    struct __Unpin<'__pin, T> {
+        __phantom_pin: ::core::marker::PhantomData<fn(&'__pin ()) -> &'__pin ()>,
+        __phantom: ::core::marker::PhantomData<fn(Foo<T>) -> Foo<T>>,
+        pin: PhantomPinned,
+    }
+    #[doc(hidden)]
+    impl<'__pin, T> ::core::marker::Unpin for Foo<T>
+    where
+        __Unpin<'__pin, T>: ::core::marker::Unpin,
+    {}
+    trait MustNotImplDrop {}
+    #[expect(drop_bounds)]
+    impl<T: ::core::ops::Drop> MustNotImplDrop for T {}
+    impl<T> MustNotImplDrop for Foo<T> {}
+    #[expect(non_camel_case_types)]
+    trait UselessPinnedDropImpl_you_need_to_specify_PinnedDrop {}
+    impl<
+        T: ::pinned_init::PinnedDrop,
+    > UselessPinnedDropImpl_you_need_to_specify_PinnedDrop for T {}
+    impl<T> UselessPinnedDropImpl_you_need_to_specify_PinnedDrop for Foo<T> {}
+};
+impl<T> Foo<T> {
+    /// Creates a pin-initializer from one initializer for every field, in declaration order.
+    #[allow(dead_code, clippy::too_many_arguments)]
+    pub fn new_pin_init<E>(
+        pin: impl ::pinned_init::PinInit<PhantomPinned, E>,
+        value: impl ::pinned_init::Init<T, E>,
+    ) -> impl ::pinned_init::PinInit<Self, E> {
+        {
+            struct __InitOk;
+            let data = unsafe {
+                use ::pinned_init::__internal::GetPinData;
+                Foo::__get_pin_data()
+            };
+            let init = ::pinned_init::__internal::PinData::make_closure::<
+                _,
+                __InitOk,
+                E,
+            >(
+                data,
+                move |slot| {
+                    {
+                        struct __InitOk;
+                        #[allow(unused_macros)]
+                        macro_rules! field_ref {
+                            ($name:ident) => {
+                                ::pinned_init::macros::paste! { [< __ $name _ref >] ! () }
+                            };
+                        }
+                        let init = pin;
+                        unsafe { data.pin(&raw mut (*slot).pin, init)? };
+                        let __pin_guard = unsafe {
+                            ::pinned_init::__internal::DropGuard::new_field(
+                                &raw mut (*slot).pin,
+                            )
+                        };
+                        #[allow(unused_macros)]
+                        macro_rules! __pin_ref {
+                            () => {
+                                unsafe { ::pinned_init::__internal::field_ref(& slot,
+                                ::core::ptr::addr_of!((* slot).pin)) }
+                            };
+                        }
+                        let init = value;
+                        unsafe { data.value(&raw mut (*slot).value, init)? };
+                        let __value_guard = unsafe {
+                            ::pinned_init::__internal::DropGuard::new_field(
+                                &raw mut (*slot).value,
+                            )
+                        };
+                        #[allow(unused_macros)]
+                        macro_rules! __value_ref {
+                            () => {
+                                unsafe { ::pinned_init::__internal::field_ref(& slot,
+                                ::core::ptr::addr_of!((* slot).value)) }
+                            };
+                        }
+                        ::core::mem::forget(__value_guard);
+                        ::core::mem::forget(__pin_guard);
+                        #[allow(unreachable_code, clippy::diverging_sub_expression)]
+                        let _ = || {
+                            #[allow(
+                                non_camel_case_types,
+                                dead_code,
+                                clippy::extra_unused_type_parameters
+                            )]
+                            fn __check_duplicate_fields<pin, value>() {}
+                            unsafe {
+                                ::core::ptr::write(
+                                    slot,
+                                    Foo {
+                                        pin: ::core::panicking::panic("explicit panic"),
+                                        value: ::core::panicking::panic("explicit panic"),
+                                    },
+                                );
+                            };
+                        };
+                    }
+                    Ok(__InitOk)
+                },
+            );
+            let init = move |slot| -> ::core::result::Result<(), E> {
+                init(slot).map(|__InitOk| ())
+            };
+            let init = unsafe { ::pinned_init::pin_init_from_closure::<_, E>(init) };
+            init
+        }
+    }
+}
+fn main() {
+    let _ = Foo::new_pin_init::<core::convert::Infallible>(PhantomPinned, 0u8);
+}
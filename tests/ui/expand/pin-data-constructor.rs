@@ -0,0 +1,13 @@
+use core::marker::PhantomPinned;
+use pinned_init::*;
+
+#[pin_data(constructor)]
+pub struct Foo<T> {
+    #[pin]
+    pin: PhantomPinned,
+    value: T,
+}
+
+fn main() {
+    let _ = Foo::new_pin_init::<core::convert::Infallible>(PhantomPinned, 0u8);
+}
@@ -0,0 +1,85 @@
+use pinned_init::*;
+struct Config<T> {
+    flags: u32,
+    value: T,
+}
+#[automatically_derived]
+unsafe impl<T: ::pinned_init::Zeroable> ::pinned_init::Zeroable for Config<T> {}
+const _: () = {
+    fn assert_zeroable<T: ?::core::marker::Sized + ::pinned_init::Zeroable>() {}
+    fn ensure_zeroable<T: ::pinned_init::Zeroable>() {
+        assert_zeroable::<u32>();
+        assert_zeroable::<T>();
+    }
+};
+const _: () = {
+    struct __ThePinData<T> {
+        __phantom: ::core::marker::PhantomData<fn(Config<T>) -> Config<T>>,
+    }
+    impl<T> ::core::clone::Clone for __ThePinData<T> {
+        fn clone(&self) -> Self {
+            *self
+        }
+    }
+    impl<T> ::core::marker::Copy for __ThePinData<T> {}
+    #[allow(dead_code)]
+    #[expect(clippy::missing_safety_doc)]
+    impl<T> __ThePinData<T> {
+        unsafe fn flags<E>(
+            self,
+            slot: *mut u32,
+            init: impl ::pinned_init::Init<u32, E>,
+        ) -> ::core::result::Result<(), E> {
+            unsafe { ::pinned_init::Init::__init(init, slot) }
+        }
+        unsafe fn value<E>(
+            self,
+            slot: *mut T,
+            init: impl ::pinned_init::Init<T, E>,
+        ) -> ::core::result::Result<(), E> {
+            unsafe { ::pinned_init::Init::__init(init, slot) }
+        }
+    }
+    unsafe impl<T> ::pinned_init::__internal::HasPinData for Config<T> {
+        type PinData = __ThePinData<T>;
+        unsafe fn __pin_data() -> Self::PinData {
+            __ThePinData {
+                __phantom: ::core::marker::PhantomData,
+            }
+        }
+    }
+    unsafe impl<T> ::pinned_init::__internal::PinData for __ThePinData<T> {
+        type Datee = Config<T>;
+    }
+    #[allow(dead_code)]
+    struct __Unpin<'__pin, T> {
+        __phantom_pin: ::core::marker::PhantomData<fn(&'__pin ()) -> &'__pin ()>,
+        __phantom: ::core::marker::PhantomData<fn(Config<T>) -> Config<T>>,
+    }
+    #[doc(hidden)]
+    impl<'__pin, T> ::core::marker::Unpin for Config<T>
+    where
+        __Unpin<'__pin, T>: ::core::marker::Unpin,
+    {}
+    trait MustNotImplDrop {}
+    #[expect(drop_bounds)]
+    impl<T: ::core::ops::Drop> MustNotImplDrop for T {}
+    impl<T> MustNotImplDrop for Config<T> {}
+    #[expect(non_camel_case_types)]
+    trait UselessPinnedDropImpl_you_need_to_specify_PinnedDrop {}
+    impl<
+        T: ::pinned_init::PinnedDrop,
+    > UselessPinnedDropImpl_you_need_to_specify_PinnedDrop for T {}
+    impl<T> UselessPinnedDropImpl_you_need_to_specify_PinnedDrop for Config<T> {}
+};
+impl<T> ::core::default::Default for Config<T>
+where
+    Config<T>: ::pinned_init::Zeroable,
+{
+    fn default() -> Self {
+        ::pinned_init::zeroed_value()
+    }
+}
+fn main() {
+    let _ = Config::<u64>::default();
+}
@@ -0,0 +1,11 @@
+use pinned_init::*;
+
+#[pin_data(Zeroable, Default)]
+struct Config<T> {
+    flags: u32,
+    value: T,
+}
+
+fn main() {
+    let _ = Config::<u64>::default();
+}
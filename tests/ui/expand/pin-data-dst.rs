@@ -0,0 +1,9 @@
+use pinned_init::*;
+
+#[pin_data]
+struct Foo {
+    header: u32,
+    tail: [u8],
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+use core::marker::PhantomPinned;
+use pinned_init::*;
+
+#[pin_data]
+enum Foo {
+    Pinned {
+        #[pin]
+        pin: PhantomPinned,
+        value: usize,
+    },
+    Unpinned(usize),
+}
+
+fn main() {}
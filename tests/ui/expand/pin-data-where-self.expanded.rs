@@ -0,0 +1,215 @@
+use core::marker::PhantomPinned;
+use pinned_init::*;
+trait Queue {
+    type Item;
+}
+struct Foo<T>
+where
+    Foo<T>: Queue,
+    <Foo<T> as Queue>::Item: Clone,
+    T: Into<Option<<Foo<T> as Queue>::Item>>,
+{
+    pin: PhantomPinned,
+    value: T,
+}
+const _: () = {
+    struct __ThePinData<T>
+    where
+        Foo<T>: Queue,
+        <Foo<T> as Queue>::Item: Clone,
+        T: Into<Option<<Foo<T> as Queue>::Item>>,
+    {
+        __phantom: ::core::marker::PhantomData<fn(Foo<T>) -> Foo<T>>,
+    }
+    impl<T> ::core::clone::Clone for __ThePinData<T>
+    where
+        Foo<T>: Queue,
+        <Foo<T> as Queue>::Item: Clone,
+        T: Into<Option<<Foo<T> as Queue>::Item>>,
+    {
+        fn clone(&self) -> Self {
+            *self
+        }
+    }
+    impl<T> ::core::marker::Copy for __ThePinData<T>
+    where
+        Foo<T>: Queue,
+        <Foo<T> as Queue>::Item: Clone,
+        T: Into<Option<<Foo<T> as Queue>::Item>>,
+    {}
+    #[allow(dead_code)]
+    #[expect(clippy::missing_safety_doc)]
+    impl<T> __ThePinData<T>
+    where
+        Foo<T>: Queue,
+        <Foo<T> as Queue>::Item: Clone,
+        T: Into<Option<<Foo<T> as Queue>::Item>>,
+    {
+        unsafe fn pin<E>(
+            self,
+            slot: *mut PhantomPinned,
+            init: impl ::pinned_init::PinInit<PhantomPinned, E>,
+        ) -> ::core::result::Result<(), E> {
+            unsafe { ::pinned_init::PinInit::__pinned_init(init, slot) }
+        }
+        unsafe fn value<E>(
+            self,
+            slot: *mut T,
+            init: impl ::pinned_init::Init<T, E>,
+        ) -> ::core::result::Result<(), E> {
+            unsafe { ::pinned_init::Init::__init(init, slot) }
+        }
+    }
+    unsafe impl<T> ::pinned_init::__internal::HasPinData for Foo<T>
+    where
+        Foo<T>: Queue,
+        <Foo<T> as Queue>::Item: Clone,
+        T: Into<Option<<Foo<T> as Queue>::Item>>,
+    {
+        type PinData = __ThePinData<T>;
+        unsafe fn __pin_data() -> Self::PinData {
+            __ThePinData {
+                __phantom: ::core::marker::PhantomData,
+            }
+        }
+    }
+    unsafe impl<T> ::pinned_init::__internal::PinData for __ThePinData<T>
+    where
+        Foo<T>: Queue,
+        <Foo<T> as Queue>::Item: Clone,
+        T: Into<Option<<Foo<T> as Queue>::Item>>,
+    {
+        type Datee = Foo<T>;
+    }
+    #[allow(dead_code)]
+    struct __Unpin<'__pin, T>
+    where
+        Foo<T>: Queue,
+        <Foo<T> as Queue>::Item: Clone,
+        T: Into<Option<<Foo<T> as Queue>::Item>>,
+    {
+        __phantom_pin: ::core::marker::PhantomData<fn(&'__pin ()) -> &'__pin ()>,
+        __phantom: ::core::marker::PhantomData<fn(Foo<T>) -> Foo<T>>,
+        pin: PhantomPinned,
+    }
+    #[doc(hidden)]
+    impl<'__pin, T> ::core::marker::Unpin for Foo<T>
+    where
+        __Unpin<'__pin, T>: ::core::marker::Unpin,
+        Foo<T>: Queue,
+        <Foo<T> as Queue>::Item: Clone,
+        T: Into<Option<<Foo<T> as Queue>::Item>>,
+    {}
+    trait MustNotImplDrop {}
+    #[expect(drop_bounds)]
+    impl<T: ::core::ops::Drop> MustNotImplDrop for T {}
+    impl<T> MustNotImplDrop for Foo<T>
+    where
+        Foo<T>: Queue,
+        <Foo<T> as Queue>::Item: Clone,
+        T: Into<Option<<Foo<T> as Queue>::Item>>,
+    {}
+    #[expect(non_camel_case_types)]
+    trait UselessPinnedDropImpl_you_need_to_specify_PinnedDrop {}
+    impl<
+        T: ::pinned_init::PinnedDrop,
+    > UselessPinnedDropImpl_you_need_to_specify_PinnedDrop for T {}
+    impl<T> UselessPinnedDropImpl_you_need_to_specify_PinnedDrop for Foo<T>
+    where
+        Foo<T>: Queue,
+        <Foo<T> as Queue>::Item: Clone,
+        T: Into<Option<<Foo<T> as Queue>::Item>>,
+    {}
+};
+impl<T: Into<Option<u8>>> Queue for Foo<T> {
+    type Item = u8;
+}
+fn main() {
+    let _ = {
+        struct __InitOk;
+        let data = unsafe {
+            use ::pinned_init::__internal::GetPinData;
+            Foo::__get_pin_data()
+        };
+        let init = ::pinned_init::__internal::PinData::make_closure::<
+            _,
+            __InitOk,
+            ::core::convert::Infallible,
+        >(
+            data,
+            move |slot| {
+                {
+                    struct __InitOk;
+                    #[allow(unused_macros)]
+                    macro_rules! field_ref {
+                        ($name:ident) => {
+                            ::pinned_init::macros::paste! { [< __ $name _ref >] ! () }
+                        };
+                    }
+                    {
+                        let pin = PhantomPinned;
+                        unsafe { ::core::ptr::write(&raw mut (*slot).pin, pin) };
+                    }
+                    let __pin_guard = unsafe {
+                        ::pinned_init::__internal::DropGuard::new_field(
+                            &raw mut (*slot).pin,
+                        )
+                    };
+                    #[allow(unused_macros)]
+                    macro_rules! __pin_ref {
+                        () => {
+                            unsafe { ::pinned_init::__internal::field_ref(& slot,
+                            ::core::ptr::addr_of!((* slot).pin)) }
+                        };
+                    }
+                    {
+                        let value = 0u8;
+                        unsafe { ::core::ptr::write(&raw mut (*slot).value, value) };
+                    }
+                    let __value_guard = unsafe {
+                        ::pinned_init::__internal::DropGuard::new_field(
+                            &raw mut (*slot).value,
+                        )
+                    };
+                    #[allow(unused_macros)]
+                    macro_rules! __value_ref {
+                        () => {
+                            unsafe { ::pinned_init::__internal::field_ref(& slot,
+                            ::core::ptr::addr_of!((* slot).value)) }
+                        };
+                    }
+                    ::core::mem::forget(__value_guard);
+                    ::core::mem::forget(__pin_guard);
+                    #[allow(unreachable_code, clippy::diverging_sub_expression)]
+                    let _ = || {
+                        #[allow(
+                            non_camel_case_types,
+                            dead_code,
+                            clippy::extra_unused_type_parameters
+                        )]
+                        fn __check_duplicate_fields<pin, value>() {}
+                        unsafe {
+                            ::core::ptr::write(
+                                slot,
+                                Foo {
+                                    pin: ::core::panicking::panic("explicit panic"),
+                                    value: ::core::panicking::panic("explicit panic"),
+                                },
+                            );
+                        };
+                    };
+                }
+                Ok(__InitOk)
+            },
+        );
+        let init = move |
+            slot,
+        | -> ::core::result::Result<(), ::core::convert::Infallible> {
+            init(slot).map(|__InitOk| ())
+        };
+        let init = unsafe {
+            ::pinned_init::pin_init_from_closure::<_, ::core::convert::Infallible>(init)
+        };
+        init
+    };
+}
@@ -0,0 +1,29 @@
+use core::marker::PhantomPinned;
+use pinned_init::*;
+
+trait Queue {
+    type Item;
+}
+
+#[pin_data]
+struct Foo<T>
+where
+    Self: Queue,
+    <Self as Queue>::Item: Clone,
+    T: Into<Option<<Self as Queue>::Item>>,
+{
+    #[pin]
+    pin: PhantomPinned,
+    value: T,
+}
+
+impl<T: Into<Option<u8>>> Queue for Foo<T> {
+    type Item = u8;
+}
+
+fn main() {
+    let _ = pin_init!(Foo {
+        pin: PhantomPinned,
+        value: 0u8,
+    });
+}
@@ -0,0 +1,70 @@
+use pinned_init::*;
+struct Foo<T> {
+    buf: Vec<T>,
+}
+const _: () = {
+    struct __ThePinData<T> {
+        __phantom: ::core::marker::PhantomData<fn(Foo<T>) -> Foo<T>>,
+    }
+    impl<T> ::core::clone::Clone for __ThePinData<T> {
+        fn clone(&self) -> Self {
+            *self
+        }
+    }
+    impl<T> ::core::marker::Copy for __ThePinData<T> {}
+    #[allow(dead_code)]
+    #[expect(clippy::missing_safety_doc)]
+    impl<T> __ThePinData<T> {
+        unsafe fn buf<E>(
+            self,
+            slot: *mut Vec<T>,
+            init: impl ::pinned_init::Init<Vec<T>, E>,
+        ) -> ::core::result::Result<(), E> {
+            unsafe { ::pinned_init::Init::__init(init, slot) }
+        }
+    }
+    unsafe impl<T> ::pinned_init::__internal::HasPinData for Foo<T> {
+        type PinData = __ThePinData<T>;
+        unsafe fn __pin_data() -> Self::PinData {
+            __ThePinData {
+                __phantom: ::core::marker::PhantomData,
+            }
+        }
+    }
+    unsafe impl<T> ::pinned_init::__internal::PinData for __ThePinData<T> {
+        type Datee = Foo<T>;
+    }
+    #[allow(dead_code)]
+    struct __Unpin<'__pin, T> {
+        __phantom_pin: ::core::marker::PhantomData<fn(&'__pin ()) -> &'__pin ()>,
+        __phantom: ::core::marker::PhantomData<fn(Foo<T>) -> Foo<T>>,
+    }
+    #[doc(hidden)]
+    impl<'__pin, T> ::core::marker::Unpin for Foo<T>
+    where
+        __Unpin<'__pin, T>: ::core::marker::Unpin,
+    {}
+    impl<T> ::core::ops::Drop for Foo<T> {
+        fn drop(&mut self) {
+            let pinned = unsafe { ::core::pin::Pin::new_unchecked(self) };
+            let token = unsafe { ::pinned_init::__internal::OnlyCallFromDrop::new() };
+            ::pinned_init::PinnedDrop::drop(pinned, token);
+        }
+    }
+};
+unsafe impl<T> ::pinned_init::PinnedDrop for Foo<T> {
+    fn drop(
+        self: ::core::pin::Pin<&mut Self>,
+        _: ::pinned_init::__internal::OnlyCallFromDrop,
+    ) {
+        trait UnpinnedDrop {
+            fn drop(&mut self);
+        }
+        impl<T> UnpinnedDrop for Foo<T> {
+            fn drop(&mut self) {
+                self.buf.clear();
+            }
+        }
+        UnpinnedDrop::drop(::core::pin::Pin::into_inner(self))
+    }
+}
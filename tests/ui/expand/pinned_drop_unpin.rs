@@ -0,0 +1,13 @@
+use pinned_init::*;
+
+#[pin_data(PinnedDrop)]
+struct Foo<T> {
+    buf: Vec<T>,
+}
+
+#[pinned_drop]
+impl<T> PinnedDrop for Foo<T> {
+    fn drop(&mut self) {
+        self.buf.clear();
+    }
+}
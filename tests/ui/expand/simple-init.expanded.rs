@@ -16,8 +16,20 @@ fn main() {
             move |slot| {
                 {
                     struct __InitOk;
+                    #[allow(unused_macros)]
+                    macro_rules! field_ref {
+                        ($name:ident) => {
+                            ::pinned_init::macros::paste! { [< __ $name _ref >] ! () }
+                        };
+                    }
                     #[allow(unreachable_code, clippy::diverging_sub_expression)]
                     let _ = || {
+                        #[allow(
+                            non_camel_case_types,
+                            dead_code,
+                            clippy::extra_unused_type_parameters
+                        )]
+                        fn __check_duplicate_fields() {}
                         unsafe {
                             ::core::ptr::write(slot, Foo {});
                         };
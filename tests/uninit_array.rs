@@ -0,0 +1,46 @@
+#![cfg_attr(feature = "alloc", feature(allocator_api))]
+
+use core::mem::MaybeUninit;
+use pinned_init::*;
+
+mod common;
+use common::{dropped, reset_dropped, Counted};
+
+#[expect(unused_attributes)]
+#[path = "../examples/error.rs"]
+mod error;
+use error::Error;
+
+#[test]
+fn staged() {
+    let mut array: Box<MaybeUninit<[String; 8]>> =
+        Box::init(uninit_array_init_from_fn(|i| i.to_string())).unwrap();
+    // The elements are initialized, but the array is still typed as uninitialized.
+    // SAFETY: All elements have been initialized above.
+    unsafe { array.assume_init_mut()[3].push('!') };
+    // SAFETY: All elements have been initialized above.
+    let array = unsafe { array.assume_init() };
+    assert_eq!(array[3], "3!");
+    assert_eq!(array[7], "7");
+}
+
+#[test]
+fn error_drops_initialized() {
+    reset_dropped();
+    let init = uninit_array_init_from_fn::<_, 8, Counted, Error>(|i| {
+        // SAFETY: The closure initializes `slot` when returning `Ok`.
+        unsafe {
+            init_from_closure(move |slot: *mut Counted| {
+                if i == 5 {
+                    return Err(Error);
+                }
+                slot.write(Counted(i));
+                Ok(())
+            })
+        }
+    });
+    stack_try_pin_init!(let array: MaybeUninit<[Counted; 8]> = init);
+    assert_eq!(array.err(), Some(Error));
+    // The elements initialized before the error have been dropped.
+    assert_eq!(dropped(), 5);
+}
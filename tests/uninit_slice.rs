@@ -0,0 +1,69 @@
+use core::{
+    cell::Cell,
+    mem::MaybeUninit,
+    pin::{pin, Pin},
+    ptr,
+};
+use pinned_init::*;
+
+struct CountDrop<'a> {
+    value: usize,
+    drops: &'a Cell<usize>,
+}
+
+impl Drop for CountDrop<'_> {
+    fn drop(&mut self) {
+        self.drops.set(self.drops.get() + 1);
+    }
+}
+
+fn count_drop(value: usize, drops: &Cell<usize>) -> impl PinInit<CountDrop<'_>, usize> + '_ {
+    // SAFETY: The slot is initialized on success and left untouched on failure.
+    unsafe {
+        pin_init_from_closure(move |slot: *mut CountDrop<'_>| {
+            if value == 7 {
+                return Err(value);
+            }
+            slot.write(CountDrop { value, drops });
+            Ok(())
+        })
+    }
+}
+
+#[test]
+fn success() {
+    let drops = Cell::new(0);
+    let mut buf = pin!([const { MaybeUninit::<CountDrop<'_>>::uninit() }; 5]);
+    let buf: Pin<&mut [MaybeUninit<CountDrop<'_>>]> = buf.as_mut();
+    // SAFETY: The elements are dropped below.
+    let slice = unsafe { pin_init_uninit_slice(buf, |i| count_drop(i * 2, &drops)) }.unwrap();
+    assert_eq!(slice.len(), 5);
+    for (i, elem) in slice.iter().enumerate() {
+        assert_eq!(elem.value, i * 2);
+    }
+    assert_eq!(drops.get(), 0);
+    // SAFETY: The elements are initialized and never used again.
+    unsafe { ptr::drop_in_place(slice.get_unchecked_mut()) };
+    assert_eq!(drops.get(), 5);
+}
+
+#[test]
+fn empty() {
+    let drops = Cell::new(0);
+    let mut buf = pin!([] as [MaybeUninit<CountDrop<'_>>; 0]);
+    let buf: Pin<&mut [MaybeUninit<CountDrop<'_>>]> = buf.as_mut();
+    // SAFETY: There are no elements to drop.
+    let slice = unsafe { pin_init_uninit_slice(buf, |i| count_drop(i, &drops)) }.unwrap();
+    assert!(slice.is_empty());
+}
+
+#[test]
+fn failure_drops_prefix() {
+    let drops = Cell::new(0);
+    let mut buf = pin!([const { MaybeUninit::<CountDrop<'_>>::uninit() }; 10]);
+    let buf: Pin<&mut [MaybeUninit<CountDrop<'_>>]> = buf.as_mut();
+    // SAFETY: The function fails, so no elements need to be dropped.
+    let res = unsafe { pin_init_uninit_slice(buf, |i| count_drop(i, &drops)) };
+    assert_eq!(res.err(), Some(7));
+    assert_eq!(drops.get(), 7);
+}
@@ -0,0 +1,55 @@
+use core::pin::Pin;
+use pinned_init::*;
+
+mod common;
+use common::{count_drop, dropped, reset_dropped};
+
+#[derive(Debug, PartialEq)]
+enum Error {
+    Invalid,
+}
+
+#[pin_data(PinnedDrop)]
+struct Bounds {
+    lo: u32,
+    hi: u32,
+}
+
+#[pinned_drop]
+impl PinnedDrop for Bounds {
+    fn drop(self: Pin<&mut Self>) {
+        count_drop();
+    }
+}
+
+impl Bounds {
+    fn new(lo: u32, hi: u32) -> impl PinInit<Self, Error> {
+        try_pin_init!(Self { lo, hi }? Error).validate(|b| {
+            if b.lo <= b.hi {
+                Ok(())
+            } else {
+                Err(Error::Invalid)
+            }
+        })
+    }
+}
+
+#[test]
+fn valid() {
+    reset_dropped();
+    {
+        stack_try_pin_init!(let bounds = Bounds::new(1, 2));
+        let bounds = bounds.unwrap();
+        assert_eq!((bounds.lo, bounds.hi), (1, 2));
+    }
+    assert_eq!(dropped(), 1);
+}
+
+#[test]
+fn invalid() {
+    reset_dropped();
+    stack_try_pin_init!(let bounds = Bounds::new(2, 1));
+    assert_eq!(bounds.err(), Some(Error::Invalid));
+    // The value was initialized, so it has to be dropped after the check failed.
+    assert_eq!(dropped(), 1);
+}
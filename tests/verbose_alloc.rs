@@ -0,0 +1,85 @@
+#![cfg(feature = "alloc")]
+#![feature(allocator_api)]
+
+use core::{
+    alloc::{AllocError, Layout},
+    convert::Infallible,
+    marker::PhantomData,
+    pin::Pin,
+};
+use pinned_init::*;
+
+/// A smart pointer whose allocation always fails, mocking an allocator that is out of memory.
+struct OomBox<T>(PhantomData<T>);
+
+impl<T> InPlaceInit<T> for OomBox<T> {
+    fn try_pin_init<E>(_init: impl PinInit<T, E>) -> Result<Pin<Self>, E>
+    where
+        E: From<AllocError>,
+    {
+        Err(AllocError.into())
+    }
+
+    fn try_init<E>(_init: impl Init<T, E>) -> Result<Self, E>
+    where
+        E: From<AllocError>,
+    {
+        Err(AllocError.into())
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum Error {
+    Oom(Layout),
+    Invalid,
+}
+
+impl From<Infallible> for Error {
+    fn from(e: Infallible) -> Self {
+        match e {}
+    }
+}
+
+impl From<LayoutAllocError> for Error {
+    fn from(err: LayoutAllocError) -> Self {
+        Self::Oom(err.layout)
+    }
+}
+
+#[allow(dead_code)]
+struct Big {
+    buf: [u64; 512],
+}
+
+fn big() -> impl Init<Big, Error> {
+    try_init!(Big {
+        buf <- zeroed(),
+    }? Error)
+}
+
+#[test]
+fn reports_layout() {
+    let err = OomBox::<Big>::try_pin_init_verbose(big()).err().unwrap();
+    assert_eq!(err, Error::Oom(Layout::new::<Big>()));
+    let err = OomBox::<Big>::try_init_verbose(big()).err().unwrap();
+    assert_eq!(err, Error::Oom(Layout::from_size_align(4096, 8).unwrap()));
+}
+
+#[test]
+fn init_error_is_kept() {
+    let failing = || try_init!(Big { buf: [0; 512] }? Error).chain(|_| Err(Error::Invalid));
+    assert_eq!(
+        Box::<Big>::try_init_verbose(failing()).err().unwrap(),
+        Error::Invalid
+    );
+    assert_eq!(
+        Box::<Big>::try_pin_init_verbose(failing()).err().unwrap(),
+        Error::Invalid
+    );
+}
+
+#[test]
+fn succeeds() {
+    let big = Box::<Big>::try_init_verbose(big()).unwrap();
+    assert!(big.buf.iter().all(|x| *x == 0));
+}
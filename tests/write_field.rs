@@ -0,0 +1,57 @@
+#![cfg_attr(feature = "alloc", feature(allocator_api))]
+
+use core::{mem, pin::Pin};
+use pinned_init::*;
+
+mod common;
+use common::{dropped, reset_dropped, Counted};
+
+#[expect(unused_attributes)]
+#[path = "../examples/error.rs"]
+mod error;
+use error::Error;
+
+#[pin_data]
+struct Triple {
+    first: Counted,
+    #[pin]
+    second: Counted,
+    third: u32,
+}
+
+fn triple(fail: bool) -> impl PinInit<Triple, Error> {
+    let third = pin_init_from_fn(move || if fail { Err(Error) } else { Ok(3) });
+    // SAFETY: Every field is initialized when `Ok` is returned, otherwise the guards drop the
+    // initialized fields.
+    unsafe {
+        pin_init_from_closure(move |slot: *mut Triple| {
+            let first = write_field!(slot, first: Counted(1));
+            let second =
+                write_field!(slot, second <- pin_init_from_fn(|| Ok::<_, Error>(Counted(2))))?;
+            let third = write_field!(slot, third <- third)?;
+            mem::forget((first, second, third));
+            Ok(())
+        })
+    }
+}
+
+#[test]
+fn success() {
+    reset_dropped();
+    {
+        stack_try_pin_init!(let triple = triple(false));
+        let triple: Pin<&mut Triple> = triple.unwrap();
+        assert_eq!(triple.third, 3);
+        assert_eq!(dropped(), 0);
+    }
+    assert_eq!(dropped(), 2);
+}
+
+#[test]
+fn failure() {
+    reset_dropped();
+    stack_try_pin_init!(let triple = triple(true));
+    assert!(triple.is_err());
+    // The first two fields are dropped by their guards.
+    assert_eq!(dropped(), 2);
+}
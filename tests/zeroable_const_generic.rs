@@ -0,0 +1,31 @@
+use core::marker::PhantomData;
+use pinned_init::*;
+
+#[derive(Zeroable)]
+struct Buf<const N: usize, T> {
+    buf: [T; N],
+    len: usize,
+}
+
+#[derive(Zeroable)]
+struct Defaulted<T: Copy, const N: usize = 16> {
+    buf: [T; N],
+    _phantom: PhantomData<T>,
+}
+
+fn assert_zeroable<T: Zeroable>() {}
+
+#[test]
+fn const_generic() {
+    assert_zeroable::<Buf<4, u8>>();
+    assert_zeroable::<Buf<0, Buf<4, u8>>>();
+    let buf: Box<Buf<4, u8>> = Box::init(zeroed()).unwrap();
+    assert_eq!(buf.buf, [0; 4]);
+    assert_eq!(buf.len, 0);
+}
+
+#[test]
+fn const_generic_default() {
+    let buf: Box<Defaulted<u32>> = Box::init(zeroed()).unwrap();
+    assert_eq!(buf.buf, [0; 16]);
+}
@@ -0,0 +1,44 @@
+use core::num::NonZeroI32;
+use pinned_init::*;
+
+#[derive(Zeroable, Default, ZeroableDefault, PartialEq, Debug)]
+struct Statistics {
+    reads: u64,
+    writes: u64,
+    last_error: Option<NonZeroI32>,
+    name: Option<Box<str>>,
+}
+
+#[derive(Zeroable, Default, ZeroableDefault, PartialEq, Debug)]
+struct Pair<T> {
+    a: T,
+    b: T,
+}
+
+#[derive(Zeroable, Default, ZeroableDefault, PartialEq, Debug)]
+struct Counter(u64, Option<Box<str>>);
+
+#[derive(Zeroable, Default, ZeroableDefault, PartialEq, Debug)]
+struct Wrapper<T>(pub(crate) T)
+where
+    u8: Copy;
+
+fn assert_zeroed_is_default<T: ZeroableDefault + PartialEq + core::fmt::Debug>() {
+    let zeroed: Box<T> = Box::init(zeroed_or_default()).unwrap();
+    assert_eq!(*zeroed, T::default());
+}
+
+#[test]
+fn primitives() {
+    assert_zeroed_is_default::<u32>();
+    assert_zeroed_is_default::<Option<Box<u8>>>();
+    assert_zeroed_is_default::<(bool, char, f64, [i16; 32])>();
+}
+
+#[test]
+fn derived() {
+    assert_zeroed_is_default::<Statistics>();
+    assert_zeroed_is_default::<Pair<Statistics>>();
+    assert_zeroed_is_default::<Counter>();
+    assert_zeroed_is_default::<Wrapper<u32>>();
+}
@@ -0,0 +1,27 @@
+use core::mem::MaybeUninit;
+use pinned_init::*;
+
+/// Does not implement `Zeroable`, since a null reference is invalid.
+struct NotZeroable(#[allow(dead_code)] &'static u8);
+
+fn assert_zeroable<T: Zeroable>() {}
+
+#[test]
+fn covered() {
+    assert_zeroable::<MaybeUninit<NotZeroable>>();
+    assert_zeroable::<MaybeUninit<[NotZeroable; 4]>>();
+    assert_zeroable::<[MaybeUninit<NotZeroable>; 4]>();
+    assert_zeroable::<[[MaybeUninit<NotZeroable>; 2]; 4]>();
+}
+
+#[test]
+fn zeroed_arrays() {
+    let array: Box<[MaybeUninit<NotZeroable>; 16]> = Box::init(zeroed()).unwrap();
+    // SAFETY: The memory has been zeroed and `NotZeroable` has the size of a `usize`.
+    let words = unsafe { array.as_ptr().cast::<[usize; 16]>().read() };
+    assert_eq!(words, [0; 16]);
+    let array: Box<MaybeUninit<[NotZeroable; 16]>> = Box::init(zeroed()).unwrap();
+    // SAFETY: The memory has been zeroed and `NotZeroable` has the size of a `usize`.
+    let words = unsafe { array.as_ptr().cast::<[usize; 16]>().read() };
+    assert_eq!(words, [0; 16]);
+}
@@ -0,0 +1,48 @@
+use pinned_init::*;
+
+#[derive(Zeroable)]
+struct Registers {
+    ctrl: u32,
+    status: u32,
+    data: [u8; 16],
+}
+
+#[pin_data]
+struct Device {
+    regs: Registers,
+    shadow: Registers,
+}
+
+fn is_zeroed(regs: &Registers) -> bool {
+    regs.ctrl == 0 && regs.status == 0 && regs.data == [0; 16]
+}
+
+#[test]
+fn reuse_for_fields() {
+    let zero = zeroed::<Registers>();
+    let dev: Box<Device> = Box::init(init!(Device {
+        regs <- zero,
+        shadow <- zero,
+    }))
+    .unwrap();
+    assert!(is_zeroed(&dev.regs) && is_zeroed(&dev.shadow));
+}
+
+fn copied<T>(init: impl Init<T> + Copy) -> [impl Init<T>; 2] {
+    [init, init]
+}
+
+#[test]
+fn reuse_in_generic_fn() {
+    let [a, b] = copied(zeroed::<Registers>());
+    let a: Box<Registers> = Box::init(a).unwrap();
+    let b: Box<Registers> = Box::init(b).unwrap();
+    assert!(is_zeroed(&a) && is_zeroed(&b));
+}
+
+#[test]
+fn reuse_in_array() {
+    let zero = zeroed::<Registers>();
+    let array: Box<[Registers; 8]> = Box::init(init_array_from_fn(|_| zero)).unwrap();
+    assert!(array.iter().all(is_zeroed));
+}
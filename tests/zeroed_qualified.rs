@@ -0,0 +1,55 @@
+use pinned_init::*;
+
+#[pin_data]
+#[derive(Zeroable)]
+struct Foo {
+    a: u32,
+    b: u64,
+    c: [u8; 4],
+}
+
+#[test]
+fn crate_path() {
+    stack_pin_init!(let foo = pin_init!(Foo {
+        a: 1,
+        ..::pinned_init::Zeroable::zeroed()
+    }));
+    assert_eq!((foo.a, foo.b, foo.c), (1, 0, [0; 4]));
+    stack_pin_init!(let foo = init!(Foo {
+        b: 2,
+        ..pinned_init::Zeroable::zeroed()
+    }));
+    assert_eq!((foo.a, foo.b, foo.c), (0, 2, [0; 4]));
+}
+
+#[test]
+fn qualified_self() {
+    stack_pin_init!(let foo = init!(Foo {
+        c: [3; 4],
+        ..<Self as Zeroable>::zeroed()
+    }));
+    assert_eq!((foo.a, foo.b, foo.c), (0, 0, [3; 4]));
+    stack_pin_init!(let foo = pin_init!(Foo {
+        a: 4,
+        b: 4,
+        ..<Foo as ::pinned_init::Zeroable>::zeroed()
+    }));
+    assert_eq!((foo.a, foo.b, foo.c), (4, 4, [0; 4]));
+}
+
+#[test]
+fn only_zeroed() {
+    stack_pin_init!(let foo = init!(Foo { ..<Foo as pinned_init::Zeroable>::zeroed() }));
+    assert_eq!((foo.a, foo.b, foo.c), (0, 0, [0; 4]));
+}
+
+#[test]
+fn skip_rest() {
+    let skip = true;
+    stack_pin_init!(let foo = init!(Foo {
+        a: 5,
+        b: if skip { skip_rest!() } else { 1 },
+        ..<Self as Zeroable>::zeroed()
+    }));
+    assert_eq!((foo.a, foo.b, foo.c), (5, 0, [0; 4]));
+}
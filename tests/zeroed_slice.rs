@@ -0,0 +1,37 @@
+use core::{mem::MaybeUninit, ptr};
+use pinned_init::*;
+
+/// Initializes a boxed slice of length `len` with `init`.
+fn boxed_slice<T>(len: usize, init: impl Init<[T]>) -> Box<[T]> {
+    let mut buf = Box::<[T]>::new_uninit_slice(len);
+    let slot = ptr::slice_from_raw_parts_mut(buf.as_mut_ptr().cast::<T>(), len);
+    // SAFETY: `slot` is valid for writes and points to uninitialized memory.
+    let Ok(()) = unsafe { init.__init(slot) };
+    // SAFETY: The slice has been initialized above.
+    unsafe { buf.assume_init() }
+}
+
+#[test]
+fn zeroed() {
+    let buf: Box<[u64]> = boxed_slice(1000, zeroed_slice());
+    assert_eq!(buf.len(), 1000);
+    assert!(buf.iter().all(|&x| x == 0));
+}
+
+#[test]
+fn zeroed_options() {
+    let buf: Box<[Option<Box<u8>>]> = boxed_slice(16, zeroed_slice());
+    assert!(buf.iter().all(Option::is_none));
+}
+
+#[test]
+fn zeroed_empty() {
+    let buf: Box<[u64]> = boxed_slice(0, zeroed_slice());
+    assert!(buf.is_empty());
+}
+
+#[test]
+fn uninit() {
+    let buf: Box<[MaybeUninit<u64>]> = boxed_slice(1000, uninit_slice());
+    assert_eq!(buf.len(), 1000);
+}
@@ -0,0 +1,50 @@
+use pinned_init::*;
+
+#[repr(C)]
+#[derive(Zeroable)]
+struct Command {
+    opcode: u8,
+    flags: u8,
+    len: u16,
+    addr: u64,
+}
+
+fn command(patches: &[(usize, &[u8])]) -> Box<Command> {
+    // SAFETY: All fields of `Command` are integers, so every bit pattern is valid.
+    Box::init(unsafe { zeroed_with_patches(patches) }).unwrap()
+}
+
+#[test]
+fn no_patches() {
+    let cmd = command(&[]);
+    assert_eq!((cmd.opcode, cmd.flags, cmd.len, cmd.addr), (0, 0, 0, 0));
+}
+
+#[test]
+fn patches() {
+    let addr = 0xdead_beef_u64.to_ne_bytes();
+    let cmd = command(&[(0, &[7, 1]), (8, &addr), (1, &[2])]);
+    // The later patch overwrites `flags`.
+    assert_eq!(
+        (cmd.opcode, cmd.flags, cmd.len, cmd.addr),
+        (7, 2, 0, 0xdead_beef)
+    );
+}
+
+#[test]
+fn patch_at_end() {
+    let cmd = command(&[(15, &[0xff])]);
+    assert_eq!(cmd.addr.to_ne_bytes()[7], 0xff);
+}
+
+#[test]
+#[should_panic(expected = "does not fit into 16 bytes")]
+fn out_of_bounds() {
+    command(&[(12, &[0; 8])]);
+}
+
+#[test]
+#[should_panic(expected = "does not fit into 16 bytes")]
+fn offset_overflow() {
+    command(&[(usize::MAX, &[0])]);
+}
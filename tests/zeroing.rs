@@ -1,4 +1,12 @@
-use std::{marker::PhantomPinned, ptr::addr_of_mut};
+use core::{
+    ffi::{
+        c_char, c_double, c_float, c_int, c_long, c_longlong, c_schar, c_short, c_uchar, c_uint,
+        c_ulong, c_ulonglong, c_ushort, c_void,
+    },
+    marker::{PhantomData, PhantomPinned},
+    mem::{size_of, MaybeUninit},
+    ptr::addr_of_mut,
+};
 
 use pinned_init::*;
 
@@ -31,3 +39,165 @@ impl Foo {
 fn test() {
     let _ = Box::pin_init(Foo::new()).unwrap();
 }
+
+static BUF: [u8; 4096] = zeroed_value();
+
+#[test]
+fn static_zeroed_value() {
+    assert!(BUF.iter().all(|b| *b == 0));
+    const FOO: (u64, Option<Box<u8>>, [bool; 16]) = zeroed_value();
+    assert_eq!(FOO, (0, None, [false; 16]));
+}
+
+#[derive(Zeroable)]
+pub(crate) struct Pair(pub(crate) u32, *const u8)
+where
+    u8: Copy;
+
+#[test]
+fn tuple_struct() {
+    let pair: Box<Pair> = Box::init(zeroed()).unwrap();
+    assert_eq!(pair.0, 0);
+    assert!(pair.1.is_null());
+}
+
+#[test]
+fn range() {
+    let range: Box<core::ops::Range<usize>> = Box::init(zeroed()).unwrap();
+    assert_eq!(*range, 0..0);
+}
+
+#[test]
+fn range_field_values() {
+    #[derive(Zeroable)]
+    struct Window {
+        span: core::ops::Range<usize>,
+        len: usize,
+        total: usize,
+    }
+
+    let start = 2;
+    let window = Box::init(init!(Window {
+        span: start..start + 4,
+        len <- init_from_fn(|| Ok::<_, core::convert::Infallible>(4)),
+        ..Zeroable::zeroed()
+    }))
+    .unwrap();
+    assert_eq!(window.span, 2..6);
+    assert_eq!((window.len, window.total), (4, 0));
+}
+
+/// Does not implement `Zeroable`.
+struct Token(#[allow(dead_code)] &'static str);
+
+#[derive(Zeroable)]
+struct Tuple<T>(u8, PhantomData<T>);
+
+#[derive(Zeroable)]
+struct Named<T, U> {
+    value: U,
+    _marker: PhantomData<fn(T) -> T>,
+}
+
+#[derive(Zeroable)]
+pub(crate) struct Bounded<T>(pub(crate) u32, PhantomData<T>)
+where
+    T: Send;
+
+fn assert_zeroable<T: Zeroable>() {}
+
+#[test]
+fn phantom_only() {
+    assert_zeroable::<Tuple<Token>>();
+    assert_zeroable::<Named<Token, u64>>();
+    assert_zeroable::<Bounded<Token>>();
+    let tuple: Box<Tuple<Token>> = Box::init(zeroed()).unwrap();
+    assert_eq!(tuple.0, 0);
+    let named: Box<Named<Token, u64>> = Box::init(zeroed()).unwrap();
+    assert_eq!(named.value, 0);
+    let bounded: Box<Bounded<Token>> = Box::init(zeroed()).unwrap();
+    assert_eq!(bounded.0, 0);
+}
+
+#[derive(Zeroable)]
+#[repr(C)]
+struct Device {
+    name: [c_char; 16],
+    id: c_int,
+    flags: c_uint,
+    mode: c_schar,
+    state: c_uchar,
+    minor: c_short,
+    major: c_ushort,
+    offset: c_long,
+    size: c_ulong,
+    total: c_longlong,
+    limit: c_ulonglong,
+    scale: c_float,
+    ratio: c_double,
+    private: *mut c_void,
+    ops: *const c_void,
+}
+
+#[test]
+fn ffi_struct() {
+    let dev: Box<Device> = Box::init(zeroed()).unwrap();
+    assert_eq!(dev.name, [0; 16]);
+    assert_eq!((dev.id, dev.flags, dev.mode, dev.state), (0, 0, 0, 0));
+    assert_eq!((dev.minor, dev.major, dev.offset, dev.size), (0, 0, 0, 0));
+    assert_eq!((dev.total, dev.limit), (0, 0));
+    assert_eq!((dev.scale, dev.ratio), (0.0, 0.0));
+    assert!(dev.private.is_null());
+    assert!(dev.ops.is_null());
+}
+
+#[repr(C)]
+struct Inner {
+    tag: u16,
+    // Two padding bytes.
+    value: u32,
+}
+
+#[repr(C)]
+struct Packet {
+    kind: u8,
+    // Three padding bytes.
+    len: u32,
+    flag: u8,
+    // Seven padding bytes.
+    data: u64,
+    inner: Inner,
+}
+
+/// Initializes a `Packet` into memory that was filled with `garbage` before and returns its bytes.
+fn bytes(garbage: u8, init: impl Init<Packet>) -> [u8; size_of::<Packet>()] {
+    let mut packet = MaybeUninit::<Packet>::uninit();
+    let slot = packet.as_mut_ptr();
+    // SAFETY: `slot` is valid for writes.
+    unsafe { slot.write_bytes(garbage, 1) };
+    // SAFETY: `slot` is valid for writes and the initializer does not fail.
+    let Ok(()) = unsafe { init.__init(slot) };
+    // SAFETY: All bytes have been initialized by the garbage or by the initializer.
+    unsafe { slot.cast::<[u8; size_of::<Packet>()]>().read() }
+}
+
+fn packet() -> impl Init<Packet> {
+    init!(Packet {
+        kind: 1,
+        len: 2,
+        flag: 3,
+        data: 4,
+        inner <- init!(Inner { tag: 5, value: 6 }),
+    })
+    .zero_padding()
+}
+
+#[test]
+fn padding_is_zeroed() {
+    let a = bytes(0xaa, packet());
+    let b = bytes(0x55, packet());
+    assert_eq!(a, b);
+    assert_eq!(a[1..4], [0; 3]);
+    assert_eq!(a[9..16], [0; 7]);
+    assert_eq!(a[26..28], [0; 2]);
+}
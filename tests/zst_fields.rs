@@ -0,0 +1,88 @@
+#![cfg_attr(feature = "alloc", feature(allocator_api))]
+
+use core::marker::{PhantomData, PhantomPinned};
+use pinned_init::*;
+
+mod common;
+use common::{count_drop, dropped, reset_dropped, Counted};
+
+#[expect(unused_attributes)]
+#[path = "../examples/error.rs"]
+mod error;
+use error::Error;
+
+/// A zero-sized type with a `Drop` impl, it still needs a drop guard.
+struct ZstWithDrop;
+
+impl Drop for ZstWithDrop {
+    fn drop(&mut self) {
+        count_drop();
+    }
+}
+
+#[pin_data]
+struct Mixed {
+    a: (),
+    b: Counted,
+    c: ZstWithDrop,
+    d: PhantomData<u64>,
+    #[pin]
+    e: PhantomPinned,
+    f: usize,
+}
+
+fn fail() -> impl Init<usize, Error> {
+    // SAFETY: The closure does not touch `slot` and returns an error.
+    unsafe { init_from_closure(|_| Err(Error)) }
+}
+
+#[test]
+fn mixed_success() {
+    reset_dropped();
+    {
+        stack_pin_init!(let mixed = pin_init!(Mixed {
+            a: (),
+            b: Counted(42),
+            c: ZstWithDrop,
+            d: PhantomData,
+            e: PhantomPinned,
+            f: 7,
+        }));
+        assert_eq!(mixed.f, 7);
+        assert_eq!(dropped(), 0);
+    }
+    assert_eq!(dropped(), 2);
+}
+
+#[test]
+fn mixed_error_drops_initialized_fields() {
+    reset_dropped();
+    stack_try_pin_init!(let mixed = try_pin_init!(Mixed {
+        a: (),
+        b: Counted(42),
+        c: ZstWithDrop,
+        d: PhantomData,
+        e: PhantomPinned,
+        f <- fail(),
+    }? Error));
+    assert_eq!(mixed.err(), Some(Error));
+    assert_eq!(dropped(), 2);
+}
+
+#[test]
+fn only_zsts() {
+    #[pin_data]
+    struct Empty {
+        a: (),
+        #[pin]
+        b: PhantomPinned,
+        c: PhantomData<Counted>,
+    }
+
+    stack_pin_init!(let empty = pin_init!(Empty {
+        a: (),
+        b: PhantomPinned,
+        c: PhantomData,
+    }));
+    let _: &Empty = &empty;
+}